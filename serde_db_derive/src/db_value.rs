@@ -0,0 +1,271 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Null,
+    Bool,
+    Int,
+    Float,
+    Text,
+    Bytes,
+    Array,
+    Map,
+    Composite,
+}
+
+const SCALAR_TARGETS: [&str; 12] = [
+    "bool", "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64", "String",
+];
+
+#[allow(clippy::too_many_lines)]
+pub(super) fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(ref data) = input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "#[derive(DbValue)] is only supported for enums",
+        ));
+    };
+
+    let mut variants: Vec<(Kind, &Ident)> = Vec::new();
+    for variant in &data.variants {
+        if let Some(kind) = kind_of(variant)? {
+            if kind != Kind::Null
+                && !matches!(variant.fields, Fields::Unnamed(ref f) if f.unnamed.len() == 1)
+            {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "annotated variants must have exactly one unnamed field",
+                ));
+            }
+            variants.push((kind, &variant.ident));
+        }
+    }
+    let of_kind = |kind: Kind| -> Vec<&Ident> {
+        variants
+            .iter()
+            .filter(|(k, _)| *k == kind)
+            .map(|(_, ident)| *ident)
+            .collect()
+    };
+    let all_variants: Vec<&Ident> = data.variants.iter().map(|v| &v.ident).collect();
+    let all_names: Vec<String> = all_variants.iter().map(ToString::to_string).collect();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (bools, ints, floats, texts) = (
+        of_kind(Kind::Bool),
+        of_kind(Kind::Int),
+        of_kind(Kind::Float),
+        of_kind(Kind::Text),
+    );
+    let nulls = of_kind(Kind::Null);
+    let bytes = of_kind(Kind::Bytes);
+
+    let is_null = if nulls.is_empty() {
+        quote!(false)
+    } else {
+        quote!(matches!(self, #(Self::#nulls { .. })|*))
+    };
+    // the kinds of the annotated scalar variants; all other values are probed as strings
+    let kinds: Vec<(Vec<&Ident>, TokenStream2)> = [
+        (Kind::Bool, quote!(Bool)),
+        (Kind::Int, quote!(Int)),
+        (Kind::Float, quote!(Float)),
+        (Kind::Bytes, quote!(Bytes)),
+    ]
+    .into_iter()
+    .map(|(kind, value_kind)| (of_kind(kind), value_kind))
+    .filter(|(variants, _)| !variants.is_empty())
+    .collect();
+    let value_kind = if kinds.is_empty() {
+        TokenStream2::new()
+    } else {
+        let arms = kinds.iter().map(|(variants, value_kind)| {
+            quote!(#(Self::#variants(_))|* => ::serde_db::value::ValueKind::#value_kind,)
+        });
+        quote! {
+            #[allow(unreachable_patterns)]
+            fn value_kind(&self) -> ::serde_db::value::ValueKind {
+                match self {
+                    #(#arms)*
+                    _ => ::serde_db::value::ValueKind::String,
+                }
+            }
+        }
+    };
+    let is_empty_string = if texts.is_empty() {
+        TokenStream2::new()
+    } else {
+        quote! {
+            #[allow(unreachable_patterns)]
+            fn is_empty_string(&self) -> bool {
+                match self {
+                    #(Self::#texts(v) => ::std::convert::AsRef::<str>::as_ref(v).is_empty(),)*
+                    _ => false,
+                }
+            }
+        }
+    };
+    let array_methods = capability(
+        &of_kind(Kind::Array),
+        &quote!(is_array),
+        &quote!(try_into_elements),
+        &quote!(::std::vec::Vec<Self>),
+        "an array",
+    );
+    let map_methods = capability(
+        &of_kind(Kind::Map),
+        &quote!(is_map),
+        &quote!(try_into_entries),
+        &quote!(::std::vec::Vec<(::std::string::String, Self)>),
+        "a map",
+    );
+    let composite_methods = capability(
+        &of_kind(Kind::Composite),
+        &quote!(is_composite),
+        &quote!(try_into_fields),
+        &quote!(::std::vec::Vec<(::std::string::String, Self)>),
+        "a composite value",
+    );
+    let scalar_targets = SCALAR_TARGETS
+        .iter()
+        .map(|t| syn::parse_str::<syn::Type>(t))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        const _: () = {
+            use ::serde_db::de::numeric::Scalar;
+            use ::serde_db::de::ConversionError;
+
+            fn type_error #impl_generics (
+                value: &#name #ty_generics,
+                expected: &str,
+            ) -> ConversionError #where_clause {
+                let variant = match value {
+                    #(#name::#all_variants { .. } => #all_names,)*
+                };
+                ConversionError::ValueType(::std::format!(
+                    "{}::{} is not {}",
+                    ::std::stringify!(#name),
+                    variant,
+                    expected
+                ))
+            }
+
+            impl #impl_generics ::serde_db::de::numeric::ToScalar for #name #ty_generics
+            #where_clause
+            {
+                #[allow(unreachable_patterns)]
+                fn try_into_scalar(self) -> ::std::result::Result<Scalar, ConversionError> {
+                    match self {
+                        #(Self::#bools(v) => Ok(Scalar::Bool(v)),)*
+                        #(Self::#ints(v) => Ok(Scalar::Int(::std::convert::Into::into(v))),)*
+                        #(Self::#floats(v) => Ok(Scalar::Float(::std::convert::Into::into(v))),)*
+                        #(Self::#texts(v) => Ok(Scalar::Text(::std::convert::Into::into(v))),)*
+                        other => Err(type_error(&other, "a scalar value")),
+                    }
+                }
+            }
+
+            #(
+                impl #impl_generics ::serde_db::de::DbValueInto<#scalar_targets>
+                    for #name #ty_generics #where_clause
+                {
+                    fn try_into(self) -> ::std::result::Result<#scalar_targets, ConversionError> {
+                        ::serde_db::de::numeric::ToScalar::try_into_scalar(self)?.try_into()
+                    }
+                }
+            )*
+
+            impl #impl_generics ::serde_db::de::DbValueInto<::std::vec::Vec<u8>>
+                for #name #ty_generics #where_clause
+            {
+                #[allow(unreachable_patterns)]
+                fn try_into(self) -> ::std::result::Result<::std::vec::Vec<u8>, ConversionError> {
+                    match self {
+                        #(Self::#bytes(v) => Ok(::std::convert::Into::into(v)),)*
+                        other => Err(type_error(&other, "a binary value")),
+                    }
+                }
+            }
+
+            impl #impl_generics ::serde_db::de::DbValue for #name #ty_generics #where_clause {
+                fn is_null(&self) -> bool {
+                    #is_null
+                }
+
+                #is_empty_string
+                #value_kind
+                #array_methods
+                #map_methods
+                #composite_methods
+
+                fn try_into_i128(self) -> ::std::result::Result<i128, ConversionError> {
+                    ::serde_db::de::numeric::ToScalar::try_into_scalar(self)?.try_into()
+                }
+
+                fn try_into_u128(self) -> ::std::result::Result<u128, ConversionError> {
+                    ::serde_db::de::numeric::ToScalar::try_into_scalar(self)?.try_into()
+                }
+            }
+        };
+    })
+}
+
+// The pair of methods with which DbValue exposes a structured value;
+// without annotated variants, the default implementations are kept.
+fn capability(
+    variants: &[&Ident],
+    is_method: &TokenStream2,
+    into_method: &TokenStream2,
+    output: &TokenStream2,
+    expected: &str,
+) -> TokenStream2 {
+    if variants.is_empty() {
+        return TokenStream2::new();
+    }
+    quote! {
+        fn #is_method(&self) -> bool {
+            matches!(self, #(Self::#variants(_))|*)
+        }
+
+        #[allow(unreachable_patterns)]
+        fn #into_method(self) -> ::std::result::Result<#output, ConversionError> {
+            match self {
+                #(Self::#variants(v) => Ok(v),)*
+                other => Err(type_error(&other, #expected)),
+            }
+        }
+    }
+}
+
+fn kind_of(variant: &syn::Variant) -> syn::Result<Option<Kind>> {
+    let mut kind = None;
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("db_value"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let k = match meta.path.get_ident().map(ToString::to_string).as_deref() {
+                Some("null") => Kind::Null,
+                Some("bool") => Kind::Bool,
+                Some("int") => Kind::Int,
+                Some("float") => Kind::Float,
+                Some("text") => Kind::Text,
+                Some("bytes") => Kind::Bytes,
+                Some("array") => Kind::Array,
+                Some("map") => Kind::Map,
+                Some("composite") => Kind::Composite,
+                _ => return Err(meta.error("unknown db_value representation")),
+            };
+            if kind.replace(k).is_some() {
+                return Err(meta.error("only one db_value representation per variant"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(kind)
+}
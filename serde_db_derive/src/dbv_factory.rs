@@ -0,0 +1,287 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Ident, Path, Type};
+
+// The rust representation of the database values a variant produces.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Bool,
+    Int,
+    Float,
+    Text,
+    Bytes,
+}
+
+// The rust types that DbvFactory hands over to its serialize methods.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Bool,
+    Int,
+    Float,
+    Char,
+    Str,
+    Bytes,
+}
+
+const METHODS: [(&str, &str, Source); 14] = [
+    ("serialize_bool", "bool", Source::Bool),
+    ("serialize_i8", "i8", Source::Int),
+    ("serialize_i16", "i16", Source::Int),
+    ("serialize_i32", "i32", Source::Int),
+    ("serialize_i64", "i64", Source::Int),
+    ("serialize_u8", "u8", Source::Int),
+    ("serialize_u16", "u16", Source::Int),
+    ("serialize_u32", "u32", Source::Int),
+    ("serialize_u64", "u64", Source::Int),
+    ("serialize_f32", "f32", Source::Float),
+    ("serialize_f64", "f64", Source::Float),
+    ("serialize_char", "char", Source::Char),
+    ("serialize_str", "&str", Source::Str),
+    ("serialize_bytes", "&[u8]", Source::Bytes),
+];
+
+struct Variant<'a> {
+    ident: &'a Ident,
+    constructor: Path,
+    native: Type,
+    kind: Kind,
+    nullable: bool,
+}
+
+pub(super) fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(ref data) = input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "#[derive(DbvFactory)] is only supported for enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[derive(DbvFactory)] is not supported for generic enums",
+        ));
+    }
+    let (value_type, null) = container_attributes(input)?;
+
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if let Some(v) = variant_attributes(variant)? {
+            if v.nullable && null.is_none() {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "nullable variants require #[dbv_factory(null = ...)] on the enum",
+                ));
+            }
+            variants.push(v);
+        }
+    }
+    let all_variants: Vec<&Ident> = data.variants.iter().map(|v| &v.ident).collect();
+    let all_names: Vec<String> = all_variants.iter().map(ToString::to_string).collect();
+
+    let methods = METHODS
+        .iter()
+        .map(|(method, value_type, source)| {
+            let method = Ident::new(method, proc_macro2::Span::call_site());
+            let rust_type = syn::parse_str::<Type>(value_type)?;
+            let arms = variants.iter().filter_map(|v| {
+                let conversion = conversion(v, *source, value_type)?;
+                let (ident, constructor) = (v.ident, &v.constructor);
+                Some(quote!(Self::#ident { .. } => (#conversion).map(#constructor),))
+            });
+            Ok(quote! {
+                #[allow(unreachable_patterns)]
+                fn #method(&self, value: #rust_type) -> Result<Self::DBV, SerializationError> {
+                    match self {
+                        #(#arms)*
+                        _ => Err(type_error(#value_type, self.descriptor())),
+                    }
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let delegations = delegations()?;
+
+    let nullables: Vec<&Ident> = variants
+        .iter()
+        .filter(|v| v.nullable)
+        .map(|v| v.ident)
+        .collect();
+    let serialize_none = match null {
+        Some(null) if !nullables.is_empty() => quote! {
+            match self {
+                #(Self::#nullables { .. })|* => Ok(#null),
+                _ => Err(type_error("none", self.descriptor())),
+            }
+        },
+        _ => quote!(Err(type_error("none", self.descriptor()))),
+    };
+
+    let name = &input.ident;
+    Ok(quote! {
+        const _: () = {
+            use ::serde_db::de::numeric::Scalar;
+            use ::serde_db::ser::{parse_error, type_error, DbvFactory, SerializationError};
+
+            impl DbvFactory for #name {
+                type DBV = #value_type;
+
+                #(#methods)*
+
+                #[allow(unreachable_patterns)]
+                fn serialize_none(&self) -> Result<Self::DBV, SerializationError> {
+                    #serialize_none
+                }
+
+                fn descriptor(&self) -> ::std::string::String {
+                    ::std::string::String::from(match self {
+                        #(Self::#all_variants { .. } => #all_names,)*
+                    })
+                }
+            }
+
+            impl DbvFactory for &#name {
+                type DBV = #value_type;
+
+                #(#delegations)*
+
+                fn serialize_none(&self) -> Result<Self::DBV, SerializationError> {
+                    (**self).serialize_none()
+                }
+
+                fn descriptor(&self) -> ::std::string::String {
+                    (**self).descriptor()
+                }
+            }
+        };
+    })
+}
+
+// The methods of the implementation for references, which delegate to the enum.
+fn delegations() -> syn::Result<Vec<TokenStream2>> {
+    METHODS
+        .iter()
+        .map(|(method, value_type, _)| {
+            let method = Ident::new(method, proc_macro2::Span::call_site());
+            let rust_type = syn::parse_str::<Type>(value_type)?;
+            Ok(quote! {
+                fn #method(&self, value: #rust_type) -> Result<Self::DBV, SerializationError> {
+                    (**self).#method(value)
+                }
+            })
+        })
+        .collect()
+}
+
+// The expression that converts `value` into the native type of the variant,
+// or None if the variant does not accept values of this type.
+fn conversion(variant: &Variant, source: Source, value_type: &str) -> Option<TokenStream2> {
+    let native = &variant.native;
+    let range_error = quote!(|_| SerializationError::Range(#value_type, self.descriptor()));
+    let parsed = quote! {
+        Scalar::Text(::std::string::ToString::to_string(value))
+            .try_into::<#native>()
+            .map_err(|e| parse_error(value, self.descriptor(), Some(::std::boxed::Box::new(e))))
+    };
+    Some(match (variant.kind, source) {
+        (Kind::Bool, Source::Bool) => quote!(Ok(value)),
+        (Kind::Int | Kind::Float, Source::Int) => quote! {
+            Scalar::Int(i128::from(value)).try_into::<#native>().map_err(#range_error)
+        },
+        (Kind::Float, Source::Float) => quote! {
+            Scalar::Float(f64::from(value)).try_into::<#native>().map_err(#range_error)
+        },
+        (Kind::Bool | Kind::Int | Kind::Float, Source::Str) => parsed,
+        (Kind::Text, Source::Bytes) => return None,
+        (Kind::Text, _) => quote!(Ok(::std::string::ToString::to_string(&value))),
+        (Kind::Bytes, Source::Bytes) => quote!(Ok(value.to_vec())),
+        _ => return None,
+    })
+}
+
+fn container_attributes(input: &DeriveInput) -> syn::Result<(Type, Option<Expr>)> {
+    let mut value_type = None;
+    let mut null = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("dbv_factory"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("value") {
+                value_type = Some(meta.value()?.parse::<Type>()?);
+            } else if meta.path.is_ident("null") {
+                null = Some(meta.value()?.parse::<Expr>()?);
+            } else {
+                return Err(meta.error("unknown dbv_factory attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    let value_type = value_type.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "#[derive(DbvFactory)] requires #[dbv_factory(value = ...)] on the enum",
+        )
+    })?;
+    Ok((value_type, null))
+}
+
+fn variant_attributes(variant: &syn::Variant) -> syn::Result<Option<Variant<'_>>> {
+    let mut constructor = None;
+    let mut nullable = false;
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("dbv_factory"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nullable") {
+                nullable = true;
+                return Ok(());
+            }
+            if !meta.input.peek(syn::token::Paren) {
+                return Err(meta.error("expected a constructor like `MyDbValue::Variant(i32)`"));
+            }
+            let content;
+            syn::parenthesized!(content in meta.input);
+            let native = content.parse::<Type>()?;
+            if constructor.replace((meta.path, native)).is_some() {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "only one constructor per variant",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    let Some((constructor, native)) = constructor else {
+        if nullable {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "nullable variants require a constructor",
+            ));
+        }
+        return Ok(None);
+    };
+    let kind = match quote!(#native).to_string().replace(' ', "").as_str() {
+        "bool" => Kind::Bool,
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" => Kind::Int,
+        "f32" | "f64" => Kind::Float,
+        "String" => Kind::Text,
+        "Vec<u8>" => Kind::Bytes,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                native,
+                "supported types are bool, the integer types up to 64 bit, f32, f64, \
+                 String, and Vec<u8>",
+            ))
+        }
+    };
+    Ok(Some(Variant {
+        ident: &variant.ident,
+        constructor,
+        native,
+        kind,
+        nullable,
+    }))
+}
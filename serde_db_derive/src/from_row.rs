@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, GenericArgument, LitStr, PathArguments, Type};
+
+// How a field is read from the row.
+enum Read<'a> {
+    Required(&'a Type),
+    Optional(&'a Type),
+    Deserialize(&'a Type),
+}
+
+pub(super) fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "#[derive(FromRow)] is only supported for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "#[derive(FromRow)] is only supported for structs",
+            ))
+        }
+    };
+
+    let mut idents = Vec::new();
+    let mut columns = Vec::new();
+    let mut reads = Vec::new();
+    for field in fields {
+        let (column, serde) = field_attributes(field)?;
+        let ident = field.ident.as_ref().unwrap();
+        columns.push(column.unwrap_or_else(|| ident.to_string()));
+        idents.push(ident);
+        reads.push(match (serde, option_inner(&field.ty)) {
+            (true, _) => Read::Deserialize(&field.ty),
+            (false, Some(inner)) => Read::Optional(inner),
+            (false, None) => Read::Required(&field.ty),
+        });
+    }
+    let reads = reads.iter().map(|read| match read {
+        Read::Required(ty) => quote!(fields.required::<#ty>()?),
+        Read::Optional(ty) => quote!(fields.optional::<#ty>()?),
+        Read::Deserialize(ty) => quote!(fields.deserialize::<#ty>()?),
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::serde_db::de::FromRow for #name #ty_generics #where_clause {
+            const COLUMNS: &'static [&'static str] = &[#(#columns),*];
+
+            fn from_fields<R: ::serde_db::de::DeserializableRow>(
+                fields: &mut ::serde_db::de::RowFields<'_, R>,
+            ) -> ::serde_db::de::DeserializationResult<Self> {
+                Ok(Self {
+                    #(#idents: #reads,)*
+                })
+            }
+        }
+    })
+}
+
+// The type within `Option<...>`, if the field is an Option.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(ref type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(ref args) = segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+fn field_attributes(field: &syn::Field) -> syn::Result<(Option<String>, bool)> {
+    let mut column = None;
+    let mut serde = false;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("from_row")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                column = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("serde") {
+                serde = true;
+            } else {
+                return Err(meta.error("unknown from_row attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok((column, serde))
+}
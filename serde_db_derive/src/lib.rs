@@ -0,0 +1,156 @@
+//! Derive macros for the driver-side traits of
+//! [`serde_db`](https://docs.rs/serde_db/).
+//!
+//! Use this crate through the `derive` feature of `serde_db`,
+//! which re-exports the macros next to the traits they implement.
+
+#![deny(missing_docs)]
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![forbid(unsafe_code)]
+
+mod db_value;
+mod dbv_factory;
+mod from_row;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Implements `serde_db::de::DbValue`, `serde_db::de::numeric::ToScalar`,
+/// and all `serde_db::de::DbValueInto<T>` that `DbValue` requires, for an enum
+/// whose variants are annotated with their rust representation.
+///
+/// ```rust,ignore
+/// use serde_db::de::DbValue;
+///
+/// #[derive(DbValue)]
+/// pub enum MyDbValue {
+///     #[db_value(null)]
+///     Null,
+///     #[db_value(bool)]
+///     Boolean(bool),
+///     #[db_value(int)]
+///     Integer(i32),
+///     #[db_value(float)]
+///     Double(f64),
+///     #[db_value(text)]
+///     Varchar(String),
+///     #[db_value(bytes)]
+///     Blob(Vec<u8>),
+///     #[db_value(array)]
+///     Array(Vec<MyDbValue>),
+///     #[db_value(map)]
+///     Map(Vec<(String, MyDbValue)>),
+///     #[db_value(composite)]
+///     Row(Vec<(String, MyDbValue)>),
+///     // variants without annotation are not converted
+///     Cursor(u64),
+/// }
+/// ```
+///
+/// The representations are:
+///
+/// | annotation  | variant          | converts into                                     |
+/// |-------------|------------------|---------------------------------------------------|
+/// | `null`      | any shape        | `None`                                            |
+/// | `bool`      | `V(bool)`        | `bool`, numbers, `String`                         |
+/// | `int`       | `V(<integer>)`   | numbers, `bool`, `String` (with range checks)     |
+/// | `float`     | `V(f32 or f64)`  | numbers, `String` (with range and precision checks)|
+/// | `text`      | `V(String)`      | `String`, parsed numbers and `bool`               |
+/// | `bytes`     | `V(Vec<u8>)`     | `Vec<u8>`                                         |
+/// | `array`     | `V(Vec<Self>)`   | collections                                       |
+/// | `map`       | `V(Vec<(String, Self)>)` | maps and structs                          |
+/// | `composite` | `V(Vec<(String, Self)>)` | structs and tuples                        |
+///
+/// The numeric conversions are those of `serde_db::de::numeric`.
+/// `DbValue::value_kind()` and `DbValue::is_empty_string()` are derived from the annotations
+/// as well, so that the values are deserialized into the matching variant of `serde_db::Value`.
+/// Drivers that need to override further methods of `DbValue` implement the traits manually.
+#[proc_macro_derive(DbValue, attributes(db_value))]
+pub fn derive_db_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    db_value::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `serde_db::ser::DbvFactory` for an enum of parameter descriptors,
+/// and for references to it, based on annotations that declare for each variant
+/// the constructor of the database value it produces.
+///
+/// ```rust,ignore
+/// use serde_db::ser::DbvFactory;
+///
+/// #[derive(DbvFactory)]
+/// #[dbv_factory(value = MyDbValue, null = MyDbValue::Null)]
+/// pub enum MyParameterType {
+///     #[dbv_factory(MyDbValue::Boolean(bool))]
+///     Boolean,
+///     #[dbv_factory(MyDbValue::SmallInt(i16), nullable)]
+///     NullableSmallInt,
+///     #[dbv_factory(MyDbValue::Double(f64))]
+///     Double,
+///     #[dbv_factory(MyDbValue::Varchar(String), nullable)]
+///     NullableVarchar,
+///     #[dbv_factory(MyDbValue::Blob(Vec<u8>))]
+///     Blob,
+///     // variants without annotation accept no values
+///     Cursor,
+/// }
+/// ```
+///
+/// The constructor takes one of `bool`, the integer types up to 64 bit, `f32`, `f64`,
+/// `String`, or `Vec<u8>`. The generated methods accept
+///
+/// | constructor takes | accepted rust values                                          |
+/// |-------------------|---------------------------------------------------------------|
+/// | `bool`            | `bool`, parsed `&str`                                         |
+/// | an integer type   | integers (with range checks), parsed `&str`                   |
+/// | `f32` or `f64`    | integers and floats (with range and precision checks), parsed `&str` |
+/// | `String`          | all values except bytes, in their textual representation      |
+/// | `Vec<u8>`         | bytes                                                         |
+///
+/// and fail with `SerializationError::Range` for values out of range,
+/// with `SerializationError::Parse` for text that cannot be parsed,
+/// and with `type_error()` for all other values. `None` is accepted by `nullable` variants,
+/// which then produce the value given with `null`.
+/// `descriptor()` returns the name of the variant.
+#[proc_macro_derive(DbvFactory, attributes(dbv_factory))]
+pub fn derive_dbv_factory(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    dbv_factory::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `serde_db::de::FromRow` for a struct with named fields, which allows creating
+/// it from database rows without the overhead of the serde layers.
+///
+/// ```rust,ignore
+/// use serde_db::de::FromRow;
+///
+/// #[derive(FromRow)]
+/// pub struct Person {
+///     id: u32,
+///     name: String,
+///     nickname: Option<String>,
+///     #[from_row(rename = "BIRTHDAY", serde)]
+///     birthday: chrono::NaiveDate,
+/// }
+/// ```
+///
+/// The columns are expected in the order of the fields, with the names of the fields;
+/// rows with other column orders are matched by name.
+///
+/// Fields are converted with `DbValueInto`, which supports `bool`, the integer types up to
+/// 64 bit, `f32`, `f64`, `String`, and `Vec<u8>`, and `Option`s of these.
+/// Fields of other types must be annotated with `serde`, and are converted with
+/// `DbValue::try_into_with()`.
+/// `rename` specifies the name of the column, if it differs from the name of the field.
+#[proc_macro_derive(FromRow, attributes(from_row))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_row::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,139 @@
+//! Support for transporting date and time values between the database driver and
+//! the date and time types of `chrono` (feature `chrono`) and `time` (feature `time`),
+//! without detours through strings.
+//!
+//! Drivers with a binary representation of dates, times, and timestamps override
+//! [`DbValue::try_into_datetime()`](../de/trait.DbValue.html#method.try_into_datetime) and
+//! [`DbvFactory::serialize_datetime()`](../ser/trait.DbvFactory.html#method.serialize_datetime),
+//! and exchange the value as a [`DbDateTime`](enum.DbDateTime.html).
+//! The default implementations of these methods fall back to the driver's string conversions,
+//! using the ISO 8601 formats.
+//!
+//! Date, time, and timestamp fields use the transport by naming this module
+//! (or its submodule [`option`](option/index.html) for `Option`s) in a serde attribute:
+//!
+//! ```rust,ignore
+//! use chrono::{DateTime, NaiveDate, Utc};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Booking {
+//!     #[serde(with = "serde_db::datetime")]
+//!     day: NaiveDate,
+//!     #[serde(with = "serde_db::datetime::option")]
+//!     cancelled_at: Option<DateTime<Utc>>,
+//! }
+//! ```
+//!
+//! Other serde formats see such fields as newtype structs around the binary form of
+//! a [`DbDateTime`](enum.DbDateTime.html), and not as the ISO 8601 strings that `chrono`'s
+//! and `time`'s own serde implementations produce.
+//!
+//! Timestamps without time zone information are converted into time-zone-aware types
+//! (`chrono`'s `DateTime<Utc>`, `DateTime<Local>`, `DateTime<FixedOffset>`,
+//! and `time`'s `OffsetDateTime`)
+//! according to the [`NaiveTimestampPolicy`](enum.NaiveTimestampPolicy.html)
+//! of the [`DeserializationConfig`](../de/struct.DeserializationConfig.html).
+
+#[cfg(feature = "chrono")]
+mod chrono_types;
+mod date_time_type;
+mod db_datetime;
+#[cfg(feature = "time")]
+mod time_types;
+
+pub use self::date_time_type::DateTimeType;
+pub use self::db_datetime::{DbDate, DbDateTime, DbTime, NaiveTimestampPolicy};
+
+use crate::de::ConversionError;
+use crate::transport::{self, binary};
+use std::marker::PhantomData;
+
+/// Serializes a date or time value as a `DbDateTime`, which the driver receives in
+/// [`DbvFactory::serialize_datetime()`](../ser/trait.DbvFactory.html#method.serialize_datetime).
+///
+/// # Errors
+///
+/// `S::Error` if the value cannot be represented as a `DbDateTime`, or if serialization fails.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: DateTimeType,
+    S: serde::Serializer,
+{
+    binary::serialize::<DateTimes<T>, S>(value, serializer)
+}
+
+/// Deserializes a date or time value from the `DbDateTime` that the driver provides with
+/// [`DbValue::try_into_datetime()`](../de/trait.DbValue.html#method.try_into_datetime).
+///
+/// Naive timestamps are converted into time-zone-aware types according to the
+/// `NaiveTimestampPolicy` of the `DeserializationConfig`.
+///
+/// # Errors
+///
+/// `D::Error` if the value cannot be converted into the target type.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DateTimeType,
+    D: serde::Deserializer<'de>,
+{
+    binary::deserialize::<DateTimes<T>, D>(deserializer)
+}
+
+/// Variants of [`serialize`] and [`deserialize`]
+/// for nullable date and time columns.
+pub mod option {
+    use super::{DateTimeType, DateTimes};
+    use crate::transport::binary;
+
+    /// Serializes `None` as NULL, and other values like
+    /// [`datetime::serialize`](super::serialize).
+    ///
+    /// # Errors
+    ///
+    /// `S::Error` if the value cannot be represented as a `DbDateTime`,
+    /// or if serialization fails.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: DateTimeType,
+        S: serde::Serializer,
+    {
+        binary::serialize_option::<DateTimes<T>, S>(value.as_ref(), serializer)
+    }
+
+    /// Deserializes NULL as `None`, and other values like
+    /// [`datetime::deserialize`](super::deserialize).
+    ///
+    /// # Errors
+    ///
+    /// `D::Error` if the value cannot be converted into the target type.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: DateTimeType,
+        D: serde::Deserializer<'de>,
+    {
+        binary::deserialize_option::<DateTimes<T>, D>(deserializer)
+    }
+}
+
+// Transports a DateTimeType as the bytes of a DbDateTime, together with the
+// NaiveTimestampPolicy of the deserializer.
+struct DateTimes<T>(PhantomData<T>);
+
+impl<T: DateTimeType> binary::Codec for DateTimes<T> {
+    type Value = T;
+    const NAME: &'static str = transport::DATETIME;
+    const EXPECTING: &'static str = "a date or time value";
+
+    fn encode(value: &T) -> Result<Vec<u8>, ConversionError> {
+        Ok(value
+            .to_db_datetime()?
+            .to_bytes(NaiveTimestampPolicy::default())
+            .to_vec())
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Result<T, ConversionError>> {
+        DbDateTime::from_bytes(bytes).map(|(dbdt, policy)| T::from_db_datetime(dbdt, policy))
+    }
+}
@@ -0,0 +1,156 @@
+use super::{DateTimeType, DbDate, DbDateTime, DbTime, NaiveTimestampPolicy};
+use crate::de::ConversionError;
+use chrono::{
+    DateTime, Datelike, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    Offset, TimeZone, Timelike, Utc,
+};
+
+impl DateTimeType for NaiveDate {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        Ok(DbDateTime::Date(db_date(*self)))
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        _policy: NaiveTimestampPolicy,
+    ) -> Result<NaiveDate, ConversionError> {
+        match dbdt {
+            DbDateTime::Date(date) => naive_date(date),
+            dbdt => Err(type_error(dbdt, "NaiveDate")),
+        }
+    }
+}
+
+impl DateTimeType for NaiveTime {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        Ok(DbDateTime::Time(db_time(*self)))
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        _policy: NaiveTimestampPolicy,
+    ) -> Result<NaiveTime, ConversionError> {
+        match dbdt {
+            DbDateTime::Time(time) => naive_time(time),
+            dbdt => Err(type_error(dbdt, "NaiveTime")),
+        }
+    }
+}
+
+impl DateTimeType for NaiveDateTime {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        Ok(DbDateTime::Timestamp(
+            db_date(self.date()),
+            db_time(self.time()),
+        ))
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        _policy: NaiveTimestampPolicy,
+    ) -> Result<NaiveDateTime, ConversionError> {
+        match dbdt {
+            DbDateTime::Date(date) => Ok(naive_date(date)?.and_time(NaiveTime::MIN)),
+            DbDateTime::Timestamp(date, time) => Ok(naive_date(date)?.and_time(naive_time(time)?)),
+            dbdt => Err(type_error(dbdt, "NaiveDateTime")),
+        }
+    }
+}
+
+impl DateTimeType for DateTime<FixedOffset> {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        let local = self.naive_local();
+        Ok(DbDateTime::TimestampWithOffset(
+            db_date(local.date()),
+            db_time(local.time()),
+            self.offset().local_minus_utc(),
+        ))
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        policy: NaiveTimestampPolicy,
+    ) -> Result<DateTime<FixedOffset>, ConversionError> {
+        match dbdt {
+            DbDateTime::TimestampWithOffset(date, time, offset) => {
+                let naive = naive_date(date)?.and_time(naive_time(time)?);
+                FixedOffset::east_opt(offset)
+                    .and_then(|offset| offset.from_local_datetime(&naive).single())
+                    .ok_or_else(|| range_error(dbdt))
+            }
+            DbDateTime::Timestamp(date, time) => {
+                let naive = naive_date(date)?.and_time(naive_time(time)?);
+                match policy {
+                    NaiveTimestampPolicy::AssumeUtc => {
+                        Ok(Utc.from_utc_datetime(&naive).fixed_offset())
+                    }
+                    NaiveTimestampPolicy::AssumeLocal => match Local.from_local_datetime(&naive) {
+                        LocalResult::Single(dt) => Ok(dt),
+                        // the order of the pair is not reliable, so earliest() is not used
+                        LocalResult::Ambiguous(dt1, dt2) => Ok(dt1.min(dt2)),
+                        LocalResult::None => Err(range_error(dbdt)),
+                    }
+                    .map(|dt| dt.with_timezone(&dt.offset().fix())),
+                    NaiveTimestampPolicy::Reject => Err(ConversionError::ValueType(format!(
+                        "timestamp {dbdt} has no time zone information"
+                    ))),
+                }
+            }
+            dbdt => Err(type_error(dbdt, "DateTime")),
+        }
+    }
+}
+
+impl DateTimeType for DateTime<Utc> {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        self.fixed_offset().to_db_datetime()
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        policy: NaiveTimestampPolicy,
+    ) -> Result<DateTime<Utc>, ConversionError> {
+        DateTime::<FixedOffset>::from_db_datetime(dbdt, policy).map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
+impl DateTimeType for DateTime<Local> {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        self.fixed_offset().to_db_datetime()
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        policy: NaiveTimestampPolicy,
+    ) -> Result<DateTime<Local>, ConversionError> {
+        DateTime::<FixedOffset>::from_db_datetime(dbdt, policy).map(|dt| dt.with_timezone(&Local))
+    }
+}
+
+fn db_date(date: NaiveDate) -> DbDate {
+    DbDate::new(date.year(), date.month(), date.day())
+}
+
+fn db_time(time: NaiveTime) -> DbTime {
+    DbTime::new(time.hour(), time.minute(), time.second(), time.nanosecond())
+}
+
+fn naive_date(date: DbDate) -> Result<NaiveDate, ConversionError> {
+    NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())
+        .ok_or_else(|| range_error(DbDateTime::Date(date)))
+}
+
+fn naive_time(time: DbTime) -> Result<NaiveTime, ConversionError> {
+    NaiveTime::from_hms_nano_opt(time.hour(), time.minute(), time.second(), time.nanosecond())
+        .ok_or_else(|| range_error(DbDateTime::Time(time)))
+}
+
+fn type_error(dbdt: DbDateTime, target: &str) -> ConversionError {
+    ConversionError::ValueType(format!(
+        "{dbdt} cannot be converted into a chrono::{target}"
+    ))
+}
+
+fn range_error(dbdt: DbDateTime) -> ConversionError {
+    ConversionError::NumberRange(format!("{dbdt} is not a valid chrono value"))
+}
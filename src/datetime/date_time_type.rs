@@ -0,0 +1,26 @@
+use super::{DbDateTime, NaiveTimestampPolicy};
+use crate::de::ConversionError;
+
+/// Rust date and time types that can be converted from and into a
+/// [`DbDateTime`](enum.DbDateTime.html).
+pub trait DateTimeType: Sized {
+    /// Converts the value into a `DbDateTime`.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be represented as `DbDateTime`.
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError>;
+
+    /// Converts a `DbDateTime` into a value of this type.
+    ///
+    /// The `policy` is applied if a timestamp without time zone information is to be converted
+    /// into a time-zone-aware type.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into this type.
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        policy: NaiveTimestampPolicy,
+    ) -> Result<Self, ConversionError>;
+}
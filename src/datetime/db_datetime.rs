@@ -0,0 +1,345 @@
+use crate::de::ConversionError;
+
+/// Transport type for a calendar date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DbDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl DbDate {
+    /// Constructs a `DbDate`; `month` and `day` are 1-based.
+    ///
+    /// The values are not validated here, but when the `DbDate` is converted into a
+    /// rust date type.
+    #[must_use]
+    pub fn new(year: i32, month: u32, day: u32) -> DbDate {
+        DbDate { year, month, day }
+    }
+
+    /// Returns the year.
+    #[must_use]
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month (1-based).
+    #[must_use]
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month (1-based).
+    #[must_use]
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+/// Transport type for a time of day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DbTime {
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+}
+
+impl DbTime {
+    /// Constructs a `DbTime`.
+    ///
+    /// The values are not validated here, but when the `DbTime` is converted into a
+    /// rust time type.
+    #[must_use]
+    pub fn new(hour: u32, minute: u32, second: u32, nanosecond: u32) -> DbTime {
+        DbTime {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        }
+    }
+
+    /// Returns the hour.
+    #[must_use]
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute.
+    #[must_use]
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// Returns the second.
+    #[must_use]
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+
+    /// Returns the fraction of the second, in nanoseconds.
+    #[must_use]
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+}
+
+/// Transport type for date and time values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DbDateTime {
+    /// A date.
+    Date(DbDate),
+    /// A time of day.
+    Time(DbTime),
+    /// A timestamp without time zone information.
+    Timestamp(DbDate, DbTime),
+    /// A timestamp in local time, with its offset to UTC in seconds (positive east of UTC).
+    TimestampWithOffset(DbDate, DbTime, i32),
+}
+
+/// Policy for converting timestamps without time zone information into
+/// time-zone-aware rust types (like `chrono::DateTime<Utc>`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NaiveTimestampPolicy {
+    /// Timestamps without time zone information are interpreted as UTC.
+    #[default]
+    AssumeUtc,
+    /// Timestamps without time zone information are interpreted as local time
+    /// of the application.
+    ///
+    /// Local times that do not exist (because a DST transition skips them) are rejected;
+    /// local times that occur twice (because a DST transition repeats them) are converted
+    /// into the earlier instant.
+    /// With `time`, the local offset cannot be determined in multithreaded processes on most
+    /// Unix systems (see `time::UtcOffset::local_offset_at`); the conversion then fails
+    /// with a `ConversionError`.
+    AssumeLocal,
+    /// Timestamps without time zone information are not converted, a `ConversionError`
+    /// is returned instead.
+    Reject,
+}
+
+const TAG_DATE: u8 = 1;
+const TAG_TIME: u8 = 2;
+const TAG_TIMESTAMP: u8 = 3;
+const TAG_TIMESTAMP_WITH_OFFSET: u8 = 4;
+const BYTES_LEN: usize = 34;
+
+impl DbDateTime {
+    // The policy is added to the transport because it is evaluated by the target type.
+    pub(crate) fn to_bytes(self, policy: NaiveTimestampPolicy) -> [u8; BYTES_LEN] {
+        let (tag, date, time, offset) = match self {
+            DbDateTime::Date(date) => (TAG_DATE, Some(date), DbTime::default(), 0),
+            DbDateTime::Time(time) => (TAG_TIME, None, time, 0),
+            DbDateTime::Timestamp(date, time) => (TAG_TIMESTAMP, Some(date), time, 0),
+            DbDateTime::TimestampWithOffset(date, time, offset) => {
+                (TAG_TIMESTAMP_WITH_OFFSET, Some(date), time, offset)
+            }
+        };
+        let date = date.unwrap_or(DbDate::new(0, 0, 0));
+
+        let mut bytes = [0_u8; BYTES_LEN];
+        bytes[0] = tag;
+        bytes[1] = match policy {
+            NaiveTimestampPolicy::AssumeUtc => 0,
+            NaiveTimestampPolicy::AssumeLocal => 1,
+            NaiveTimestampPolicy::Reject => 2,
+        };
+        bytes[2..6].copy_from_slice(&date.year.to_le_bytes());
+        for (i, v) in [
+            date.month,
+            date.day,
+            time.hour,
+            time.minute,
+            time.second,
+            time.nanosecond,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            bytes[6 + 4 * i..10 + 4 * i].copy_from_slice(&v.to_le_bytes());
+        }
+        bytes[30..].copy_from_slice(&offset.to_le_bytes());
+        bytes
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<(DbDateTime, NaiveTimestampPolicy)> {
+        if bytes.len() != BYTES_LEN {
+            return None;
+        }
+        let u32_at = |pos: usize| bytes[pos..pos + 4].try_into().ok().map(u32::from_le_bytes);
+        let date = DbDate::new(
+            i32::from_le_bytes(bytes[2..6].try_into().ok()?),
+            u32_at(6)?,
+            u32_at(10)?,
+        );
+        let time = DbTime::new(u32_at(14)?, u32_at(18)?, u32_at(22)?, u32_at(26)?);
+        let offset = i32::from_le_bytes(bytes[30..].try_into().ok()?);
+
+        let policy = match bytes[1] {
+            0 => NaiveTimestampPolicy::AssumeUtc,
+            1 => NaiveTimestampPolicy::AssumeLocal,
+            2 => NaiveTimestampPolicy::Reject,
+            _ => return None,
+        };
+        let dbdt = match bytes[0] {
+            TAG_DATE => DbDateTime::Date(date),
+            TAG_TIME => DbDateTime::Time(time),
+            TAG_TIMESTAMP => DbDateTime::Timestamp(date, time),
+            TAG_TIMESTAMP_WITH_OFFSET => DbDateTime::TimestampWithOffset(date, time, offset),
+            _ => return None,
+        };
+        Some((dbdt, policy))
+    }
+}
+
+impl std::fmt::Display for DbDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.year < 0 {
+            write!(f, "-")?;
+        }
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.year.unsigned_abs(),
+            self.month,
+            self.day
+        )
+    }
+}
+
+impl std::fmt::Display for DbTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        if self.nanosecond > 0 {
+            write!(f, ".{:09}", self.nanosecond)?;
+        }
+        Ok(())
+    }
+}
+
+/// Uses the ISO 8601 formats, like `2024-10-13`, `23:59:01.5`, `2024-10-13T23:59:01`, or
+/// `2024-10-13T23:59:01+02:00`; offsets with seconds are written as `+hh:mm:ss`.
+impl std::fmt::Display for DbDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DbDateTime::Date(date) => write!(f, "{date}"),
+            DbDateTime::Time(time) => write!(f, "{time}"),
+            DbDateTime::Timestamp(date, time) => write!(f, "{date}T{time}"),
+            DbDateTime::TimestampWithOffset(date, time, offset) => {
+                let sign = if *offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                write!(
+                    f,
+                    "{date}T{time}{sign}{:02}:{:02}",
+                    offset / 3600,
+                    offset % 3600 / 60
+                )?;
+                if offset % 60 > 0 {
+                    write!(f, ":{:02}", offset % 60)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Parses the formats produced by `Display`; the separator between date and time can
+/// also be a blank, and `Z` is accepted as offset of UTC.
+impl std::str::FromStr for DbDateTime {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<DbDateTime, ConversionError> {
+        let parse_error =
+            || ConversionError::ValueType(format!("cannot parse {s:?} as date or time"));
+        let s = s.trim();
+        if s.len() > 4 && s.as_bytes()[2] == b':' {
+            return Ok(DbDateTime::Time(parse_time(s).ok_or_else(parse_error)?));
+        }
+        match s.find(['T', ' ']) {
+            None => Ok(DbDateTime::Date(parse_date(s).ok_or_else(parse_error)?)),
+            Some(idx) => {
+                let date = parse_date(&s[..idx]).ok_or_else(parse_error)?;
+                let time_and_offset = &s[idx + 1..];
+                match time_and_offset.find(['Z', '+', '-']) {
+                    None => Ok(DbDateTime::Timestamp(
+                        date,
+                        parse_time(time_and_offset).ok_or_else(parse_error)?,
+                    )),
+                    Some(idx) => Ok(DbDateTime::TimestampWithOffset(
+                        date,
+                        parse_time(&time_and_offset[..idx]).ok_or_else(parse_error)?,
+                        parse_offset(&time_and_offset[idx..]).ok_or_else(parse_error)?,
+                    )),
+                }
+            }
+        }
+    }
+}
+
+fn parse_date(s: &str) -> Option<DbDate> {
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s),
+    };
+    let mut parts = s.splitn(3, '-');
+    let year: i32 = parts
+        .next()
+        .filter(|year| year.bytes().all(|b| b.is_ascii_digit()))?
+        .parse()
+        .ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    Some(DbDate::new(sign * year, month, day))
+}
+
+fn parse_time(s: &str) -> Option<DbTime> {
+    let (hms, fraction) = s.split_once('.').unwrap_or((s, ""));
+    let mut parts = hms.splitn(3, ':');
+    let hour = parts.next()?.parse().ok()?;
+    let minute = parts.next()?.parse().ok()?;
+    let second = parts.next().map_or(Some(0), |s| s.parse().ok())?;
+    let nanosecond = if fraction.is_empty() {
+        0
+    } else if fraction.len() <= 9 && fraction.bytes().all(|b| b.is_ascii_digit()) {
+        format!("{fraction:0<9}").parse().ok()?
+    } else {
+        return None;
+    };
+    Some(DbTime::new(hour, minute, second, nanosecond))
+}
+
+// Accepts `Z`, `+hh`, `+hhmm`, `+hh:mm`, and `+hh:mm:ss` (and the same with `-`).
+fn parse_offset(s: &str) -> Option<i32> {
+    if s == "Z" {
+        return Some(0);
+    }
+    let (sign, hms) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let (hours, minutes, seconds) = if hms.len() == 4 && hms.bytes().all(|b| b.is_ascii_digit()) {
+        (&hms[..2], &hms[2..], "0")
+    } else {
+        let mut parts = hms.splitn(3, ':');
+        (
+            parts.next()?,
+            parts.next().unwrap_or("0"),
+            parts.next().unwrap_or("0"),
+        )
+    };
+    let seconds =
+        offset_part(hours, 23)? * 3600 + offset_part(minutes, 59)? * 60 + offset_part(seconds, 59)?;
+    Some(sign * seconds)
+}
+
+// One or two ASCII digits, with a value of at most max.
+fn offset_part(s: &str, max: i32) -> Option<i32> {
+    if s.is_empty() || s.len() > 2 || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok().filter(|v| *v <= max)
+}
@@ -0,0 +1,180 @@
+use super::{DateTimeType, DbDate, DbDateTime, DbTime, NaiveTimestampPolicy};
+use crate::de::ConversionError;
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+impl DateTimeType for Date {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        Ok(DbDateTime::Date(db_date(*self)))
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        _policy: NaiveTimestampPolicy,
+    ) -> Result<Date, ConversionError> {
+        match dbdt {
+            DbDateTime::Date(date) => time_date(date),
+            dbdt => Err(type_error(dbdt, "Date")),
+        }
+    }
+}
+
+impl DateTimeType for Time {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        Ok(DbDateTime::Time(db_time(*self)))
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        _policy: NaiveTimestampPolicy,
+    ) -> Result<Time, ConversionError> {
+        match dbdt {
+            DbDateTime::Time(time) => time_time(time),
+            dbdt => Err(type_error(dbdt, "Time")),
+        }
+    }
+}
+
+impl DateTimeType for PrimitiveDateTime {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        Ok(DbDateTime::Timestamp(
+            db_date(self.date()),
+            db_time(self.time()),
+        ))
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        _policy: NaiveTimestampPolicy,
+    ) -> Result<PrimitiveDateTime, ConversionError> {
+        match dbdt {
+            DbDateTime::Date(date) => Ok(PrimitiveDateTime::new(time_date(date)?, Time::MIDNIGHT)),
+            DbDateTime::Timestamp(date, time) => {
+                Ok(PrimitiveDateTime::new(time_date(date)?, time_time(time)?))
+            }
+            dbdt => Err(type_error(dbdt, "PrimitiveDateTime")),
+        }
+    }
+}
+
+impl DateTimeType for OffsetDateTime {
+    fn to_db_datetime(&self) -> Result<DbDateTime, ConversionError> {
+        Ok(DbDateTime::TimestampWithOffset(
+            db_date(self.date()),
+            db_time(self.time()),
+            self.offset().whole_seconds(),
+        ))
+    }
+
+    fn from_db_datetime(
+        dbdt: DbDateTime,
+        policy: NaiveTimestampPolicy,
+    ) -> Result<OffsetDateTime, ConversionError> {
+        match dbdt {
+            DbDateTime::TimestampWithOffset(date, time, offset) => {
+                let primitive = PrimitiveDateTime::new(time_date(date)?, time_time(time)?);
+                UtcOffset::from_whole_seconds(offset)
+                    .map(|offset| primitive.assume_offset(offset))
+                    .map_err(|_| range_error(dbdt))
+            }
+            DbDateTime::Timestamp(date, time) => {
+                let primitive = PrimitiveDateTime::new(time_date(date)?, time_time(time)?);
+                match policy {
+                    NaiveTimestampPolicy::AssumeUtc => Ok(primitive.assume_utc()),
+                    NaiveTimestampPolicy::AssumeLocal => assume_local(primitive, dbdt),
+                    NaiveTimestampPolicy::Reject => Err(ConversionError::ValueType(format!(
+                        "timestamp {dbdt} has no time zone information"
+                    ))),
+                }
+            }
+            dbdt => Err(type_error(dbdt, "OffsetDateTime")),
+        }
+    }
+}
+
+// The offset of the local time zone is looked up for an instant, so the wall-clock time is
+// tried with the local offsets one day before and after it, which differ around a DST transition;
+// an offset fits if it is the local offset at the resulting instant.
+// A wall-clock time that is skipped by a DST transition has no fitting offset and is rejected;
+// one that is repeated has two, and the earlier instant is taken.
+fn assume_local(
+    primitive: PrimitiveDateTime,
+    dbdt: DbDateTime,
+) -> Result<OffsetDateTime, ConversionError> {
+    let local_offset_at = |datetime: OffsetDateTime| {
+        UtcOffset::local_offset_at(datetime).map_err(|e| {
+            ConversionError::Other(
+                format!(
+                    "local offset of {dbdt} cannot be determined ({e}); the time crate refuses \
+                     to determine it in multithreaded processes on most Unix systems, \
+                     consider NaiveTimestampPolicy::AssumeUtc"
+                )
+                .into(),
+            )
+        })
+    };
+    let guess = primitive.assume_utc();
+    let mut earliest: Option<OffsetDateTime> = None;
+    for instant in [
+        guess.checked_sub(Duration::DAY).unwrap_or(guess),
+        guess.checked_add(Duration::DAY).unwrap_or(guess),
+    ] {
+        let candidate = primitive.assume_offset(local_offset_at(instant)?);
+        if local_offset_at(candidate)? != candidate.offset() {
+            continue;
+        }
+        match earliest {
+            Some(earliest) if earliest <= candidate => {}
+            _ => earliest = Some(candidate),
+        }
+    }
+    earliest.ok_or_else(|| {
+        ConversionError::ValueType(format!("{dbdt} does not exist in the local time zone"))
+    })
+}
+
+fn db_date(date: Date) -> DbDate {
+    DbDate::new(
+        date.year(),
+        u32::from(u8::from(date.month())),
+        u32::from(date.day()),
+    )
+}
+
+fn db_time(time: Time) -> DbTime {
+    DbTime::new(
+        u32::from(time.hour()),
+        u32::from(time.minute()),
+        u32::from(time.second()),
+        time.nanosecond(),
+    )
+}
+
+fn time_date(date: DbDate) -> Result<Date, ConversionError> {
+    let range_error = || range_error(DbDateTime::Date(date));
+    let month = u8::try_from(date.month())
+        .ok()
+        .and_then(|month| Month::try_from(month).ok())
+        .ok_or_else(range_error)?;
+    let day = u8::try_from(date.day()).map_err(|_| range_error())?;
+    Date::from_calendar_date(date.year(), month, day).map_err(|_| range_error())
+}
+
+fn time_time(time: DbTime) -> Result<Time, ConversionError> {
+    let range_error = || range_error(DbDateTime::Time(time));
+    let to_u8 = |v: u32| u8::try_from(v).map_err(|_| range_error());
+    Time::from_hms_nano(
+        to_u8(time.hour())?,
+        to_u8(time.minute())?,
+        to_u8(time.second())?,
+        time.nanosecond(),
+    )
+    .map_err(|_| range_error())
+}
+
+fn type_error(dbdt: DbDateTime, target: &str) -> ConversionError {
+    ConversionError::ValueType(format!("{dbdt} cannot be converted into a time::{target}"))
+}
+
+fn range_error(dbdt: DbDateTime) -> ConversionError {
+    ConversionError::NumberRange(format!("{dbdt} is not a valid time value"))
+}
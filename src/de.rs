@@ -73,6 +73,17 @@
 //! let s: String = result set.try_into()?;
 //! ```
 //!
+//! ## Convert a result set with zero or one rows into an `Option`:
+//!
+//! ```rust,ignore
+//! // None if no row was found, an error if more than one row was found
+//! let maybe_user: Option<User> = result set.try_into()?;
+//! ```
+//!
+//! If the rows have a single field, a NULL value is converted into `None` as well.
+//! Use `Option<Option<T>>` if you need to distinguish a missing row (`None`)
+//! from a NULL value (`Some(None)`).
+//!
 //! # Rows
 //!
 //! ## Convert rows into tuples or structs
@@ -93,6 +104,16 @@
 //! }
 //! ```
 //!
+//! ## Convert rows without serde
+//!
+//! For high-volume queries, types that implement [`FromRow`](trait.FromRow.html)
+//! (e.g. with `#[derive(FromRow)]`, feature `derive`) are created directly from the
+//! values of the rows, which avoids the overhead of the serde layers:
+//!
+//! ```rust,ignore
+//! let data: Vec<MyStruct> = result set.try_into_rows()?;
+//! ```
+//!
 //! # Individual values
 //!
 //! When necessary, you can also convert individual values directly into an adequate rust type:
@@ -105,6 +126,11 @@
 //! }
 //! ```
 //!
+//! # Large objects
+//!
+//! Large values (BLOBs, CLOBs, ...) can be streamed rather than materialized in memory
+//! by using [`Lob`](struct.Lob.html) as the field type.
+//!
 //! # Note for implementors
 //!
 //! Implementing DB drivers need
@@ -121,13 +147,19 @@
 //! By this, the deserialization functionality of `serde_db` can be provided
 //! to the users of the DB driver without forcing them to import `serde_db`.
 
+mod composite_row;
 mod conversion_error;
 mod db_value;
 mod db_value_into;
 mod deserializable_result_set;
 mod deserializable_row;
+mod deserialization_config;
 mod deserialization_error;
 mod field_deserializer;
+mod from_row;
+mod limits;
+mod lob;
+pub mod numeric;
 mod row_deserializer;
 mod rs_deserializer;
 
@@ -136,4 +168,9 @@ pub use self::db_value::DbValue;
 pub use self::db_value_into::DbValueInto;
 pub use self::deserializable_result_set::DeserializableResultSet;
 pub use self::deserializable_row::DeserializableRow;
+pub use self::deserialization_config::{DeserializationConfig, NullPolicy};
 pub use self::deserialization_error::{DeserializationError, DeserializationResult};
+pub use self::from_row::{FromRow, RowFields};
+pub use self::lob::Lob;
+#[cfg(feature = "derive")]
+pub use serde_db_derive::{DbValue, FromRow};
@@ -0,0 +1,38 @@
+use crate::de::{DbValue, DeserializableRow, DeserializationError};
+
+// The fields of a composite value, presented as a row to the RowDeserializer.
+pub(crate) struct CompositeRow<DBV> {
+    field_names: Vec<String>,
+    values: std::vec::IntoIter<DBV>,
+}
+
+impl<DBV> CompositeRow<DBV> {
+    pub(crate) fn new(fields: Vec<(String, DBV)>) -> Self {
+        let (field_names, values): (Vec<String>, Vec<DBV>) = fields.into_iter().unzip();
+        CompositeRow {
+            field_names,
+            values: values.into_iter(),
+        }
+    }
+}
+
+impl<DBV: DbValue> DeserializableRow for CompositeRow<DBV> {
+    type Error = DeserializationError;
+    type Value = DBV;
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn next(&mut self) -> Option<DBV> {
+        self.values.next()
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.field_names.len()
+    }
+
+    fn field_name(&self, field_idx: usize) -> Option<&str> {
+        self.field_names.get(field_idx).map(String::as_str)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::de::field_deserializer::FieldDeserializer;
-use crate::de::{DbValueInto, DeserializationError};
+use crate::de::limits::{ByteBudget, Tally};
+use crate::de::{ConversionError, DbValueInto, DeserializationConfig, DeserializationError, Lob};
+use crate::value::ValueKind;
 use std::marker::Sized;
 
 /// Provides the conversion of a database value into a standard rust type.
@@ -22,6 +24,189 @@ pub trait DbValue:
     /// Returns true if this is a NULL value.
     fn is_null(&self) -> bool;
 
+    /// Returns true if this is an empty string.
+    ///
+    /// The default implementation returns `false`.
+    /// Drivers should override this method to support
+    /// [`DeserializationConfig::with_empty_string_as_null()`](
+    /// struct.DeserializationConfig.html#method.with_empty_string_as_null).
+    fn is_empty_string(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this is a value of an array type.
+    ///
+    /// The default implementation returns `false`.
+    /// Drivers for databases with array types (e.g. `INTEGER[]`) should override this method
+    /// and [`try_into_elements()`](#method.try_into_elements).
+    fn is_array(&self) -> bool {
+        false
+    }
+
+    /// Converts a value of an array type into its elements.
+    ///
+    /// Array values can be deserialized into collections like `Vec<T>`, `HashSet<T>`,
+    /// or `Vec<Option<T>>`, where each element is converted like a plain `DbValue`.
+    ///
+    /// The default implementation returns an error.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value is not an array.
+    fn try_into_elements(self) -> Result<Vec<Self>, ConversionError> {
+        Err(ConversionError::ValueType(
+            "the value is not an array".to_string(),
+        ))
+    }
+
+    /// Returns true if this is a value of a composite type (e.g. a `ROW` or `STRUCT`).
+    ///
+    /// The default implementation returns `false`.
+    /// Drivers for databases with composite types should override this method
+    /// and [`try_into_fields()`](#method.try_into_fields).
+    fn is_composite(&self) -> bool {
+        false
+    }
+
+    /// Converts a value of a composite type into its named fields, in their defined order.
+    ///
+    /// Composite values can be deserialized into structs and tuples,
+    /// in the same way as the rows of a result set.
+    ///
+    /// The default implementation returns an error.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value is not a composite value.
+    fn try_into_fields(self) -> Result<Vec<(String, Self)>, ConversionError> {
+        Err(ConversionError::ValueType(
+            "the value is not a composite value".to_string(),
+        ))
+    }
+
+    /// Returns true if this is a value of a map type.
+    ///
+    /// The default implementation returns `false`.
+    /// Drivers for databases with key-value types (e.g. `hstore`, `MAP`) should override this
+    /// method and [`try_into_entries()`](#method.try_into_entries).
+    fn is_map(&self) -> bool {
+        false
+    }
+
+    /// Converts a value of a map type into its entries.
+    ///
+    /// Map values can be deserialized into maps like `HashMap<String, Option<String>>`,
+    /// or into structs, whose fields are then matched by the keys.
+    /// Each value is converted like a plain `DbValue`.
+    ///
+    /// The default implementation returns an error.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value is not a map.
+    fn try_into_entries(self) -> Result<Vec<(String, Self)>, ConversionError> {
+        Err(ConversionError::ValueType(
+            "the value is not a map".to_string(),
+        ))
+    }
+
+    /// Returns the kind of this (non-NULL) value, which determines the variant of
+    /// [`Value`](../value/enum.Value.html) into which it is deserialized.
+    ///
+    /// The default implementation returns `ValueKind::String`, so that every value
+    /// is represented with its `DbValueInto<String>` conversion.
+    /// Drivers that implement `DbValue` by hand must override this method based on
+    /// the value's database type; `#[derive(DbValue)]` derives it from the annotations.
+    fn value_kind(&self) -> ValueKind {
+        ValueKind::String
+    }
+
+    /// Converts the `DbValue` into an `i128`.
+    ///
+    /// The default implementation delegates to `DbValueInto<i64>`.
+    /// Drivers with database types that exceed the 64-bit range
+    /// (e.g. `DECIMAL(38,0)`) should override this method.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into an `i128`.
+    fn try_into_i128(self) -> Result<i128, ConversionError> {
+        DbValueInto::<i64>::try_into(self).map(i128::from)
+    }
+
+    /// Converts the `DbValue` into a `u128`.
+    ///
+    /// The default implementation delegates to `DbValueInto<u64>`.
+    /// Drivers with database types that exceed the 64-bit range
+    /// (e.g. `DECIMAL(38,0)`) should override this method.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into a `u128`.
+    fn try_into_u128(self) -> Result<u128, ConversionError> {
+        DbValueInto::<u64>::try_into(self).map(u128::from)
+    }
+
+    /// Converts the `DbValue` into a [`DbDecimal`](../decimal/struct.DbDecimal.html).
+    ///
+    /// The default implementation parses the result of `DbValueInto<String>`.
+    /// Drivers with a binary representation of decimal values should override this method.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into a `DbDecimal`.
+    #[cfg(feature = "decimal")]
+    fn try_into_decimal(self) -> Result<crate::decimal::DbDecimal, ConversionError> {
+        DbValueInto::<String>::try_into(self)?.parse()
+    }
+
+    /// Converts the `DbValue` into a [`DbDateTime`](../datetime/enum.DbDateTime.html).
+    ///
+    /// The default implementation parses the result of `DbValueInto<String>`,
+    /// which is expected in one of the ISO 8601 formats described for
+    /// [`DbDateTime`](../datetime/enum.DbDateTime.html#impl-FromStr-for-DbDateTime).
+    /// Drivers with a binary representation of date and time values should override
+    /// this method.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into a `DbDateTime`.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn try_into_datetime(self) -> Result<crate::datetime::DbDateTime, ConversionError> {
+        DbValueInto::<String>::try_into(self)?.parse()
+    }
+
+    /// Converts the `DbValue` into a `uuid::Uuid`.
+    ///
+    /// The default implementation parses the result of `DbValueInto<String>`.
+    /// Drivers that store UUIDs as 16-byte binaries should override this method.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into a `Uuid`.
+    #[cfg(feature = "uuid")]
+    fn try_into_uuid(self) -> Result<::uuid::Uuid, ConversionError> {
+        let s = DbValueInto::<String>::try_into(self)?;
+        ::uuid::Uuid::parse_str(&s)
+            .map_err(|e| ConversionError::ValueType(format!("{s:?} is not a valid UUID: {e}")))
+    }
+
+    /// Converts the `DbValue` into a [`Lob`], from which the content can be streamed.
+    ///
+    /// The default implementation materializes the value with `DbValueInto<Vec<u8>>`,
+    /// so the limit of `DeserializationConfig::with_max_lob_size()` is only checked
+    /// after the complete content was loaded.
+    /// Drivers that load large objects incrementally should override this method
+    /// and return a `Lob` that fetches the content on demand;
+    /// read errors of such a `Lob` should be reported as `std::io::Error`.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into a `Lob`.
+    fn try_into_lob(self) -> Result<Lob, ConversionError> {
+        Ok(Lob::from(DbValueInto::<Vec<u8>>::try_into(self)?))
+    }
+
     /// Converts the `DbValue` into a plain rust value.
     ///
     /// The generic implementation of this method is based on to-be-provided
@@ -35,6 +220,30 @@ pub trait DbValue:
     {
         #[cfg(feature = "trace")]
         log::trace!("DbValue::try_into");
-        serde::Deserialize::deserialize(FieldDeserializer::new(self))
+        self.try_into_with(&DeserializationConfig::default())
+    }
+
+    /// Converts the `DbValue` into a plain rust value, using the given configuration.
+    ///
+    /// # Errors
+    /// `DeserializationError` if the value cannot be converted into the target type.
+    fn try_into_with<'de, T>(
+        self,
+        config: &DeserializationConfig,
+    ) -> Result<T, DeserializationError>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DbValue::try_into_with");
+        let _budget = ByteBudget::install(config);
+        let mut tally = Tally::new(config);
+        tally.value_started();
+        let result = serde::Deserialize::deserialize(FieldDeserializer::new(self, config));
+        match result {
+            Ok(_) => tally.report(config.observer()),
+            Err(ref e) => config.observer().deserialization_failed(e),
+        }
+        result
     }
 }
@@ -37,6 +37,8 @@ use crate::de::conversion_error::ConversionError;
 ///     }
 ///  }
 /// ```
+///
+/// The module [`numeric`](numeric/index.html) provides these conversions ready-made.
 pub trait DbValueInto<T> {
     /// Converts the database value into the target rust type.
     ///
@@ -1,6 +1,13 @@
+use crate::de::from_row::{Layout, RowFields};
+use crate::de::limits::{self, ByteBudget};
+use crate::de::row_deserializer::RowDeserializer;
 use crate::de::rs_deserializer::RsDeserializer;
-use crate::de::{DeserializableRow, DeserializationError, DeserializationResult};
+use crate::de::{
+    DeserializableRow, DeserializationConfig, DeserializationError, DeserializationResult, FromRow,
+};
+use crate::value::{self, Table};
 use std::marker::Sized;
+use std::sync::Arc;
 
 /// Interface for a database result set to support deserialization.
 pub trait DeserializableResultSet: Sized {
@@ -53,8 +60,157 @@ pub trait DeserializableResultSet: Sized {
     {
         #[cfg(feature = "trace")]
         log::trace!("DeserializableResultSet::try_into()");
-        Ok(serde::Deserialize::deserialize(
-            &mut RsDeserializer::try_new(self)?,
-        )?)
+        self.try_into_with(&DeserializationConfig::default())
+    }
+
+    /// Like [`try_into()`](#method.try_into), but using the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible,
+    /// or if fetching fails.
+    fn try_into_with<'de, T>(self, config: &DeserializationConfig) -> Result<T, Self::Error>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultSet::try_into_with()");
+        #[cfg(feature = "tracing")]
+        let operation = crate::instrument::Operation::start(tracing::debug_span!(
+            "serde_db::deserialize_result_set",
+            columns = self.number_of_fields(),
+            rows = tracing::field::Empty,
+            duration_us = tracing::field::Empty
+        ));
+        let _budget = ByteBudget::install(config);
+        let result = RsDeserializer::deserialize(
+            self,
+            config,
+            #[cfg(feature = "tracing")]
+            &operation,
+        );
+        if let Err(ref e) = result {
+            config.observer().deserialization_failed(e);
+        }
+        #[cfg(feature = "tracing")]
+        operation.finish(&result);
+        Ok(result?)
+    }
+
+    /// A _provided method_ that converts all rows of the result set into a rust type
+    /// that implements [`FromRow`](trait.FromRow.html), without going through serde.
+    ///
+    /// The assignment of columns to fields is determined once for the result set.
+    ///
+    /// ```ignore
+    /// #[derive(FromRow)]
+    /// struct MyStruct {
+    ///     ...
+    /// }
+    /// let typed_result: Vec<MyStruct> = result_set.try_into_rows()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the columns of the result set do not match the fields,
+    /// if a value cannot be converted into the type of its field, or if fetching fails.
+    fn try_into_rows<T: FromRow>(self) -> Result<Vec<T>, Self::Error> {
+        self.try_into_rows_with(&DeserializationConfig::default())
+    }
+
+    /// Like [`try_into_rows()`](#method.try_into_rows), but using the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the columns of the result set do not match the fields,
+    /// if a value cannot be converted into the type of its field, or if fetching fails.
+    fn try_into_rows_with<T: FromRow>(
+        mut self,
+        config: &DeserializationConfig,
+    ) -> Result<Vec<T>, Self::Error> {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultSet::try_into_rows_with()");
+        #[cfg(feature = "tracing")]
+        let operation = crate::instrument::Operation::start(tracing::debug_span!(
+            "serde_db::deserialize_result_set",
+            columns = self.number_of_fields(),
+            rows = tracing::field::Empty,
+            duration_us = tracing::field::Empty
+        ));
+        let _budget = ByteBudget::install(config);
+        let mut result = Vec::new();
+        let outcome = Layout::new(T::COLUMNS, self.number_of_fields(), |idx| {
+            self.field_name(idx)
+        })
+        .and_then(|layout| {
+            while let Some(row) = self.next()? {
+                limits::check_rows(result.len() + 1, config)?;
+                result.push(RowFields::new(row, T::COLUMNS, &layout, config)?.convert()?);
+            }
+            Ok(())
+        });
+        if let Err(ref e) = outcome {
+            config.observer().deserialization_failed(e);
+        }
+        #[cfg(feature = "tracing")]
+        {
+            operation.record("rows", result.len());
+            operation.finish(&outcome);
+        }
+        outcome?;
+        Ok(result)
+    }
+
+    /// A _provided method_ that converts the result set into a [`Table`](../value/struct.Table.html)
+    /// of dynamically typed [`Value`](../value/enum.Value.html)s, with the names of the columns.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if a value cannot be converted, or if fetching fails.
+    fn try_into_table(self) -> Result<Table, Self::Error> {
+        self.try_into_table_with(&DeserializationConfig::default())
+    }
+
+    /// Like [`try_into_table()`](#method.try_into_table), but using the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if a value cannot be converted, or if fetching fails.
+    fn try_into_table_with(mut self, config: &DeserializationConfig) -> Result<Table, Self::Error> {
+        #[cfg(feature = "trace")]
+        log::trace!("DeserializableResultSet::try_into_table_with()");
+        #[cfg(feature = "tracing")]
+        let operation = crate::instrument::Operation::start(tracing::debug_span!(
+            "serde_db::deserialize_result_set",
+            columns = self.number_of_fields(),
+            rows = tracing::field::Empty,
+            duration_us = tracing::field::Empty
+        ));
+        let columns: Arc<[String]> = (0..self.number_of_fields())
+            .map(|idx| self.field_name(idx).unwrap_or_default().to_string())
+            .collect();
+        let _budget = ByteBudget::install(config);
+        let mut rows = Vec::new();
+        let mut fetch_rows = || -> DeserializationResult<()> {
+            while let Some(row) = self.next()? {
+                limits::check_rows(rows.len() + 1, config)?;
+                let values = RowDeserializer::deserialize_row(row, config, |rd| {
+                    serde::Deserialize::deserialize(rd)
+                })?;
+                rows.push(value::Row::new(Arc::clone(&columns), values));
+            }
+            Ok(())
+        };
+        let outcome = fetch_rows();
+        if let Err(ref e) = outcome {
+            config.observer().deserialization_failed(e);
+        }
+        #[cfg(feature = "tracing")]
+        {
+            operation.record("rows", rows.len());
+            operation.finish(&outcome);
+        }
+        outcome?;
+        Ok(Table::new(columns, rows))
     }
 }
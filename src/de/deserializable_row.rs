@@ -1,5 +1,6 @@
+use crate::de::limits::ByteBudget;
 use crate::de::row_deserializer::RowDeserializer;
-use crate::de::{DbValue, DeserializationError};
+use crate::de::{DbValue, DeserializationConfig, DeserializationError};
 use std::convert::From;
 use std::marker::Sized;
 
@@ -32,8 +33,30 @@ pub trait DeserializableRow: Sized {
     where
         T: serde::Deserialize<'de>,
     {
-        Ok(serde::Deserialize::deserialize(&mut RowDeserializer::new(
-            self,
-        ))?)
+        self.try_into_with(&DeserializationConfig::default())
+    }
+
+    /// Converts the row into a struct, a tuple, or (if applicable) into a plain rust value,
+    /// using the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if deserialization into the target type is not possible.
+    fn try_into_with<'de, T>(self, config: &DeserializationConfig) -> Result<T, Self::Error>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        let _budget = ByteBudget::install(config);
+        let result = RowDeserializer::deserialize_row(self, config, |rd| {
+            let result = serde::Deserialize::deserialize(rd);
+            // within the span of the row
+            #[cfg(feature = "tracing")]
+            crate::instrument::report(&result);
+            result
+        });
+        if let Err(ref e) = result {
+            config.observer().deserialization_failed(e);
+        }
+        Ok(result?)
     }
 }
@@ -0,0 +1,183 @@
+use crate::observer::{NoopObserver, Observer};
+use std::sync::Arc;
+
+/// Options that control the deserialization.
+///
+/// Use [`Default`] to get the standard behavior, and the `with_*` methods to deviate from it:
+///
+/// ```rust
+/// use serde_db::de::DeserializationConfig;
+///
+/// let config = DeserializationConfig::default();
+/// ```
+///
+/// The options are applied with the `try_into_with()` methods of
+/// [`DeserializableResultSet`](trait.DeserializableResultSet.html#method.try_into_with),
+/// [`DeserializableRow`](trait.DeserializableRow.html#method.try_into_with), and
+/// [`DbValue`](trait.DbValue.html#method.try_into_with).
+#[derive(Clone, Debug, Default)]
+pub struct DeserializationConfig {
+    null_policy: NullPolicy,
+    empty_string_as_null: bool,
+    max_rows: Option<usize>,
+    max_bytes: Option<usize>,
+    max_lob_size: Option<usize>,
+    observer: Option<Arc<dyn Observer>>,
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    naive_timestamp_policy: crate::datetime::NaiveTimestampPolicy,
+}
+
+impl DeserializationConfig {
+    /// Sets the policy for NULL values in fields that are not an `Option`.
+    ///
+    /// Default is `NullPolicy::Error`.
+    #[must_use]
+    pub fn with_null_policy(mut self, policy: NullPolicy) -> Self {
+        self.null_policy = policy;
+        self
+    }
+
+    /// Returns the policy for NULL values in fields that are not an `Option`.
+    #[must_use]
+    pub fn null_policy(&self) -> NullPolicy {
+        self.null_policy
+    }
+
+    /// Sets whether empty strings are deserialized into `Option`s as `None`,
+    /// like databases do that do not distinguish between the two (e.g. Oracle).
+    ///
+    /// Requires the driver to implement
+    /// [`DbValue::is_empty_string()`](trait.DbValue.html#method.is_empty_string).
+    ///
+    /// Default is `false`.
+    #[must_use]
+    pub fn with_empty_string_as_null(mut self, empty_string_as_null: bool) -> Self {
+        self.empty_string_as_null = empty_string_as_null;
+        self
+    }
+
+    /// Returns whether empty strings are deserialized into `Option`s as `None`.
+    #[must_use]
+    pub fn empty_string_as_null(&self) -> bool {
+        self.empty_string_as_null
+    }
+
+    /// Limits the number of rows that are deserialized from a result set;
+    /// if the result set has more rows, the deserialization fails
+    /// with `DeserializationError::LimitExceeded`.
+    ///
+    /// Default is no limit.
+    #[must_use]
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Returns the maximum number of rows, if limited.
+    #[must_use]
+    pub fn max_rows(&self) -> Option<usize> {
+        self.max_rows
+    }
+
+    /// Limits the total number of bytes of all strings and byte arrays that are produced
+    /// by a single call to `try_into_with()`; if the limit is exceeded, the deserialization
+    /// fails with `DeserializationError::LimitExceeded`.
+    ///
+    /// Default is no limit.
+    #[must_use]
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Returns the maximum total number of bytes of strings and byte arrays, if limited.
+    #[must_use]
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+
+    /// Limits the size of each [`Lob`](struct.Lob.html); reading beyond the limit fails
+    /// with an `std::io::Error` that wraps `DeserializationError::LimitExceeded`.
+    ///
+    /// `Lob`s that the driver has already materialized in memory, e.g. with the default
+    /// implementation of [`DbValue::try_into_lob()`](trait.DbValue.html#method.try_into_lob),
+    /// are rejected with `DeserializationError::LimitExceeded` when they are deserialized.
+    /// The limit cannot prevent this materialization, though; only drivers that
+    /// load large objects incrementally keep the memory usage within the limit.
+    ///
+    /// Default is no limit.
+    #[must_use]
+    pub fn with_max_lob_size(mut self, max_lob_size: usize) -> Self {
+        self.max_lob_size = Some(max_lob_size);
+        self
+    }
+
+    /// Returns the maximum size of a `Lob`, if limited.
+    #[must_use]
+    pub fn max_lob_size(&self) -> Option<usize> {
+        self.max_lob_size
+    }
+
+    /// Sets an observer that is informed about the progress of the deserialization,
+    /// e.g. [`Counters`](../observer/struct.Counters.html).
+    ///
+    /// Default is no observer.
+    #[must_use]
+    pub fn with_observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Returns the observer, or a [`NoopObserver`](../observer/struct.NoopObserver.html).
+    #[must_use]
+    pub fn observer(&self) -> &dyn Observer {
+        self.observer.as_deref().unwrap_or(&NoopObserver)
+    }
+
+    pub(crate) fn has_observer(&self) -> bool {
+        self.observer.is_some()
+    }
+
+    /// Sets the policy for converting timestamps without time zone information
+    /// into time-zone-aware types.
+    ///
+    /// Default is `NaiveTimestampPolicy::AssumeUtc`.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[must_use]
+    pub fn with_naive_timestamp_policy(
+        mut self,
+        policy: crate::datetime::NaiveTimestampPolicy,
+    ) -> Self {
+        self.naive_timestamp_policy = policy;
+        self
+    }
+
+    /// Returns the policy for converting timestamps without time zone information
+    /// into time-zone-aware types.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[must_use]
+    pub fn naive_timestamp_policy(&self) -> crate::datetime::NaiveTimestampPolicy {
+        self.naive_timestamp_policy
+    }
+}
+
+/// Policy for NULL values in fields that are not an `Option`.
+///
+/// NULL values are always detected with [`DbValue::is_null()`](trait.DbValue.html#tymethod.is_null)
+/// before the value is handed to the driver's `DbValueInto` conversions,
+/// so the behavior does not depend on the driver.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NullPolicy {
+    /// NULL values are rejected with `DeserializationError::UnexpectedNull`,
+    /// which names the affected column.
+    #[default]
+    Error,
+    /// NULL values are replaced with the default value of the target type
+    /// (`0`, `false`, an empty string, an empty collection);
+    /// for other target types they are rejected as with `Error`.
+    Default,
+    /// Columns with NULL values are omitted when deserializing rows into structs,
+    /// so that serde applies the field's default (`#[serde(default)]`, or `None` for `Option`s);
+    /// for fields without default, and elsewhere, NULL values are rejected as with `Error`.
+    SerdeDefault,
+}
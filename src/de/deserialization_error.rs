@@ -3,6 +3,7 @@ use thiserror::Error;
 
 /// The errors that can arise while deserializing with `serde_db::de`.
 #[derive(Error)]
+#[non_exhaustive]
 pub enum DeserializationError {
     /// Deserialization failed due to a conversion error.
     #[error("Deserialization failed due to a conversion error")]
@@ -25,6 +26,16 @@ pub enum DeserializationError {
     #[error("the deserialization target misses a field for which data are provided")]
     UnknownField(String),
 
+    /// A NULL value was found for a field that is not an `Option`
+    /// (see [`NullPolicy`](enum.NullPolicy.html)); contains the name of the column, if known.
+    #[error("unexpected NULL value for a field that is not an Option")]
+    UnexpectedNull(String),
+
+    /// A limit of the [`DeserializationConfig`](struct.DeserializationConfig.html)
+    /// was exceeded; contains a description of the limit.
+    #[error("a resource limit was exceeded")]
+    LimitExceeded(String),
+
     /// The deserialization cannot consume all existing rows.
     #[error("cannot consume all existing rows")]
     TrailingRows,
@@ -38,6 +49,21 @@ impl serde::de::Error for DeserializationError {
     fn custom<T: std::fmt::Display>(msg: T) -> Self {
         DeserializationError::SerdeError(msg.to_string())
     }
+
+    fn missing_field(field: &'static str) -> Self {
+        DeserializationError::SerdeError(missing_field_message(field))
+    }
+}
+
+impl DeserializationError {
+    // True if serde reported the field as missing.
+    pub(crate) fn is_missing_field(&self, field: &str) -> bool {
+        matches!(self, Self::SerdeError(msg) if *msg == missing_field_message(field))
+    }
+}
+
+fn missing_field_message(field: &str) -> String {
+    format!("missing field `{field}`")
 }
 
 impl std::fmt::Debug for DeserializationError {
@@ -45,7 +71,12 @@ impl std::fmt::Debug for DeserializationError {
         match *self {
             Self::ConversionError(ref e) => write!(formatter, "{e:?}"),
             Self::NotImplemented(s) => write!(formatter, "{self}: {s}"),
-            Self::SerdeError(ref s) | Self::UnknownField(ref s) | Self::Usage(ref s) => {
+            Self::UnexpectedNull(ref s) if s.is_empty() => write!(formatter, "{self}"),
+            Self::SerdeError(ref s)
+            | Self::UnknownField(ref s)
+            | Self::Usage(ref s)
+            | Self::UnexpectedNull(ref s)
+            | Self::LimitExceeded(ref s) => {
                 write!(formatter, "{self}: {s}")
             }
             Self::TrailingRows | Self::TrailingCols => write!(formatter, "{self}"),
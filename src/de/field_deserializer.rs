@@ -1,22 +1,117 @@
-use crate::de::{DbValue, DbValueInto, DeserializationError, DeserializationResult};
+use crate::de::composite_row::CompositeRow;
+use crate::de::row_deserializer::RowDeserializer;
+use crate::de::{limits, lob};
+use crate::de::{
+    DbValue, DbValueInto, DeserializationConfig, DeserializationError, DeserializationResult,
+    NullPolicy,
+};
+use crate::transport;
+use crate::value::{Value, ValueKind};
 #[cfg(feature = "trace")]
 use log::trace;
 
 /// Deserialize a single `DbValue` into a normal rust type.
-pub struct FieldDeserializer<DBV>(DBV);
+pub struct FieldDeserializer<'a, DBV> {
+    value: DBV,
+    config: &'a DeserializationConfig,
+}
 
-impl<DBV> FieldDeserializer<DBV>
+impl<'a, DBV> FieldDeserializer<'a, DBV>
 where
     DBV: DbValue,
 {
-    pub fn new(value: DBV) -> FieldDeserializer<DBV> {
+    pub fn new(value: DBV, config: &'a DeserializationConfig) -> FieldDeserializer<'a, DBV> {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::new()");
-        FieldDeserializer(value)
+        FieldDeserializer { value, config }
+    }
+
+    // Checks for NULL centrally, before the value is handed to the conversions of the driver.
+    // Returns true if the NULL value is to be replaced with the default value of the target type.
+    fn null_to_default(&self) -> DeserializationResult<bool> {
+        if !self.value.is_null() {
+            return Ok(false);
+        }
+        match self.config.null_policy() {
+            NullPolicy::Default => Ok(true),
+            NullPolicy::Error | NullPolicy::SerdeDefault => {
+                Err(DeserializationError::UnexpectedNull(String::new()))
+            }
+        }
     }
+
+    // For target types without a well-defined default value.
+    fn reject_null(&self) -> DeserializationResult<()> {
+        if self.value.is_null() {
+            Err(DeserializationError::UnexpectedNull(String::new()))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Converts the value with the driver's conversion.
+    fn convert<T>(self) -> DeserializationResult<T>
+    where
+        DBV: DbValueInto<T>,
+    {
+        Ok(DbValueInto::try_into(self.value)?)
+    }
+
+    // Like convert(), for strings and byte arrays, which count against the byte limit.
+    fn convert_sized<T: AsRef<[u8]>>(self) -> DeserializationResult<T>
+    where
+        DBV: DbValueInto<T>,
+    {
+        let value: T = DbValueInto::try_into(self.value)?;
+        limits::consume_bytes(value.as_ref().len())?;
+        Ok(value)
+    }
+
+    // Probes the kind of the value.
+    fn into_value(self) -> DeserializationResult<Value> {
+        if self.value.is_null() {
+            return Ok(Value::Null);
+        }
+        Ok(match self.value.value_kind() {
+            ValueKind::Bool => Value::Bool(self.convert()?),
+            ValueKind::Int => Value::Int(self.convert()?),
+            ValueKind::Float => Value::Float(self.convert()?),
+            ValueKind::String => Value::String(self.convert_sized()?),
+            ValueKind::Bytes => Value::Bytes(self.convert_sized()?),
+            #[cfg(feature = "decimal")]
+            ValueKind::Decimal => Value::Decimal(self.value.try_into_decimal()?),
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            ValueKind::DateTime => Value::DateTime(self.value.try_into_datetime()?),
+        })
+    }
+
+    // The fields of composite values are deserialized like the columns of a row.
+    fn into_composite_row(self) -> DeserializationResult<RowDeserializer<'a, CompositeRow<DBV>>> {
+        Ok(RowDeserializer::new_composite(
+            CompositeRow::new(self.value.try_into_fields()?),
+            self.config,
+        ))
+    }
+
+    // Structured targets (structs, tuples, sequences, maps) are parsed from JSON text.
+    #[cfg(feature = "json")]
+    fn into_json(self) -> DeserializationResult<serde_json::Value> {
+        let text: String = self.convert_sized()?;
+        serde_json::from_str(&text).map_err(|e| {
+            DeserializationError::ConversionError(crate::de::ConversionError::ValueType(format!(
+                "value is not valid JSON: {e}"
+            )))
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+#[allow(clippy::needless_pass_by_value)]
+fn json_error(e: serde_json::Error) -> DeserializationError {
+    DeserializationError::SerdeError(e.to_string())
 }
 
-impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
+impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<'_, DBV> {
     type Error = DeserializationError;
 
     fn deserialize_any<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -25,7 +120,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_any()");
-        visitor.visit_string(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_string(String::new());
+        }
+        visitor.visit_string(self.convert_sized()?)
     }
 
     fn deserialize_bool<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -34,7 +132,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_bool()");
-        visitor.visit_bool(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_bool(false);
+        }
+        visitor.visit_bool(self.convert()?)
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -43,7 +144,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_u8()");
-        visitor.visit_u8(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_u8(0);
+        }
+        visitor.visit_u8(self.convert()?)
     }
 
     fn deserialize_u16<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -52,7 +156,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_u16()");
-        visitor.visit_u16(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_u16(0);
+        }
+        visitor.visit_u16(self.convert()?)
     }
 
     fn deserialize_u32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -61,7 +168,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_u32()");
-        visitor.visit_u32(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_u32(0);
+        }
+        visitor.visit_u32(self.convert()?)
     }
 
     fn deserialize_u64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -70,7 +180,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_u64()");
-        visitor.visit_u64(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_u64(0);
+        }
+        visitor.visit_u64(self.convert()?)
     }
 
     fn deserialize_i8<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -79,7 +192,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_i8()");
-        visitor.visit_i8(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_i8(0);
+        }
+        visitor.visit_i8(self.convert()?)
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -88,7 +204,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_i16()");
-        visitor.visit_i16(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_i16(0);
+        }
+        visitor.visit_i16(self.convert()?)
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -97,7 +216,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_i32()");
-        visitor.visit_i32(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_i32(0);
+        }
+        visitor.visit_i32(self.convert()?)
     }
 
     fn deserialize_i64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -106,7 +228,34 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_i64()");
-        visitor.visit_i64(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_i64(0);
+        }
+        visitor.visit_i64(self.convert()?)
+    }
+
+    fn deserialize_i128<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_i128()");
+        if self.null_to_default()? {
+            return visitor.visit_i128(0);
+        }
+        visitor.visit_i128(self.value.try_into_i128()?)
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_u128()");
+        if self.null_to_default()? {
+            return visitor.visit_u128(0);
+        }
+        visitor.visit_u128(self.value.try_into_u128()?)
     }
 
     fn deserialize_f32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -115,7 +264,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_f32()");
-        visitor.visit_f32(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_f32(0.0);
+        }
+        visitor.visit_f32(self.convert()?)
     }
 
     fn deserialize_f64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -124,7 +276,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_f64()");
-        visitor.visit_f64(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_f64(0.0);
+        }
+        visitor.visit_f64(self.convert()?)
     }
 
     fn deserialize_char<V>(self, _visitor: V) -> DeserializationResult<V::Value>
@@ -151,7 +306,10 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_string()");
-        visitor.visit_string(DbValueInto::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_string(String::new());
+        }
+        visitor.visit_string(self.convert_sized()?)
     }
 
     fn deserialize_unit<V>(self, _visitor: V) -> DeserializationResult<V::Value>
@@ -169,29 +327,71 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_option()");
-        if self.0.is_null() {
+        if self.value.is_null()
+            || (self.config.empty_string_as_null() && self.value.is_empty_string())
+        {
             visitor.visit_none()
         } else {
             visitor.visit_some(self)
         }
     }
 
-    fn deserialize_seq<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_seq<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_seq()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_seq()");
+        if self.null_to_default()? {
+            return visitor.visit_seq(ElementsSeqVisitor::<'_, DBV>::new(Vec::new(), self.config));
+        }
+        if self.value.is_array() {
+            return visitor.visit_seq(ElementsSeqVisitor::new(
+                self.value.try_into_elements()?,
+                self.config,
+            ));
+        }
+        #[cfg(feature = "json")]
+        {
+            self.into_json()?
+                .deserialize_seq(visitor)
+                .map_err(json_error)
+        }
+        #[cfg(not(feature = "json"))]
+        {
+            Err(DeserializationError::NotImplemented(
+                "FieldDeserializer::deserialize_seq()",
+            ))
+        }
     }
 
-    fn deserialize_map<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_map<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_map()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_map()");
+        if self.null_to_default()? {
+            return visitor.visit_map(EntriesMapVisitor::<'_, DBV>::new(Vec::new(), self.config));
+        }
+        if self.value.is_map() {
+            return visitor.visit_map(EntriesMapVisitor::new(
+                self.value.try_into_entries()?,
+                self.config,
+            ));
+        }
+        #[cfg(feature = "json")]
+        {
+            self.into_json()?
+                .deserialize_map(visitor)
+                .map_err(json_error)
+        }
+        #[cfg(not(feature = "json"))]
+        {
+            Err(DeserializationError::NotImplemented(
+                "FieldDeserializer::deserialize_map()",
+            ))
+        }
     }
 
     fn deserialize_unit_struct<V>(
@@ -207,6 +407,7 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
         ))
     }
 
+    #[allow(clippy::used_underscore_binding)]
     fn deserialize_newtype_struct<V>(
         self,
         _name: &'static str,
@@ -217,35 +418,128 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_newtype_struct()");
+        if _name == transport::VALUE {
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            let policy = self.config.naive_timestamp_policy();
+            return match self.into_value()? {
+                Value::Null => visitor.visit_none(),
+                Value::Bool(b) => visitor.visit_bool(b),
+                Value::Int(i) => visitor.visit_i64(i),
+                Value::Float(f) => visitor.visit_f64(f),
+                Value::String(s) => visitor.visit_string(s),
+                Value::Bytes(b) => visitor.visit_byte_buf(b),
+                #[cfg(feature = "decimal")]
+                Value::Decimal(d) => {
+                    crate::value::visit_transport(transport::DECIMAL, &d.to_bytes(), visitor)
+                }
+                #[cfg(any(feature = "chrono", feature = "time"))]
+                Value::DateTime(dt) => crate::value::visit_transport(
+                    transport::DATETIME,
+                    &dt.to_bytes(policy),
+                    visitor,
+                ),
+            };
+        }
+        if transport::is_transport(_name) {
+            self.reject_null()?;
+        }
+        if _name == transport::LOB {
+            let lob = self.value.try_into_lob()?;
+            lob::stash(match self.config.max_lob_size() {
+                Some(max) => lob.limited(max)?,
+                None => lob,
+            });
+            return visitor.visit_unit();
+        }
+        #[cfg(feature = "decimal")]
+        if _name == transport::DECIMAL {
+            return visitor.visit_bytes(&self.value.try_into_decimal()?.to_bytes());
+        }
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        if _name == transport::DATETIME {
+            return visitor.visit_bytes(
+                &self
+                    .value
+                    .try_into_datetime()?
+                    .to_bytes(self.config.naive_timestamp_policy()),
+            );
+        }
+        #[cfg(feature = "uuid")]
+        if _name == transport::UUID {
+            return visitor.visit_bytes(self.value.try_into_uuid()?.as_bytes());
+        }
+        #[cfg(feature = "json")]
+        if _name == transport::JSON {
+            return visitor.visit_string(self.convert_sized()?);
+        }
         visitor.visit_newtype_struct(self)
     }
 
+    #[cfg_attr(not(feature = "json"), allow(unused_variables))]
     fn deserialize_tuple_struct<V>(
         self,
-        _name: &'static str,
-        _len: usize,
-        _visitor: V,
+        name: &'static str,
+        len: usize,
+        visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_tuple_struct()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_tuple_struct()");
+        self.reject_null()?;
+        if self.value.is_composite() {
+            return self.into_composite_row()?.deserialize_tuple(len, visitor);
+        }
+        #[cfg(feature = "json")]
+        {
+            self.into_json()?
+                .deserialize_tuple_struct(name, len, visitor)
+                .map_err(json_error)
+        }
+        #[cfg(not(feature = "json"))]
+        {
+            Err(DeserializationError::NotImplemented(
+                "FieldDeserializer::deserialize_tuple_struct()",
+            ))
+        }
     }
 
     fn deserialize_struct<V>(
         self,
-        _name: &'static str,
-        _fields: &'static [&'static str],
-        _visitor: V,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_struct()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_struct()");
+        self.reject_null()?;
+        if self.value.is_composite() {
+            return self
+                .into_composite_row()?
+                .deserialize_struct(name, fields, visitor);
+        }
+        if self.value.is_map() {
+            return visitor.visit_map(EntriesMapVisitor::new(
+                self.value.try_into_entries()?,
+                self.config,
+            ));
+        }
+        #[cfg(feature = "json")]
+        {
+            self.into_json()?
+                .deserialize_struct(name, fields, visitor)
+                .map_err(json_error)
+        }
+        #[cfg(not(feature = "json"))]
+        {
+            Err(DeserializationError::NotImplemented(
+                "FieldDeserializer::deserialize_struct()",
+            ))
+        }
     }
 
     fn deserialize_bytes<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -254,7 +548,11 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
     {
         #[cfg(feature = "trace")]
         trace!("FieldDeserializer::deserialize_bytes()");
-        visitor.visit_bytes(&DbValueInto::<Vec<u8>>::try_into(self.0)?)
+        if self.null_to_default()? {
+            return visitor.visit_bytes(&[]);
+        }
+        let bytes: Vec<u8> = self.convert_sized()?;
+        visitor.visit_bytes(&bytes)
     }
 
     fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -262,17 +560,36 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!("FieldDeserializer::deserialize_bytes()");
-        visitor.visit_bytes(&DbValueInto::<Vec<u8>>::try_into(self.0)?)
+        trace!("FieldDeserializer::deserialize_byte_buf()");
+        if self.null_to_default()? {
+            return visitor.visit_bytes(&[]);
+        }
+        let bytes: Vec<u8> = self.convert_sized()?;
+        visitor.visit_bytes(&bytes)
     }
 
-    fn deserialize_tuple<V>(self, _len: usize, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_tuple()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_tuple()");
+        self.reject_null()?;
+        if self.value.is_composite() {
+            return self.into_composite_row()?.deserialize_tuple(len, visitor);
+        }
+        #[cfg(feature = "json")]
+        {
+            self.into_json()?
+                .deserialize_tuple(len, visitor)
+                .map_err(json_error)
+        }
+        #[cfg(not(feature = "json"))]
+        {
+            Err(DeserializationError::NotImplemented(
+                "FieldDeserializer::deserialize_tuple()",
+            ))
+        }
     }
 
     fn deserialize_enum<V>(
@@ -298,12 +615,108 @@ impl<'x, DBV: DbValue> serde::Deserializer<'x> for FieldDeserializer<DBV> {
         ))
     }
 
-    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "FieldDeserializer::deserialize_ignored_any()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("FieldDeserializer::deserialize_ignored_any()");
+        visitor.visit_unit()
+    }
+}
+
+// Provides the elements of an array value.
+struct ElementsSeqVisitor<'a, DBV> {
+    elements: std::vec::IntoIter<DBV>,
+    config: &'a DeserializationConfig,
+}
+
+impl<'a, DBV: DbValue> ElementsSeqVisitor<'a, DBV> {
+    fn new(elements: Vec<DBV>, config: &'a DeserializationConfig) -> Self {
+        #[cfg(feature = "trace")]
+        trace!("ElementsSeqVisitor::new()");
+        ElementsSeqVisitor {
+            elements: elements.into_iter(),
+            config,
+        }
+    }
+}
+
+impl<'x, DBV: DbValue> serde::de::SeqAccess<'x> for ElementsSeqVisitor<'_, DBV> {
+    type Error = DeserializationError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("ElementsSeqVisitor::next_element_seed()");
+        match self.elements.next() {
+            None => Ok(None),
+            Some(value) => seed
+                .deserialize(FieldDeserializer::new(value, self.config))
+                .map(Some),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+// Provides the entries of a map value.
+struct EntriesMapVisitor<'a, DBV> {
+    entries: std::vec::IntoIter<(String, DBV)>,
+    value: Option<DBV>,
+    config: &'a DeserializationConfig,
+}
+
+impl<'a, DBV: DbValue> EntriesMapVisitor<'a, DBV> {
+    fn new(entries: Vec<(String, DBV)>, config: &'a DeserializationConfig) -> Self {
+        #[cfg(feature = "trace")]
+        trace!("EntriesMapVisitor::new()");
+        EntriesMapVisitor {
+            entries: entries.into_iter(),
+            value: None,
+            config,
+        }
+    }
+}
+
+impl<'x, DBV: DbValue> serde::de::MapAccess<'x> for EntriesMapVisitor<'_, DBV> {
+    type Error = DeserializationError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: serde::de::DeserializeSeed<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("EntriesMapVisitor::next_key_seed()");
+        match self.entries.next() {
+            None => Ok(None),
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(serde::de::value::StringDeserializer::new(key))
+                    .map(Some)
+            }
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::DeserializeSeed<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("EntriesMapVisitor::next_value_seed()");
+        match self.value.take() {
+            Some(value) => seed.deserialize(FieldDeserializer::new(value, self.config)),
+            None => Err(DeserializationError::Usage(
+                "EntriesMapVisitor::next_value_seed(): no pending value".to_string(),
+            )),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
     }
 }
@@ -0,0 +1,277 @@
+use crate::de::field_deserializer::FieldDeserializer;
+use crate::de::limits::{self, ByteBudget, Tally};
+use crate::de::{
+    DbValue, DbValueInto, DeserializableRow, DeserializationConfig, DeserializationError,
+    DeserializationResult, NullPolicy,
+};
+
+/// Rust types that are created directly from the values of a row, without going
+/// through the serde layers.
+///
+/// This is a faster alternative to the serde-based `try_into()` for high-volume queries.
+/// It is usually implemented with `#[derive(FromRow)]` (feature `derive`).
+/// Types that also implement `serde::Deserialize` can be used with both approaches.
+///
+/// ```rust,ignore
+/// #[derive(FromRow)]
+/// struct Person {
+///     id: u32,
+///     name: String,
+///     nickname: Option<String>,
+/// }
+///
+/// let persons: Vec<Person> = result_set.try_into_rows()?;
+/// let person = Person::from_row(row)?;
+/// ```
+///
+/// The values are read in the order of [`COLUMNS`](#associatedconstant.COLUMNS) if the
+/// row has exactly these columns in this order, otherwise they are matched by name.
+pub trait FromRow: Sized {
+    /// The names of the columns from which the fields are read, in reading order.
+    const COLUMNS: &'static [&'static str];
+
+    /// Creates the rust value, reading the values with the methods of `RowFields`
+    /// in the order of [`COLUMNS`](#associatedconstant.COLUMNS).
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError` if a value cannot be converted into the type of its field.
+    fn from_fields<R: DeserializableRow>(
+        fields: &mut RowFields<'_, R>,
+    ) -> DeserializationResult<Self>;
+
+    /// Converts the row into the rust value.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the columns of the row do not match the fields,
+    /// or if a value cannot be converted into the type of its field.
+    fn from_row<R: DeserializableRow>(row: R) -> Result<Self, R::Error> {
+        Self::from_row_with(row, &DeserializationConfig::default())
+    }
+
+    /// Converts the row into the rust value, using the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// An error is produced if the columns of the row do not match the fields,
+    /// or if a value cannot be converted into the type of its field.
+    fn from_row_with<R: DeserializableRow>(
+        row: R,
+        config: &DeserializationConfig,
+    ) -> Result<Self, R::Error> {
+        let _budget = ByteBudget::install(config);
+        let result = Layout::new(Self::COLUMNS, row.number_of_fields(), |idx| {
+            row.field_name(idx)
+        })
+        .and_then(|layout| RowFields::new(row, Self::COLUMNS, &layout, config))
+        .and_then(RowFields::convert);
+        if let Err(ref e) = result {
+            config.observer().deserialization_failed(e);
+        }
+        #[cfg(feature = "tracing")]
+        crate::instrument::report(&result);
+        Ok(result?)
+    }
+}
+
+// How the columns of a row relate to the columns of a `FromRow` type;
+// can be determined once per result set.
+pub(crate) enum Layout {
+    InOrder,
+    // the position in COLUMNS of each column of the row
+    Reordered(Vec<usize>),
+}
+
+impl Layout {
+    pub(crate) fn new<'a>(
+        columns: &[&str],
+        number_of_fields: usize,
+        field_name: impl Fn(usize) -> Option<&'a str>,
+    ) -> DeserializationResult<Self> {
+        if number_of_fields == columns.len()
+            && columns
+                .iter()
+                .enumerate()
+                .all(|(idx, column)| field_name(idx) == Some(column))
+        {
+            return Ok(Layout::InOrder);
+        }
+        (0..number_of_fields)
+            .map(|idx| {
+                let name = field_name(idx).unwrap_or_default();
+                columns
+                    .iter()
+                    .position(|column| *column == name)
+                    .ok_or_else(|| DeserializationError::UnknownField(name.to_string()))
+            })
+            .collect::<DeserializationResult<Vec<usize>>>()
+            .map(Layout::Reordered)
+    }
+}
+
+enum Source<R: DeserializableRow> {
+    InOrder(R),
+    Reordered(Vec<Option<R::Value>>),
+}
+
+/// Provides the values of a row, in the order of
+/// [`FromRow::COLUMNS`](trait.FromRow.html#associatedconstant.COLUMNS),
+/// converted directly with `DbValueInto`.
+pub struct RowFields<'a, R: DeserializableRow> {
+    source: Source<R>,
+    columns: &'static [&'static str],
+    idx: usize,
+    config: &'a DeserializationConfig,
+    // the values for the observer
+    tally: Tally,
+    // the span of the row
+    #[cfg(feature = "tracing")]
+    _operation: crate::instrument::Operation,
+}
+
+impl<'a, R: DeserializableRow> RowFields<'a, R> {
+    pub(crate) fn new(
+        mut row: R,
+        columns: &'static [&'static str],
+        layout: &Layout,
+        config: &'a DeserializationConfig,
+    ) -> DeserializationResult<Self> {
+        if row.len() != row.number_of_fields() {
+            return Err(DeserializationError::Usage(
+                "the row was partially consumed".to_string(),
+            ));
+        }
+        #[cfg(feature = "tracing")]
+        let operation = crate::instrument::Operation::start_row(row.number_of_fields());
+        config.observer().row_started(row.number_of_fields());
+        let source = match layout {
+            Layout::InOrder => Source::InOrder(row),
+            Layout::Reordered(positions) => {
+                let mut slots: Vec<Option<R::Value>> = columns.iter().map(|_| None).collect();
+                for position in positions {
+                    slots[*position] = row.next();
+                }
+                Source::Reordered(slots)
+            }
+        };
+        Ok(RowFields {
+            source,
+            columns,
+            idx: 0,
+            tally: Tally::new(config),
+            config,
+            #[cfg(feature = "tracing")]
+            _operation: operation,
+        })
+    }
+
+    fn next_value(&mut self) -> DeserializationResult<(R::Value, &'static str)> {
+        let column = self
+            .columns
+            .get(self.idx)
+            .ok_or_else(|| DeserializationError::Usage("more fields than columns".to_string()))?;
+        let value = match self.source {
+            Source::InOrder(ref mut row) => row.next(),
+            Source::Reordered(ref mut slots) => slots[self.idx].take(),
+        };
+        self.idx += 1;
+        self.tally.value_started();
+        value
+            .map(|value| (value, *column))
+            .ok_or_else(|| serde::de::Error::missing_field(column))
+    }
+
+    /// Reads the next value into a field that is not an `Option`.
+    ///
+    /// NULL values are handled according to the [`NullPolicy`](enum.NullPolicy.html);
+    /// `NullPolicy::SerdeDefault` behaves like `NullPolicy::Default`.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError` if the value cannot be converted into the target type.
+    pub fn required<T: Default + 'static>(&mut self) -> DeserializationResult<T>
+    where
+        R::Value: DbValueInto<T>,
+    {
+        let (value, column) = self.next_value()?;
+        if value.is_null() {
+            match self.config.null_policy() {
+                NullPolicy::Error => Err(DeserializationError::UnexpectedNull(column.to_string())),
+                NullPolicy::Default | NullPolicy::SerdeDefault => Ok(T::default()),
+            }
+        } else {
+            convert(value)
+        }
+    }
+
+    /// Reads the next value into an `Option` field.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError` if the value cannot be converted into the target type.
+    pub fn optional<T: 'static>(&mut self) -> DeserializationResult<Option<T>>
+    where
+        R::Value: DbValueInto<T>,
+    {
+        let (value, _) = self.next_value()?;
+        if value.is_null() || (self.config.empty_string_as_null() && value.is_empty_string()) {
+            Ok(None)
+        } else {
+            convert(value).map(Some)
+        }
+    }
+
+    /// Reads the next value with [`DbValue::try_into_with()`](trait.DbValue.html#method.try_into_with),
+    /// i.e. through serde, for types that are not supported by `DbValueInto`.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError` if the value cannot be converted into the target type.
+    pub fn deserialize<T: serde::de::DeserializeOwned>(&mut self) -> DeserializationResult<T> {
+        let (value, column) = self.next_value()?;
+        let _budget = ByteBudget::install(self.config);
+        serde::Deserialize::deserialize(FieldDeserializer::new(value, self.config)).map_err(|e| {
+            match e {
+                DeserializationError::UnexpectedNull(ref name) if name.is_empty() => {
+                    DeserializationError::UnexpectedNull(column.to_string())
+                }
+                e => e,
+            }
+        })
+    }
+}
+
+impl<R: DeserializableRow> RowFields<'_, R> {
+    // Converts the row into the rust value, and reports the row to the observer
+    // if this succeeds.
+    pub(crate) fn convert<T: FromRow>(mut self) -> DeserializationResult<T> {
+        let value = T::from_fields(&mut self)?;
+        let observer = self.config.observer();
+        self.tally.report(observer);
+        observer.row_deserialized();
+        Ok(value)
+    }
+}
+
+// Converts the value with the driver's conversion; strings and byte arrays count against
+// the byte limit, like in the FieldDeserializer.
+fn convert<V: DbValueInto<T>, T: 'static>(value: V) -> DeserializationResult<T> {
+    let value: T = DbValueInto::try_into(value)?;
+    let any: &dyn std::any::Any = &value;
+    if let Some(s) = any.downcast_ref::<String>() {
+        limits::consume_bytes(s.len())?;
+    } else if let Some(b) = any.downcast_ref::<Vec<u8>>() {
+        limits::consume_bytes(b.len())?;
+    }
+    Ok(value)
+}
+
+impl<R: DeserializableRow> std::fmt::Debug for RowFields<'_, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RowFields")
+            .field("columns", &self.columns)
+            .field("idx", &self.idx)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,151 @@
+use crate::de::{DeserializationConfig, DeserializationError, DeserializationResult};
+use crate::observer::Observer;
+use std::cell::Cell;
+use std::io::{self, BufRead, Read};
+
+thread_local! {
+    // The limit and the remaining number of bytes of the running deserialization.
+    // The FieldDeserializers are created deep within the deserialization,
+    // so the budget cannot be handed over to them like the configuration.
+    static BYTES: Cell<Option<(usize, usize)>> = const { Cell::new(None) };
+
+    // The running total of the bytes of strings and byte arrays, independent of any limit;
+    // its differences are the sizes that are reported to the observer.
+    static CONSUMED: Cell<usize> = const { Cell::new(0) };
+}
+
+// Makes the byte limit of the configuration effective until it is dropped.
+//
+// Nested deserializations (e.g. `DbValue::try_into_with()` within `FromRow`)
+// use the budget of the outermost one.
+pub(crate) struct ByteBudget {
+    installed: bool,
+}
+
+impl ByteBudget {
+    pub(crate) fn install(config: &DeserializationConfig) -> Self {
+        let installed = match config.max_bytes() {
+            Some(max) if BYTES.get().is_none() => {
+                BYTES.set(Some((max, max)));
+                true
+            }
+            _ => false,
+        };
+        ByteBudget { installed }
+    }
+}
+
+impl Drop for ByteBudget {
+    fn drop(&mut self) {
+        if self.installed {
+            BYTES.set(None);
+        }
+    }
+}
+
+// Accounts for a string or byte array of the given length.
+pub(crate) fn consume_bytes(len: usize) -> DeserializationResult<()> {
+    match BYTES.get() {
+        Some((max, left)) if len > left => {
+            return Err(DeserializationError::LimitExceeded(format!(
+                "strings and bytes exceed the limit of {max} bytes"
+            )));
+        }
+        Some((max, left)) => BYTES.set(Some((max, left - len))),
+        None => {}
+    }
+    CONSUMED.set(CONSUMED.get().wrapping_add(len));
+    Ok(())
+}
+
+// Collects the sizes of the values of a row (or of a single value), which are reported
+// to the observer only when the deserialization succeeded, so that values of failed rows
+// and the elements of arrays or maps within a value are not counted as values.
+#[derive(Debug)]
+pub(crate) struct Tally {
+    // the running total of bytes when each value was taken; None without observer
+    starts: Option<Vec<usize>>,
+}
+
+impl Tally {
+    pub(crate) fn new(config: &DeserializationConfig) -> Self {
+        Tally {
+            starts: config.has_observer().then(Vec::new),
+        }
+    }
+
+    // A tally that is never reported, for the fields of composite values.
+    pub(crate) fn inactive() -> Self {
+        Tally { starts: None }
+    }
+
+    pub(crate) fn value_started(&mut self) {
+        if let Some(ref mut starts) = self.starts {
+            starts.push(CONSUMED.get());
+        }
+    }
+
+    // Reports the values, after they were deserialized successfully.
+    pub(crate) fn report(self, observer: &dyn Observer) {
+        if let Some(starts) = self.starts {
+            let ends = starts.iter().skip(1).copied().chain([CONSUMED.get()]);
+            for (start, end) in starts.iter().zip(ends) {
+                observer.value_deserialized(end.wrapping_sub(*start));
+            }
+        }
+    }
+}
+
+pub(crate) fn check_rows(rows: usize, config: &DeserializationConfig) -> DeserializationResult<()> {
+    match config.max_rows() {
+        Some(max) if rows > max => Err(DeserializationError::LimitExceeded(format!(
+            "the result set has more than {max} rows"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// A reader that fails when more than `max` bytes are read.
+pub(crate) struct LimitedReader {
+    inner: Box<dyn BufRead + Send>,
+    max: usize,
+    left: usize,
+}
+
+impl LimitedReader {
+    pub(crate) fn new(inner: Box<dyn BufRead + Send>, max: usize) -> Self {
+        LimitedReader {
+            inner,
+            max,
+            left: max,
+        }
+    }
+}
+
+impl Read for LimitedReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for LimitedReader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let (max, left) = (self.max, self.left);
+        let buf = self.inner.fill_buf()?;
+        if left == 0 && !buf.is_empty() {
+            return Err(io::Error::other(DeserializationError::LimitExceeded(
+                format!("the LOB exceeds the limit of {max} bytes"),
+            )));
+        }
+        Ok(&buf[..buf.len().min(left)])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.left = self.left.saturating_sub(amt);
+        self.inner.consume(amt);
+    }
+}
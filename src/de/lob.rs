@@ -0,0 +1,169 @@
+use crate::de::limits::LimitedReader;
+use crate::de::{DeserializationError, DeserializationResult};
+use crate::transport;
+use std::cell::RefCell;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+
+thread_local! {
+    // The visitor interface of serde cannot transport a reader, so the FieldDeserializer
+    // parks the `Lob` here, right before it lets the `LobVisitor` pick it up.
+    static PENDING: RefCell<Option<Lob>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn stash(lob: Lob) {
+    PENDING.with(|pending| *pending.borrow_mut() = Some(lob));
+}
+
+fn take() -> Option<Lob> {
+    PENDING.with(|pending| pending.borrow_mut().take())
+}
+
+/// A large object (BLOB, CLOB, ...) whose content can be streamed.
+///
+/// `Lob` can be used as the type of a struct field (or `Option<Lob>` for nullable columns)
+/// and implements [`Read`] and [`BufRead`], so that big values can be processed
+/// without materializing them in memory:
+///
+/// ```rust,ignore
+/// use serde::Deserialize;
+/// use serde_db::de::Lob;
+///
+/// #[derive(Deserialize)]
+/// struct Document {
+///     name: String,
+///     content: Lob,
+/// }
+///
+/// for row in result_set {
+///     let mut doc: Document = row.try_into()?;
+///     let mut file = std::fs::File::create(&doc.name)?;
+///     std::io::copy(&mut doc.content, &mut file)?;
+/// }
+/// ```
+///
+/// Database drivers provide streaming `Lob`s by overriding
+/// [`DbValue::try_into_lob()`](trait.DbValue.html#method.try_into_lob).
+/// With other deserializers, `Lob` is deserialized from bytes or strings.
+pub struct Lob {
+    reader: Box<dyn BufRead + Send>,
+    // the size of content that is already in memory
+    len: Option<usize>,
+}
+
+impl Lob {
+    /// Creates a `Lob` that reads its content from the given reader.
+    ///
+    /// The reader is wrapped into a [`BufReader`];
+    /// use [`from_buf_read()`](#method.from_buf_read) for readers that are already buffered.
+    pub fn new<R: Read + Send + 'static>(reader: R) -> Self {
+        Self::from_buf_read(BufReader::new(reader))
+    }
+
+    /// Creates a `Lob` that reads its content from the given buffered reader.
+    pub fn from_buf_read<R: BufRead + Send + 'static>(reader: R) -> Self {
+        Lob {
+            reader: Box::new(reader),
+            len: None,
+        }
+    }
+
+    // Wraps the reader so that it fails when more than `max` bytes are read;
+    // content that is already in memory is checked right away.
+    pub(crate) fn limited(self, max: usize) -> DeserializationResult<Self> {
+        match self.len {
+            Some(len) if len > max => Err(DeserializationError::LimitExceeded(format!(
+                "the LOB exceeds the limit of {max} bytes"
+            ))),
+            _ => Ok(Self::from_buf_read(LimitedReader::new(self.reader, max))),
+        }
+    }
+
+    /// Returns the underlying reader.
+    #[must_use]
+    pub fn into_reader(self) -> Box<dyn BufRead + Send> {
+        self.reader
+    }
+}
+
+impl From<Vec<u8>> for Lob {
+    fn from(bytes: Vec<u8>) -> Self {
+        let len = bytes.len();
+        Lob {
+            len: Some(len),
+            ..Self::from_buf_read(Cursor::new(bytes))
+        }
+    }
+}
+
+impl From<String> for Lob {
+    fn from(s: String) -> Self {
+        Self::from(s.into_bytes())
+    }
+}
+
+impl Read for Lob {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+impl BufRead for Lob {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.reader.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.reader.consume(amt);
+    }
+}
+
+impl std::fmt::Debug for Lob {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Lob").finish_non_exhaustive()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Lob {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(transport::LOB, LobVisitor)
+    }
+}
+
+struct LobVisitor;
+
+impl<'de> serde::de::Visitor<'de> for LobVisitor {
+    type Value = Lob;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a large object")
+    }
+
+    // the FieldDeserializer has stashed the driver's Lob
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        take().ok_or_else(|| E::custom("no large object available"))
+    }
+
+    // other deserializers provide the content as bytes or string
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(self)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(Lob::from(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Lob::from(value))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Lob::from(value.to_string()))
+    }
+
+    fn visit_string<E: serde::de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Lob::from(value))
+    }
+}
@@ -0,0 +1,267 @@
+//! Range-checked conversions between numeric types, for implementors of `DbValueInto`.
+//!
+//! A driver describes its values once, by implementing [`ToScalar`], and then
+//! generates the implementations of `DbValueInto` for `bool`, the integer types,
+//! `f32`, `f64`, and `String` with [`impl_db_value_into!`](../../macro.impl_db_value_into.html):
+//!
+//! ```rust
+//! use serde_db::de::numeric::{Scalar, ToScalar};
+//! use serde_db::de::{ConversionError, DbValueInto};
+//!
+//! enum MyDbValue {
+//!     TinyInt(u8),
+//!     BigInt(i64),
+//!     Double(f64),
+//!     Varchar(String),
+//!     Blob(Vec<u8>),
+//! }
+//!
+//! impl ToScalar for MyDbValue {
+//!     fn try_into_scalar(self) -> Result<Scalar, ConversionError> {
+//!         match self {
+//!             MyDbValue::TinyInt(u) => Ok(Scalar::Int(u.into())),
+//!             MyDbValue::BigInt(i) => Ok(Scalar::Int(i.into())),
+//!             MyDbValue::Double(f) => Ok(Scalar::Float(f)),
+//!             MyDbValue::Varchar(s) => Ok(Scalar::Text(s)),
+//!             MyDbValue::Blob(_) => Err(ConversionError::ValueType(
+//!                 "a BLOB is not a scalar value".to_string(),
+//!             )),
+//!         }
+//!     }
+//! }
+//!
+//! serde_db::impl_db_value_into!(MyDbValue);
+//!
+//! let v: u8 = DbValueInto::try_into(MyDbValue::BigInt(200)).unwrap();
+//! assert_eq!(200, v);
+//! assert!(DbValueInto::<u8>::try_into(MyDbValue::BigInt(300)).is_err());
+//! assert!(DbValueInto::<i32>::try_into(MyDbValue::Double(1.5)).is_err());
+//! ```
+//!
+//! Conversions fail with `ConversionError::NumberRange` if the value does not fit into
+//! the target type, or if it cannot be represented in the target type without
+//! loss of precision (e.g. `1.5` as `i32`, or `0.1` as `f32`),
+//! and with `ConversionError::ValueType` if a text cannot be parsed into the target type.
+
+use crate::de::ConversionError;
+
+/// The native representation of a scalar database value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Scalar {
+    /// A boolean value; converts into `1` or `0` as number.
+    Bool(bool),
+    /// An integer value.
+    Int(i128),
+    /// A floating point value.
+    Float(f64),
+    /// A textual value; is parsed when converted into `bool` or a number.
+    Text(String),
+}
+
+impl Scalar {
+    /// Converts the scalar into the target type.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into the target type
+    /// without loss of range or precision.
+    pub fn try_into<T: FromScalar>(self) -> Result<T, ConversionError> {
+        T::from_scalar(self)
+    }
+}
+
+/// Provides the native representation of a database value.
+///
+/// Implementing this trait is the prerequisite for using
+/// [`impl_db_value_into!`](../../macro.impl_db_value_into.html).
+pub trait ToScalar {
+    /// Converts the database value into its native scalar representation.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value is not a scalar value.
+    fn try_into_scalar(self) -> Result<Scalar, ConversionError>;
+}
+
+/// Rust types that can be created from a [`Scalar`].
+pub trait FromScalar: Sized {
+    /// Creates the rust value from the scalar.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError` if the value cannot be converted into the target type
+    /// without loss of range or precision.
+    fn from_scalar(scalar: Scalar) -> Result<Self, ConversionError>;
+}
+
+/// Implements `DbValueInto<T>` for the given type, which must implement
+/// [`ToScalar`](de/numeric/trait.ToScalar.html), and for all target types `T`
+/// that implement [`FromScalar`](de/numeric/trait.FromScalar.html).
+///
+/// Without a list of target types, the implementations are generated for
+/// `bool`, `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64`, `f32`, `f64`, and `String`.
+///
+/// ```rust,ignore
+/// serde_db::impl_db_value_into!(MyDbValue);
+/// serde_db::impl_db_value_into!(MyOtherDbValue: u8, i64, String);
+/// ```
+#[macro_export]
+macro_rules! impl_db_value_into {
+    ($db_value:ty) => {
+        $crate::impl_db_value_into!(
+            $db_value: bool, u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, String
+        );
+    };
+    ($db_value:ty: $($target:ty),+ $(,)?) => {
+        $(
+            impl $crate::de::DbValueInto<$target> for $db_value {
+                fn try_into(self) -> Result<$target, $crate::de::ConversionError> {
+                    $crate::de::numeric::ToScalar::try_into_scalar(self)?.try_into()
+                }
+            }
+        )+
+    };
+}
+
+fn range_error<V: std::fmt::Display>(value: V, target: &str) -> ConversionError {
+    ConversionError::NumberRange(format!("value {value} does not fit into {target}"))
+}
+
+fn precision_error<V: std::fmt::Display>(value: V, target: &str) -> ConversionError {
+    ConversionError::NumberRange(format!(
+        "value {value} cannot be represented as {target} without loss of precision"
+    ))
+}
+
+fn parse<T: std::str::FromStr>(s: &str, target: &str) -> Result<T, ConversionError> {
+    s.trim()
+        .parse()
+        .map_err(|_| ConversionError::ValueType(format!("{s:?} cannot be parsed as {target}")))
+}
+
+// Integral floats within the range of the target type, from its `min` up to (excluding) `end`,
+// are accepted, so that the cast into the target type is exact.
+fn check_integral(f: f64, min: f64, end: f64, target: &str) -> Result<f64, ConversionError> {
+    if !f.is_finite() || f < min || f >= end {
+        Err(range_error(f, target))
+    } else if f.fract() != 0.0 {
+        Err(precision_error(f, target))
+    } else {
+        Ok(f)
+    }
+}
+
+// True if the float is exactly the integer; the range is checked first, because the cast
+// back into i128 saturates (e.g. i128::MAX as f64 is 2^127, which would be cast back into i128::MAX).
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn is_exactly(f: f64, i: i128) -> bool {
+    f >= i128::MIN as f64 && f < i128::MAX as f64 && f as i128 == i
+}
+
+macro_rules! from_scalar_for_integers {
+    ($($t:ty),+) => {
+        $(
+            impl FromScalar for $t {
+                // MIN is exact as f64, and the float above MAX is MAX + 1, as a power of two:
+                // MAX as f64 is exact for the small types, and rounds up to it for the others
+                #[allow(
+                    clippy::cast_lossless,
+                    clippy::cast_possible_truncation,
+                    clippy::cast_precision_loss,
+                    clippy::cast_sign_loss
+                )]
+                fn from_scalar(scalar: Scalar) -> Result<Self, ConversionError> {
+                    let target = stringify!($t);
+                    match scalar {
+                        Scalar::Bool(b) => Ok(<$t>::from(b)),
+                        Scalar::Int(i) => <$t>::try_from(i).map_err(|_| range_error(i, target)),
+                        Scalar::Float(f) => {
+                            check_integral(f, <$t>::MIN as f64, <$t>::MAX as f64 + 1.0, target)
+                                .map(|f| f as $t)
+                        }
+                        Scalar::Text(s) => parse(&s, target),
+                    }
+                }
+            }
+        )+
+    };
+}
+from_scalar_for_integers!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl FromScalar for f64 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn from_scalar(scalar: Scalar) -> Result<Self, ConversionError> {
+        match scalar {
+            Scalar::Bool(b) => Ok(f64::from(u8::from(b))),
+            Scalar::Int(i) => {
+                let f = i as f64;
+                if is_exactly(f, i) {
+                    Ok(f)
+                } else {
+                    Err(precision_error(i, "f64"))
+                }
+            }
+            Scalar::Float(f) => Ok(f),
+            Scalar::Text(s) => parse(&s, "f64"),
+        }
+    }
+}
+
+impl FromScalar for f32 {
+    // the exact comparison detects the loss of precision
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::float_cmp
+    )]
+    fn from_scalar(scalar: Scalar) -> Result<Self, ConversionError> {
+        match scalar {
+            Scalar::Bool(b) => Ok(f32::from(u8::from(b))),
+            Scalar::Int(i) => {
+                let f = i as f32;
+                if is_exactly(f64::from(f), i) {
+                    Ok(f)
+                } else {
+                    Err(precision_error(i, "f32"))
+                }
+            }
+            Scalar::Float(f) => {
+                let f32 = f as f32;
+                if f.is_nan() || f64::from(f32) == f {
+                    Ok(f32)
+                } else if f32.is_infinite() {
+                    Err(range_error(f, "f32"))
+                } else {
+                    Err(precision_error(f, "f32"))
+                }
+            }
+            Scalar::Text(s) => parse(&s, "f32"),
+        }
+    }
+}
+
+impl FromScalar for bool {
+    fn from_scalar(scalar: Scalar) -> Result<Self, ConversionError> {
+        match scalar {
+            Scalar::Bool(b) => Ok(b),
+            Scalar::Int(0) => Ok(false),
+            Scalar::Int(1) => Ok(true),
+            Scalar::Int(i) => Err(range_error(i, "bool")),
+            Scalar::Float(f) => Err(ConversionError::ValueType(format!(
+                "{f} cannot be converted into bool"
+            ))),
+            Scalar::Text(s) => parse(&s, "bool"),
+        }
+    }
+}
+
+impl FromScalar for String {
+    fn from_scalar(scalar: Scalar) -> Result<Self, ConversionError> {
+        Ok(match scalar {
+            Scalar::Bool(b) => b.to_string(),
+            Scalar::Int(i) => i.to_string(),
+            Scalar::Float(f) => f.to_string(),
+            Scalar::Text(s) => s,
+        })
+    }
+}
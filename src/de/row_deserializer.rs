@@ -1,9 +1,13 @@
 use crate::de::field_deserializer::FieldDeserializer;
+use crate::de::limits::Tally;
 use crate::de::{
-    DbValue, DbValueInto, DeserializableRow, DeserializationError, DeserializationResult,
+    DbValue, DeserializableRow, DeserializationConfig, DeserializationError, DeserializationResult,
+    NullPolicy,
 };
+use crate::transport;
 #[cfg(feature = "trace")]
 use log::trace;
+use serde::de::IntoDeserializer;
 use serde::Deserialize as SD;
 
 #[derive(Debug)]
@@ -15,26 +19,71 @@ enum Need {
 
 // Deserialize a single Row into a normal rust type.
 #[derive(Debug)]
-pub struct RowDeserializer<Row> {
+pub struct RowDeserializer<'a, Row: DeserializableRow> {
     row: Row,
+    // a value that was taken from the row to check it for NULL
+    peeked: Option<Row::Value>,
     need: Need,
+    config: &'a DeserializationConfig,
+    // the values for the observer; inactive for the fields of composite values,
+    // which are not reported as rows
+    tally: Tally,
+    // the indices of the columns with NULL values that were omitted (NullPolicy::SerdeDefault)
+    omitted_nulls: Vec<usize>,
+    // the span of the row
+    #[cfg(feature = "tracing")]
+    operation: Option<crate::instrument::Operation>,
 }
 
-impl<Row> RowDeserializer<Row>
+impl<'a, Row> RowDeserializer<'a, Row>
 where
     Row: DeserializableRow,
     <Row as DeserializableRow>::Value: DbValue,
 {
-    pub fn new(row: Row) -> RowDeserializer<Row> {
+    fn new(row: Row, config: &'a DeserializationConfig) -> RowDeserializer<'a, Row> {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::new()");
+        config.observer().row_started(row.number_of_fields());
+        #[cfg(feature = "tracing")]
+        let operation = crate::instrument::Operation::start_row(row.number_of_fields());
+        let mut rd = Self::new_composite(row, config);
+        rd.tally = Tally::new(config);
+        #[cfg(feature = "tracing")]
+        {
+            rd.operation = Some(operation);
+        }
+        rd
+    }
+
+    // Deserializes a complete row with the given function,
+    // and reports the row to the observer if this succeeds.
+    pub(crate) fn deserialize_row<T>(
+        row: Row,
+        config: &'a DeserializationConfig,
+        f: impl FnOnce(&mut Self) -> DeserializationResult<T>,
+    ) -> DeserializationResult<T> {
+        let mut rd = Self::new(row, config);
+        let value = f(&mut rd)?;
+        let observer = config.observer();
+        std::mem::replace(&mut rd.tally, Tally::inactive()).report(observer);
+        observer.row_deserialized();
+        Ok(value)
+    }
+
+    pub fn new_composite(row: Row, config: &'a DeserializationConfig) -> RowDeserializer<'a, Row> {
         let cols_treat = match row.len() {
             1 => Need::Can,
             _ => Need::Must,
         };
         RowDeserializer {
             row,
+            peeked: None,
             need: cols_treat,
+            config,
+            tally: Tally::inactive(),
+            omitted_nulls: Vec::new(),
+            #[cfg(feature = "tracing")]
+            operation: None,
         }
     }
 
@@ -42,6 +91,60 @@ where
         self.row.field_name(idx)
     }
 
+    // The number of values that are not yet consumed.
+    fn remaining(&self) -> usize {
+        self.row.len() + usize::from(self.peeked.is_some())
+    }
+
+    fn next_is_null(&mut self) -> bool {
+        if self.peeked.is_none() {
+            self.peeked = self.row.next();
+        }
+        self.peeked.as_ref().is_some_and(DbValue::is_null)
+    }
+
+    // Takes the value of a row with a single column if it is NULL.
+    pub(crate) fn take_single_null(&mut self) -> bool {
+        if self.remaining() == 1 && self.next_is_null() {
+            self.next_raw();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn next_raw(&mut self) -> Option<Row::Value> {
+        let value = self.peeked.take().or_else(|| self.row.next());
+        if value.is_some() {
+            self.tally.value_started();
+        }
+        value
+    }
+
+    // Adds the column name to errors about unexpected NULL values.
+    fn with_column_name(&self, idx: usize, e: DeserializationError) -> DeserializationError {
+        match e {
+            DeserializationError::UnexpectedNull(ref name) if name.is_empty() => {
+                DeserializationError::UnexpectedNull(
+                    self.get_field_name(idx).unwrap_or_default().to_string(),
+                )
+            }
+            e => e,
+        }
+    }
+
+    // Columns with NULL values are omitted with NullPolicy::SerdeDefault, and serde reports
+    // those of fields without default as missing; they are reported as unexpected NULLs instead.
+    fn missing_field_as_null(&self, e: DeserializationError) -> DeserializationError {
+        self.omitted_nulls
+            .iter()
+            .filter_map(|idx| self.get_field_name(*idx))
+            .find(|name| e.is_missing_field(name))
+            .map_or(e, |name| {
+                DeserializationError::UnexpectedNull(name.to_string())
+            })
+    }
+
     fn next_value(&mut self) -> DeserializationResult<Row::Value> {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::next_value()");
@@ -49,14 +152,14 @@ where
         if let Need::Must = self.need {
             return Err(DeserializationError::TrailingCols);
         }
-        match self.row.next() {
+        match self.next_raw() {
             Some(tv) => Ok(tv),
             None => Err(impl_err("next_value(): no more value found in row")),
         }
     }
 }
 
-impl<'x, 'a, Row: DeserializableRow> serde::Deserializer<'x> for &'a mut RowDeserializer<Row>
+impl<'x, Row: DeserializableRow> serde::Deserializer<'x> for &mut RowDeserializer<'_, Row>
 where
     <Row as DeserializableRow>::Value: DbValue,
 {
@@ -68,7 +171,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_any()");
-        visitor.visit_string(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_string(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_bool<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -77,7 +183,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_bool()");
-        visitor.visit_bool(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_bool(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -86,7 +195,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_u8()");
-        visitor.visit_u8(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_u8(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_u16<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -95,7 +207,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_u16()");
-        visitor.visit_u16(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_u16(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_u32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -104,7 +219,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_u32()");
-        visitor.visit_u32(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_u32(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_u64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -113,7 +231,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_u64()");
-        visitor.visit_u64(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_u64(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_i8<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -122,7 +243,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_i8()");
-        visitor.visit_i8(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_i8(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -131,7 +255,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_i16()");
-        visitor.visit_i16(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_i16(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -140,7 +267,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_i32()");
-        visitor.visit_i32(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_i32(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_i64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -149,7 +279,34 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_i64()");
-        visitor.visit_i64(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_i64(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
+    }
+
+    fn deserialize_i128<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_i128()");
+        visitor.visit_i128(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_u128()");
+        visitor.visit_u128(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_f32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -158,7 +315,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_f32()");
-        visitor.visit_f32(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_f32(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_f64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -167,7 +327,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_f64()");
-        visitor.visit_f64(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_f64(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_char<V>(self, _visitor: V) -> DeserializationResult<V::Value>
@@ -194,7 +357,10 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_string()");
-        visitor.visit_string(SD::deserialize(FieldDeserializer::new(self.next_value()?))?)
+        visitor.visit_string(SD::deserialize(FieldDeserializer::new(
+            self.next_value()?,
+            self.config,
+        ))?)
     }
 
     fn deserialize_unit<V>(self, _visitor: V) -> DeserializationResult<V::Value>
@@ -212,7 +378,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_option()");
-        FieldDeserializer::new(self.next_value()?).deserialize_option(visitor)
+        FieldDeserializer::new(self.next_value()?, self.config).deserialize_option(visitor)
     }
 
     #[inline]
@@ -223,22 +389,28 @@ where
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_seq()");
         if let Need::Done = self.need {
-            Err(impl_err(
-                "double-nesting (struct/tuple in struct/tuple) not possible",
-            ))
+            // a collection in a struct or tuple: an array (or JSON) value
+            FieldDeserializer::new(self.next_value()?, self.config).deserialize_seq(visitor)
         } else {
             self.need = Need::Done;
             visitor.visit_seq(FieldsSeqVisitor::new(self))
         }
     }
 
-    fn deserialize_map<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_map<V>(self, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
-        Err(DeserializationError::NotImplemented(
-            "RowDeserializer::deserialize_map()",
-        ))
+        #[cfg(feature = "trace")]
+        trace!("RowDeserializer::deserialize_map()");
+        if let Need::Done = self.need {
+            // a map in a struct or tuple: a map (or JSON) value
+            FieldDeserializer::new(self.next_value()?, self.config).deserialize_map(visitor)
+        } else {
+            // the row itself: the column names are the keys
+            self.need = Need::Done;
+            visitor.visit_map(ColumnsMapVisitor::new(self))
+        }
     }
 
     fn deserialize_unit_struct<V>(
@@ -264,22 +436,27 @@ where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!(
-            "RowDeserializer::deserialize_newtype_struct() with _name = {}",
-            _name
-        );
+        trace!("RowDeserializer::deserialize_newtype_struct() with _name = {_name}");
+        if transport::is_transport(_name) {
+            return FieldDeserializer::new(self.next_value()?, self.config)
+                .deserialize_newtype_struct(_name, visitor);
+        }
         visitor.visit_newtype_struct(self)
     }
 
     fn deserialize_tuple_struct<V>(
         self,
-        _name: &'static str,
-        _len: usize,
-        _visitor: V,
+        name: &'static str,
+        len: usize,
+        visitor: V,
     ) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
+        if let Need::Done = self.need {
+            return FieldDeserializer::new(self.next_value()?, self.config)
+                .deserialize_tuple_struct(name, len, visitor);
+        }
         Err(DeserializationError::NotImplemented(
             "RowDeserializer::deserialize_tuple_struct()",
         ))
@@ -287,8 +464,8 @@ where
 
     fn deserialize_struct<V>(
         self,
-        _name: &'static str,
-        _fields: &'static [&'static str],
+        name: &'static str,
+        fields: &'static [&'static str],
         visitor: V,
     ) -> DeserializationResult<V::Value>
     where
@@ -297,10 +474,14 @@ where
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_struct()");
         if let Need::Done = self.need {
-            Err(impl_err("double-nesting (struct in struct) not possible"))
+            // a struct in a struct or tuple: a composite, map, or JSON value
+            FieldDeserializer::new(self.next_value()?, self.config)
+                .deserialize_struct(name, fields, visitor)
         } else {
             self.need = Need::Done;
-            visitor.visit_map(FieldsMapVisitor::new(self))
+            visitor
+                .visit_map(FieldsMapVisitor::new(self))
+                .map_err(|e| self.missing_field_as_null(e))
         }
     }
 
@@ -310,7 +491,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_bytes()");
-        visitor.visit_bytes(&DbValueInto::<Vec<u8>>::try_into(self.next_value()?)?)
+        FieldDeserializer::new(self.next_value()?, self.config).deserialize_bytes(visitor)
     }
 
     fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -319,19 +500,18 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_byte_buf()");
-        visitor.visit_bytes(&DbValueInto::<Vec<u8>>::try_into(self.next_value()?)?)
+        FieldDeserializer::new(self.next_value()?, self.config).deserialize_byte_buf(visitor)
     }
 
-    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> DeserializationResult<V::Value>
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> DeserializationResult<V::Value>
     where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_tuple()");
         if let Need::Done = self.need {
-            Err(impl_err(
-                "double-nesting (struct/tuple in struct/tuple) not possible",
-            ))
+            // a tuple in a struct or tuple: a composite (or JSON) value
+            FieldDeserializer::new(self.next_value()?, self.config).deserialize_tuple(len, visitor)
         } else {
             self.need = Need::Done;
             visitor.visit_seq(FieldsSeqVisitor::new(self))
@@ -356,7 +536,7 @@ where
     where
         V: serde::de::Visitor<'x>,
     {
-        match self.row.len() {
+        match self.remaining() {
             0 => Err(impl_err(
                 "empty row in RowDeserializer::deserialize_identifier()",
             )),
@@ -366,9 +546,7 @@ where
                     Some(field_name) => {
                         #[cfg(feature = "trace")]
                         trace!(
-                            "RowDeserializer::deserialize_identifier(): column {:?} ({})",
-                            idx,
-                            field_name
+                            "RowDeserializer::deserialize_identifier(): column {idx:?} ({field_name})"
                         );
                         visitor.visit_str(field_name)
                     }
@@ -387,31 +565,31 @@ where
         #[cfg(feature = "trace")]
         trace!("RowDeserializer::deserialize_ignored_any()");
         let field_name = self
-            .get_field_name(self.row.number_of_fields() - self.row.len())
+            .get_field_name(self.row.number_of_fields() - self.remaining())
             .unwrap_or("unknown");
         Err(DeserializationError::UnknownField(field_name.to_string()))
     }
 }
 
-struct FieldsMapVisitor<'a, R: 'a + DeserializableRow>
+struct FieldsMapVisitor<'a, 'b, R: 'a + DeserializableRow>
 where
     <R as DeserializableRow>::Value: DbValue,
 {
-    de: &'a mut RowDeserializer<R>,
+    de: &'a mut RowDeserializer<'b, R>,
 }
 
-impl<'a, R: DeserializableRow> FieldsMapVisitor<'a, R>
+impl<'a, 'b, R: DeserializableRow> FieldsMapVisitor<'a, 'b, R>
 where
     <R as DeserializableRow>::Value: DbValue,
 {
-    pub fn new(de: &'a mut RowDeserializer<R>) -> Self {
+    pub fn new(de: &'a mut RowDeserializer<'b, R>) -> Self {
         #[cfg(feature = "trace")]
         trace!("FieldsMapVisitor::new()");
         FieldsMapVisitor { de }
     }
 }
 
-impl<'x, 'a, R: DeserializableRow> serde::de::MapAccess<'x> for FieldsMapVisitor<'a, R>
+impl<'x, R: DeserializableRow> serde::de::MapAccess<'x> for FieldsMapVisitor<'_, '_, R>
 where
     <R as DeserializableRow>::Value: DbValue,
 {
@@ -421,7 +599,15 @@ where
     where
         K: serde::de::DeserializeSeed<'x>,
     {
-        match self.de.row.len() {
+        if self.de.config.null_policy() == NullPolicy::SerdeDefault {
+            // omit NULL values, so that serde applies the defaults of the fields
+            while self.de.remaining() > 0 && self.de.next_is_null() {
+                let idx = self.de.row.number_of_fields() - self.de.remaining();
+                self.de.omitted_nulls.push(idx);
+                self.de.next_raw();
+            }
+        }
+        match self.de.remaining() {
             0 => {
                 #[cfg(feature = "trace")]
                 trace!("FieldsMapVisitor::next_key_seed() on empty row");
@@ -430,14 +616,14 @@ where
             len => {
                 let idx = self.de.row.number_of_fields() - len;
                 #[cfg(feature = "trace")]
-                trace!("FieldsMapVisitor::next_key_seed() for col {}", idx);
+                trace!("FieldsMapVisitor::next_key_seed() for col {idx}");
                 let value = seed.deserialize(&mut *self.de);
                 if let Ok(res) = value {
                     Ok(Some(res))
                 } else {
                     let fname = self.de.get_field_name(idx).unwrap();
                     #[cfg(feature = "trace")]
-                    trace!("FieldsMapVisitor::next_key_seed(): Error at {}", fname);
+                    trace!("FieldsMapVisitor::next_key_seed(): Error at {fname}");
                     Err(DeserializationError::UnknownField(fname.to_string()))
                 }
             }
@@ -449,44 +635,98 @@ where
     where
         V: serde::de::DeserializeSeed<'x>,
     {
-        match self.de.row.len() {
+        match self.de.remaining() {
             0 => Err(impl_err(
                 "FieldsMapVisitor::next_value_seed(): no more value",
             )),
-            _len => {
+            len => {
+                let idx = self.de.row.number_of_fields() - len;
                 #[cfg(feature = "trace")]
-                trace!(
-                    "FieldsMapVisitor::next_value_seed() for col {}",
-                    self.de.row.number_of_fields() - _len
-                );
+                trace!("FieldsMapVisitor::next_value_seed() for col {idx}");
                 seed.deserialize(&mut *self.de)
+                    .map_err(|e| self.de.with_column_name(idx, e))
+            }
+        }
+    }
+}
+
+// Provides the columns of a row as the entries of a map.
+struct ColumnsMapVisitor<'a, 'b, R: 'a + DeserializableRow>
+where
+    <R as DeserializableRow>::Value: DbValue,
+{
+    de: &'a mut RowDeserializer<'b, R>,
+}
+
+impl<'a, 'b, R: DeserializableRow> ColumnsMapVisitor<'a, 'b, R>
+where
+    <R as DeserializableRow>::Value: DbValue,
+{
+    pub fn new(de: &'a mut RowDeserializer<'b, R>) -> Self {
+        #[cfg(feature = "trace")]
+        trace!("ColumnsMapVisitor::new()");
+        ColumnsMapVisitor { de }
+    }
+}
+
+impl<'x, R: DeserializableRow> serde::de::MapAccess<'x> for ColumnsMapVisitor<'_, '_, R>
+where
+    <R as DeserializableRow>::Value: DbValue,
+{
+    type Error = DeserializationError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: serde::de::DeserializeSeed<'x>,
+    {
+        match self.de.remaining() {
+            0 => Ok(None),
+            len => {
+                let idx = self.de.row.number_of_fields() - len;
+                let name = self.de.get_field_name(idx).unwrap_or_default().to_string();
+                seed.deserialize(name.into_deserializer()).map(Some)
             }
         }
     }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::DeserializeSeed<'x>,
+    {
+        let idx = self.de.row.number_of_fields() - self.de.remaining();
+        match self.de.next_raw() {
+            None => Err(impl_err(
+                "ColumnsMapVisitor::next_value_seed(): no more value",
+            )),
+            Some(val) => seed
+                .deserialize(FieldDeserializer::new(val, self.de.config))
+                .map_err(|e| self.de.with_column_name(idx, e)),
+        }
+    }
 }
 
 fn impl_err(s: &'static str) -> DeserializationError {
     DeserializationError::Usage(s.to_string())
 }
 
-struct FieldsSeqVisitor<'a, R: 'a + DeserializableRow>
+struct FieldsSeqVisitor<'a, 'b, R: 'a + DeserializableRow>
 where
     <R as DeserializableRow>::Value: DbValue,
 {
-    de: &'a mut RowDeserializer<R>,
+    de: &'a mut RowDeserializer<'b, R>,
 }
-impl<'a, R: DeserializableRow> FieldsSeqVisitor<'a, R>
+impl<'a, 'b, R: DeserializableRow> FieldsSeqVisitor<'a, 'b, R>
 where
     <R as DeserializableRow>::Value: DbValue,
 {
-    pub fn new(de: &'a mut RowDeserializer<R>) -> Self {
+    pub fn new(de: &'a mut RowDeserializer<'b, R>) -> Self {
         #[cfg(feature = "trace")]
         trace!("FieldsSeqVisitor::new()");
         FieldsSeqVisitor { de }
     }
 }
 
-impl<'x, 'a, R> serde::de::SeqAccess<'x> for FieldsSeqVisitor<'a, R>
+impl<'x, R> serde::de::SeqAccess<'x> for FieldsSeqVisitor<'_, '_, R>
 where
     R: DeserializableRow,
     <R as DeserializableRow>::Value: DbValue,
@@ -499,9 +739,13 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("FieldsSeqVisitor.next_element_seed()");
-        match self.de.row.next() {
+        let idx = self.de.row.number_of_fields() - self.de.remaining();
+        match self.de.next_raw() {
             None => Ok(None),
-            Some(val) => seed.deserialize(FieldDeserializer::new(val)).map(Some),
+            Some(val) => seed
+                .deserialize(FieldDeserializer::new(val, self.de.config))
+                .map(Some)
+                .map_err(|e| self.de.with_column_name(idx, e)),
         }
     }
 }
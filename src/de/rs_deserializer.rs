@@ -1,8 +1,10 @@
+use crate::de::limits;
 use crate::de::row_deserializer::RowDeserializer;
 use crate::de::{
-    DbValue, DeserializableResultSet, DeserializableRow, DeserializationError,
-    DeserializationResult,
+    DbValue, DeserializableResultSet, DeserializableRow, DeserializationConfig,
+    DeserializationError, DeserializationResult,
 };
+use crate::transport;
 #[cfg(feature = "trace")]
 use log::trace;
 use serde::Deserialize as SD;
@@ -16,17 +18,23 @@ enum Need {
 
 // Deserialize a ResultSet into a normal rust type.
 #[derive(Debug)]
-pub struct RsDeserializer<RS> {
+pub struct RsDeserializer<'a, RS> {
     rs: RS,
     need: Need,
+    config: &'a DeserializationConfig,
+    rows: usize,
+    single_null: bool,
 }
 
-impl<RS> RsDeserializer<RS>
+impl<'a, RS> RsDeserializer<'a, RS>
 where
     RS: DeserializableResultSet,
     <<RS as DeserializableResultSet>::Row as DeserializableRow>::Value: DbValue,
 {
-    pub fn try_new(mut rs: RS) -> Result<RsDeserializer<RS>, DeserializationError> {
+    pub fn try_new(
+        mut rs: RS,
+        config: &'a DeserializationConfig,
+    ) -> Result<RsDeserializer<'a, RS>, DeserializationError> {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::new()");
         let need = if rs.has_multiple_rows()? {
@@ -34,23 +42,62 @@ where
         } else {
             Need::Can
         };
-        Ok(RsDeserializer { rs, need })
+        Ok(RsDeserializer {
+            rs,
+            need,
+            config,
+            rows: 0,
+            single_null: false,
+        })
+    }
+
+    // Deserializes the result set; with feature `tracing`, the number of rows is recorded
+    // in the span of the operation.
+    pub(crate) fn deserialize<'de, T: serde::Deserialize<'de>>(
+        rs: RS,
+        config: &'a DeserializationConfig,
+        #[cfg(feature = "tracing")] operation: &crate::instrument::Operation,
+    ) -> DeserializationResult<T> {
+        let mut deserializer = Self::try_new(rs, config)?;
+        let result = match T::deserialize(&mut deserializer) {
+            // the NULL value of a 1x1 result set is None for Option<T>, see deserialize_option()
+            Err(_) if deserializer.single_null => {
+                T::deserialize(serde::de::value::UnitDeserializer::new())
+            }
+            result => result,
+        };
+        #[cfg(feature = "tracing")]
+        operation.record("rows", deserializer.rows);
+        result
+    }
+
+    fn deserialize_single_row<T>(
+        &mut self,
+        f: impl FnOnce(
+            &mut RowDeserializer<'_, <RS as DeserializableResultSet>::Row>,
+        ) -> DeserializationResult<T>,
+    ) -> DeserializationResult<T> {
+        let row = self.pop_single_row()?;
+        RowDeserializer::deserialize_row(row, self.config, f)
     }
 
     fn pop_single_row(&mut self) -> DeserializationResult<<RS as DeserializableResultSet>::Row> {
         if let Need::Must = self.need {
             return Err(DeserializationError::TrailingRows);
-        };
+        }
         match self.rs.next()? {
             None => Err(DeserializationError::Usage(String::from(
                 "no row found in result set",
             ))),
-            Some(row) => Ok(row),
+            Some(row) => {
+                self.rows += 1;
+                Ok(row)
+            }
         }
     }
 }
 
-impl<'x, 'a, RS: DeserializableResultSet> serde::Deserializer<'x> for &'a mut RsDeserializer<RS>
+impl<'x, RS: DeserializableResultSet> serde::Deserializer<'x> for &mut RsDeserializer<'_, RS>
 where
     <<RS as DeserializableResultSet>::Row as DeserializableRow>::Value: DbValue,
 {
@@ -60,9 +107,7 @@ where
     where
         V: serde::de::Visitor<'x>,
     {
-        visitor.visit_string(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_string(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_bool<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -71,9 +116,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_bool()");
-        visitor.visit_bool(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_bool(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -82,9 +125,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_u8()");
-        visitor.visit_u8(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_u8(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_u16<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -93,9 +134,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_u16()");
-        visitor.visit_u16(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_u16(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_u32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -104,9 +143,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_u32()");
-        visitor.visit_u32(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_u32(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_u64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -115,9 +152,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_u64()");
-        visitor.visit_u64(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_u64(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_i8<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -126,9 +161,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_i8()");
-        visitor.visit_i8(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_i8(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -137,9 +170,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_i16()");
-        visitor.visit_i16(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_i16(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -148,9 +179,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_i32()");
-        visitor.visit_i32(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_i32(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_i64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -159,9 +188,25 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_i64()");
-        visitor.visit_i64(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_i64(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
+    }
+
+    fn deserialize_i128<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("RsDeserializer::deserialize_i128()");
+        visitor.visit_i128(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'x>,
+    {
+        #[cfg(feature = "trace")]
+        trace!("RsDeserializer::deserialize_u128()");
+        visitor.visit_u128(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_f32<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -170,9 +215,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_f32()");
-        visitor.visit_f32(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_f32(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_f64<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -181,9 +224,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_f64()");
-        visitor.visit_f64(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_f64(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_char<V>(self, _visitor: V) -> DeserializationResult<V::Value>
@@ -210,9 +251,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_string()");
-        visitor.visit_string(SD::deserialize(&mut RowDeserializer::new(
-            self.pop_single_row()?,
-        ))?)
+        visitor.visit_string(self.deserialize_single_row(|rd| SD::deserialize(rd))?)
     }
 
     fn deserialize_unit<V>(self, _visitor: V) -> DeserializationResult<V::Value>
@@ -230,8 +269,29 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_option()");
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
-        rd.deserialize_option(visitor)
+        if let Need::Must = self.need {
+            return Err(DeserializationError::TrailingRows);
+        }
+        let row = self.rs.next()?;
+        self.rows += usize::from(row.is_some());
+        match row {
+            None => visitor.visit_none(),
+            Some(row) => {
+                // a NULL value in a 1x1 result set is Some(None) for Option<Option<T>>;
+                // other targets reject it, and deserialize() falls back to None
+                let mut single_null = false;
+                let result = RowDeserializer::deserialize_row(row, self.config, |rd| {
+                    if rd.take_single_null() {
+                        single_null = true;
+                        visitor.visit_some(NullValue)
+                    } else {
+                        visitor.visit_some(rd)
+                    }
+                });
+                self.single_null = single_null;
+                result
+            }
+        }
     }
 
     fn deserialize_seq<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -282,10 +342,10 @@ where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!(
-            "RsDeserializer::deserialize_newtype_struct() with name = {}",
-            _name
-        );
+        trace!("RsDeserializer::deserialize_newtype_struct() with name = {_name}");
+        if transport::is_transport(_name) {
+            return self.deserialize_single_row(|rd| rd.deserialize_newtype_struct(_name, visitor));
+        }
         visitor.visit_newtype_struct(self)
     }
 
@@ -299,12 +359,8 @@ where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!(
-            "RsDeserializer::deserialize_tuple_struct() with name = {}",
-            name
-        );
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
-        rd.deserialize_tuple_struct(name, len, visitor)
+        trace!("RsDeserializer::deserialize_tuple_struct() with name = {name}");
+        self.deserialize_single_row(|rd| rd.deserialize_tuple_struct(name, len, visitor))
     }
 
     fn deserialize_struct<V>(
@@ -317,9 +373,8 @@ where
         V: serde::de::Visitor<'x>,
     {
         #[cfg(feature = "trace")]
-        trace!("RsDeserializer::deserialize_struct() with name = {}", name);
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
-        rd.deserialize_struct(name, fields, visitor)
+        trace!("RsDeserializer::deserialize_struct() with name = {name}");
+        self.deserialize_single_row(|rd| rd.deserialize_struct(name, fields, visitor))
     }
 
     fn deserialize_bytes<V>(self, visitor: V) -> DeserializationResult<V::Value>
@@ -328,8 +383,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_bytes()");
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
-        rd.deserialize_bytes(visitor)
+        self.deserialize_single_row(|rd| rd.deserialize_bytes(visitor))
     }
 
     fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -338,8 +392,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_byte_buf()");
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
-        rd.deserialize_byte_buf(visitor)
+        self.deserialize_single_row(|rd| rd.deserialize_byte_buf(visitor))
     }
 
     fn deserialize_tuple<V>(self, len: usize, visitor: V) -> DeserializationResult<V::Value>
@@ -348,8 +401,7 @@ where
     {
         #[cfg(feature = "trace")]
         trace!("RsDeserializer::deserialize_tuple()");
-        let mut rd = RowDeserializer::new(self.pop_single_row()?);
-        rd.deserialize_tuple(len, visitor)
+        self.deserialize_single_row(|rd| rd.deserialize_tuple(len, visitor))
     }
 
     fn deserialize_enum<V>(
@@ -386,19 +438,19 @@ where
 }
 
 // we use generalization <R> here because this allows us to bind the parameter to the lifetime 'a
-struct RowsVisitor<'a, R: 'a> {
-    de: &'a mut RsDeserializer<R>,
+struct RowsVisitor<'a, 'b, R: 'a> {
+    de: &'a mut RsDeserializer<'b, R>,
 }
 
-impl<'a, R> RowsVisitor<'a, R> {
-    pub fn new(de: &'a mut RsDeserializer<R>) -> Self {
+impl<'a, 'b, R> RowsVisitor<'a, 'b, R> {
+    pub fn new(de: &'a mut RsDeserializer<'b, R>) -> Self {
         #[cfg(feature = "trace")]
         trace!("RowsVisitor::new()");
         RowsVisitor { de }
     }
 }
 
-impl<'x, 'a, R: DeserializableResultSet> serde::de::SeqAccess<'x> for RowsVisitor<'a, R> {
+impl<'x, R: DeserializableResultSet> serde::de::SeqAccess<'x> for RowsVisitor<'_, '_, R> {
     type Error = DeserializationError;
 
     fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
@@ -409,7 +461,39 @@ impl<'x, 'a, R: DeserializableResultSet> serde::de::SeqAccess<'x> for RowsVisito
         trace!("RowsVisitor.next_element_seed()");
         match self.de.rs.next()? {
             None => Ok(None),
-            Some(row) => seed.deserialize(&mut RowDeserializer::new(row)).map(Some),
+            Some(row) => {
+                self.de.rows += 1;
+                limits::check_rows(self.de.rows, self.de.config)?;
+                RowDeserializer::deserialize_row(row, self.de.config, |rd| seed.deserialize(rd))
+                    .map(Some)
+            }
         }
     }
 }
+
+// The NULL value of a 1x1 result set: None for an Option, and unexpected for everything else.
+struct NullValue;
+
+impl<'de> serde::Deserializer<'de> for NullValue {
+    type Error = DeserializationError;
+
+    fn deserialize_any<V>(self, _visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        Err(DeserializationError::UnexpectedNull(String::new()))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> DeserializationResult<V::Value>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_none()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
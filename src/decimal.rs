@@ -0,0 +1,129 @@
+//! Support for transporting decimal values between the database driver and
+//! `bigdecimal::BigDecimal` or `rust_decimal::Decimal`, without detours through
+//! `f64` or strings.
+//!
+//! Drivers with a binary DECIMAL representation override
+//! [`DbValue::try_into_decimal()`](../de/trait.DbValue.html#method.try_into_decimal) and
+//! [`DbvFactory::serialize_decimal()`](../ser/trait.DbvFactory.html#method.serialize_decimal),
+//! and exchange the value as a [`DbDecimal`](struct.DbDecimal.html),
+//! i.e., as mantissa and scale.
+//! The default implementations of these methods fall back to the driver's string conversions.
+//!
+//! Fields of type `BigDecimal` or `Decimal` use the transport by naming this module
+//! (or its submodule [`option`](option/index.html) for `Option`s) in a serde attribute:
+//!
+//! ```rust,ignore
+//! use bigdecimal::BigDecimal;
+//! use rust_decimal::Decimal;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Booking {
+//!     #[serde(with = "serde_db::decimal")]
+//!     amount: BigDecimal,
+//!     #[serde(with = "serde_db::decimal::option")]
+//!     discount: Option<Decimal>,
+//! }
+//! ```
+//!
+//! Other serde formats see such fields as newtype structs around the 24 bytes of
+//! mantissa and scale, not as numbers;
+//! types that are also exchanged with such formats should use a separate field or type.
+//!
+//! Individual values can be converted directly:
+//!
+//! ```rust,ignore
+//! let amount: BigDecimal = db_value.try_into_decimal()?.into();
+//! ```
+
+mod conversions;
+mod db_decimal;
+
+pub use self::conversions::DecimalType;
+pub use self::db_decimal::DbDecimal;
+
+use crate::de::ConversionError;
+use crate::transport::{self, binary};
+use std::marker::PhantomData;
+
+/// Serializes a decimal value as a `DbDecimal`, which the driver receives in
+/// [`DbvFactory::serialize_decimal()`](../ser/trait.DbvFactory.html#method.serialize_decimal).
+///
+/// # Errors
+///
+/// `S::Error` if the value cannot be represented as a `DbDecimal`, or if serialization fails.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: DecimalType,
+    S: serde::Serializer,
+{
+    binary::serialize::<Decimals<T>, S>(value, serializer)
+}
+
+/// Deserializes a decimal value from the `DbDecimal` that the driver provides with
+/// [`DbValue::try_into_decimal()`](../de/trait.DbValue.html#method.try_into_decimal).
+///
+/// # Errors
+///
+/// `D::Error` if the value cannot be converted into the target type without loss.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DecimalType,
+    D: serde::Deserializer<'de>,
+{
+    binary::deserialize::<Decimals<T>, D>(deserializer)
+}
+
+/// Variants of [`serialize`] and [`deserialize`]
+/// for nullable decimal columns.
+pub mod option {
+    use super::{DecimalType, Decimals};
+    use crate::transport::binary;
+
+    /// Serializes `None` as NULL, and other values like
+    /// [`decimal::serialize`](super::serialize).
+    ///
+    /// # Errors
+    ///
+    /// `S::Error` if the value cannot be represented as a `DbDecimal`,
+    /// or if serialization fails.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: DecimalType,
+        S: serde::Serializer,
+    {
+        binary::serialize_option::<Decimals<T>, S>(value.as_ref(), serializer)
+    }
+
+    /// Deserializes NULL as `None`, and other values like
+    /// [`decimal::deserialize`](super::deserialize).
+    ///
+    /// # Errors
+    ///
+    /// `D::Error` if the value cannot be converted into the target type without loss.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: DecimalType,
+        D: serde::Deserializer<'de>,
+    {
+        binary::deserialize_option::<Decimals<T>, D>(deserializer)
+    }
+}
+
+// Transports a DecimalType as the bytes of a DbDecimal.
+struct Decimals<T>(PhantomData<T>);
+
+impl<T: DecimalType> binary::Codec for Decimals<T> {
+    type Value = T;
+    const NAME: &'static str = transport::DECIMAL;
+    const EXPECTING: &'static str = "a decimal value";
+
+    fn encode(value: &T) -> Result<Vec<u8>, ConversionError> {
+        Ok(value.to_db_decimal()?.to_bytes().to_vec())
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Result<T, ConversionError>> {
+        DbDecimal::from_bytes(bytes).map(T::from_db_decimal)
+    }
+}
@@ -0,0 +1,107 @@
+use super::DbDecimal;
+use crate::de::ConversionError;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::BigDecimal;
+use rust_decimal::Decimal;
+
+/// Rust decimal types that can be converted from and into a [`DbDecimal`](struct.DbDecimal.html).
+///
+/// Implemented for `bigdecimal::BigDecimal` and `rust_decimal::Decimal`.
+pub trait DecimalType: Sized {
+    /// Converts the value into a `DbDecimal`.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError::NumberRange` if the value exceeds the range of `DbDecimal`.
+    fn to_db_decimal(&self) -> Result<DbDecimal, ConversionError>;
+
+    /// Converts a `DbDecimal` into a value of this type.
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError::NumberRange` if the value cannot be represented without loss.
+    fn from_db_decimal(dbd: DbDecimal) -> Result<Self, ConversionError>;
+}
+
+impl From<DbDecimal> for BigDecimal {
+    fn from(dbd: DbDecimal) -> BigDecimal {
+        BigDecimal::new(BigInt::from(dbd.mantissa()), dbd.scale())
+    }
+}
+
+impl TryFrom<&BigDecimal> for DbDecimal {
+    type Error = ConversionError;
+
+    fn try_from(bd: &BigDecimal) -> Result<DbDecimal, ConversionError> {
+        let (bigint, scale) = bd.as_bigint_and_exponent();
+        if let Ok(mantissa) = i128::try_from(&bigint) {
+            return Ok(DbDecimal::new(mantissa, scale));
+        }
+        // trailing zeros might be removable
+        let (bigint, scale) = bd.normalized().into_bigint_and_exponent();
+        i128::try_from(&bigint)
+            .map(|mantissa| DbDecimal::new(mantissa, scale))
+            .map_err(|_| {
+                ConversionError::NumberRange(format!(
+                    "BigDecimal {bd} exceeds the range of DbDecimal"
+                ))
+            })
+    }
+}
+
+impl DecimalType for BigDecimal {
+    fn to_db_decimal(&self) -> Result<DbDecimal, ConversionError> {
+        DbDecimal::try_from(self)
+    }
+
+    fn from_db_decimal(dbd: DbDecimal) -> Result<BigDecimal, ConversionError> {
+        Ok(BigDecimal::from(dbd))
+    }
+}
+
+impl From<Decimal> for DbDecimal {
+    fn from(d: Decimal) -> DbDecimal {
+        DbDecimal::new(d.mantissa(), i64::from(d.scale()))
+    }
+}
+
+impl TryFrom<DbDecimal> for Decimal {
+    type Error = ConversionError;
+
+    fn try_from(dbd: DbDecimal) -> Result<Decimal, ConversionError> {
+        let range_error = || {
+            ConversionError::NumberRange(format!(
+                "DbDecimal {dbd} exceeds the range of rust_decimal::Decimal"
+            ))
+        };
+
+        let dbd = dbd.reduce_scale_to(i64::from(Decimal::MAX_SCALE));
+        let (mantissa, scale) = if dbd.scale() < 0 {
+            // move the exponent into the mantissa
+            let factor = u32::try_from(dbd.scale().unsigned_abs())
+                .ok()
+                .and_then(|exp| 10_i128.checked_pow(exp))
+                .ok_or_else(range_error)?;
+            (
+                dbd.mantissa().checked_mul(factor).ok_or_else(range_error)?,
+                0,
+            )
+        } else {
+            (
+                dbd.mantissa(),
+                u32::try_from(dbd.scale()).map_err(|_| range_error())?,
+            )
+        };
+        Decimal::try_from_i128_with_scale(mantissa, scale).map_err(|_| range_error())
+    }
+}
+
+impl DecimalType for Decimal {
+    fn to_db_decimal(&self) -> Result<DbDecimal, ConversionError> {
+        Ok(DbDecimal::from(*self))
+    }
+
+    fn from_db_decimal(dbd: DbDecimal) -> Result<Decimal, ConversionError> {
+        Decimal::try_from(dbd)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::de::ConversionError;
+
+const BYTES_LEN: usize = 24;
+
+// The maximal number of zeros that Display pads a value with.
+const MAX_PADDING: usize = 64;
+
+/// Transport type for decimal values, consisting of an integer mantissa and a scale.
+///
+/// The numeric value is `mantissa * 10^(-scale)`; a negative scale thus multiplies
+/// the mantissa with a power of ten.
+/// The mantissa covers all values of `DECIMAL(38, s)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DbDecimal {
+    mantissa: i128,
+    scale: i64,
+}
+
+impl DbDecimal {
+    /// Constructs a `DbDecimal` with the value `mantissa * 10^(-scale)`.
+    #[must_use]
+    pub fn new(mantissa: i128, scale: i64) -> DbDecimal {
+        DbDecimal { mantissa, scale }
+    }
+
+    /// Returns the mantissa.
+    #[must_use]
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the scale, i.e., the number of decimal digits behind the decimal point.
+    #[must_use]
+    pub fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    // Removes trailing zeros from the mantissa while the scale is bigger than min_scale.
+    pub(crate) fn reduce_scale_to(mut self, min_scale: i64) -> DbDecimal {
+        while self.scale > min_scale && self.mantissa % 10 == 0 {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+        self
+    }
+
+    pub(crate) fn to_bytes(self) -> [u8; BYTES_LEN] {
+        let mut bytes = [0_u8; BYTES_LEN];
+        bytes[..16].copy_from_slice(&self.mantissa.to_le_bytes());
+        bytes[16..].copy_from_slice(&self.scale.to_le_bytes());
+        bytes
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<DbDecimal> {
+        if bytes.len() == BYTES_LEN {
+            Some(DbDecimal {
+                mantissa: i128::from_le_bytes(bytes[..16].try_into().ok()?),
+                scale: i64::from_le_bytes(bytes[16..].try_into().ok()?),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for DbDecimal {
+    /// Writes the plain notation, like `-12.345`, or the scientific notation, like `1E400`,
+    /// if the plain notation would need more than 64 zeros.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        if self.mantissa < 0 {
+            f.write_str("-")?;
+        }
+        match usize::try_from(self.scale) {
+            Err(_) => {
+                // negative scale
+                if self.mantissa == 0 {
+                    return f.write_str(&digits);
+                }
+                match usize::try_from(self.scale.unsigned_abs()) {
+                    Ok(zeros) if zeros <= MAX_PADDING => write!(f, "{digits}{:0<zeros$}", ""),
+                    _ => write!(f, "{digits}E{}", self.scale.unsigned_abs()),
+                }
+            }
+            Ok(0) => f.write_str(&digits),
+            Ok(scale) if digits.len() > scale => {
+                let (int, frac) = digits.split_at(digits.len() - scale);
+                write!(f, "{int}.{frac}")
+            }
+            Ok(scale) if scale - digits.len() < MAX_PADDING => write!(f, "0.{digits:0>scale$}"),
+            Ok(scale) => write!(f, "{digits}E-{scale}"),
+        }
+    }
+}
+
+impl std::str::FromStr for DbDecimal {
+    type Err = ConversionError;
+
+    /// Parses the plain or scientific notation of a decimal number, like `-12.345` or `1.2E-7`.
+    fn from_str(s: &str) -> Result<DbDecimal, ConversionError> {
+        let parse_error = || ConversionError::ValueType(format!("cannot parse {s:?} as decimal"));
+
+        let (number, exponent) = match s.find(['e', 'E']) {
+            Some(idx) => (
+                &s[..idx],
+                s[idx + 1..].parse::<i64>().map_err(|_| parse_error())?,
+            ),
+            None => (s, 0),
+        };
+        let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+        let (sign, int) = match int.strip_prefix('-') {
+            Some(int) => ("-", int),
+            None => ("", int.strip_prefix('+').unwrap_or(int)),
+        };
+        if (int.is_empty() && frac.is_empty())
+            || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return Err(parse_error());
+        }
+
+        let mantissa = format!("{sign}{int}{frac}").parse::<i128>().map_err(|_| {
+            ConversionError::NumberRange(format!("{s:?} exceeds the decimal range"))
+        })?;
+        let scale = i64::try_from(frac.len())
+            .ok()
+            .and_then(|frac_len| frac_len.checked_sub(exponent))
+            .ok_or_else(parse_error)?;
+        Ok(DbDecimal { mantissa, scale })
+    }
+}
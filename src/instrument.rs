@@ -0,0 +1,57 @@
+// Support for the feature `tracing`.
+
+use std::time::Instant;
+
+// An entered span around a call of the public API or around the conversion of a row,
+// which records the duration when it is dropped.
+#[derive(Debug)]
+pub(crate) struct Operation {
+    span: tracing::span::EnteredSpan,
+    start: Instant,
+}
+
+impl Operation {
+    // The span must have a field `duration_us`.
+    pub(crate) fn start(span: tracing::Span) -> Self {
+        Operation {
+            span: span.entered(),
+            start: Instant::now(),
+        }
+    }
+
+    // The span of a single row, no matter if the row is converted directly
+    // or as part of a result set.
+    pub(crate) fn start_row(columns: usize) -> Self {
+        Self::start(tracing::debug_span!(
+            "serde_db::deserialize_row",
+            columns,
+            duration_us = tracing::field::Empty
+        ))
+    }
+
+    pub(crate) fn record(&self, field: &str, value: usize) {
+        self.span.record(field, value);
+    }
+
+    // Reports the result within the span, and closes it.
+    pub(crate) fn finish<T, E: std::fmt::Debug>(self, result: &Result<T, E>) {
+        report(result);
+        drop(self);
+    }
+}
+
+impl Drop for Operation {
+    fn drop(&mut self) {
+        let duration_us = u64::try_from(self.start.elapsed().as_micros()).unwrap_or(u64::MAX);
+        self.span.record("duration_us", duration_us);
+    }
+}
+
+// Emits an event if the call of the public API failed.
+// Failures are often expected by the caller (e.g. a missing row),
+// so the event is emitted on debug level.
+pub(crate) fn report<T, E: std::fmt::Debug>(result: &Result<T, E>) {
+    if let Err(ref e) = *result {
+        tracing::debug!(error = ?e, "serde_db operation failed");
+    }
+}
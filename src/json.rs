@@ -0,0 +1,89 @@
+use crate::transport;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+/// Wrapper for values that are stored in the database as JSON text.
+///
+/// When deserialized with `serde_db`, the textual value of the column is parsed with
+/// `serde_json` into `T`.
+/// When serialized with `serde_db`, `T` is converted into JSON text, which is handed to
+/// [`DbvFactory::serialize_json()`](ser/trait.DbvFactory.html#method.serialize_json).
+/// With other serializers and deserializers, `Json<T>` behaves like `T`.
+///
+/// ```rust,ignore
+/// use serde::{Deserialize, Serialize};
+/// use serde_db::Json;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Order {
+///     id: i32,
+///     items: Json<Vec<Item>>,
+/// }
+/// ```
+///
+/// Without the wrapper, fields with structured types (structs, tuples, `Vec`s, maps) are
+/// also parsed from JSON text during deserialization; the wrapper makes this explicit,
+/// and is required for serialization.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for Json<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Json(value)
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Json<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(transport::JSON, &self.0)
+    }
+}
+
+impl<'de, T: DeserializeOwned> serde::Deserialize<'de> for Json<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(transport::JSON, JsonVisitor(PhantomData))
+    }
+}
+
+struct JsonVisitor<T>(PhantomData<T>);
+
+impl<'de, T: DeserializeOwned> serde::de::Visitor<'de> for JsonVisitor<T> {
+    type Value = Json<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("JSON text")
+    }
+
+    // the FieldDeserializer provides the column's text
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        serde_json::from_str(value).map(Json).map_err(E::custom)
+    }
+
+    // for other deserializers, Json<T> is transparent
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Json)
+    }
+}
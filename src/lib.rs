@@ -14,5 +14,26 @@
 #![deny(clippy::pedantic)]
 #![forbid(unsafe_code)]
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod datetime;
 pub mod de;
+#[cfg(feature = "decimal")]
+pub mod decimal;
+#[cfg(feature = "tracing")]
+mod instrument;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "memory")]
+pub mod memory;
+pub mod observer;
 pub mod ser;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+mod transport;
+#[cfg(feature = "uuid")]
+pub mod uuid;
+pub mod value;
+
+#[cfg(feature = "json")]
+pub use crate::json::Json;
+pub use crate::value::Value;
@@ -0,0 +1,51 @@
+//! An in-memory implementation of the driver side of `serde_db`,
+//! for unit tests of code that is built on `serde_db`, and as a reference for
+//! implementors of database drivers.
+//!
+//! [`MemValue`] implements [`DbValue`](../de/trait.DbValue.html) with all numeric conversions
+//! (range- and precision-checked), NULL values, strings, bytes, arrays, maps,
+//! composite values, and, with the respective features, decimals and dates.
+//! [`ResultSet`] and [`Row`] implement
+//! [`DeserializableResultSet`](../de/trait.DeserializableResultSet.html) and
+//! [`DeserializableRow`](../de/trait.DeserializableRow.html),
+//! and [`ParameterType`] implements [`DbvFactory`](../ser/trait.DbvFactory.html).
+//! [`to_result_set`] builds a result set from values of any type that implements
+//! `serde::Serialize`, so that fixtures and expected results can share the same rust types.
+//! All of them use [`DeserializationError`](../de/enum.DeserializationError.html) and
+//! [`SerializationError`](../ser/enum.SerializationError.html) as error types.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_db::de::DeserializableResultSet;
+//! use serde_db::memory::{ColumnType, MemValue, ParameterType, ResultSet};
+//!
+//! #[derive(Debug, Deserialize, PartialEq)]
+//! struct Person {
+//!     id: u32,
+//!     name: String,
+//!     nickname: Option<String>,
+//! }
+//!
+//! let mut rs = ResultSet::new(&["id", "name", "nickname"]);
+//! rs.push(vec![1.into(), "Alice".into(), MemValue::Null]);
+//! rs.push(vec![2.into(), "Bob".into(), "Bobby".into()]);
+//! let persons: Vec<Person> = rs.try_into().unwrap();
+//! assert_eq!(Some("Bobby".to_string()), persons[1].nickname);
+//!
+//! let metadata = [
+//!     ParameterType::new(ColumnType::Int),
+//!     ParameterType::nullable(ColumnType::String),
+//! ];
+//! let params: Vec<MemValue> =
+//!     serde_db::ser::to_params(&(7_u8, None::<String>), &mut metadata.iter()).unwrap();
+//! assert_eq!(vec![MemValue::Int(7), MemValue::Null], params);
+//! ```
+mod fixture;
+mod mem_value;
+mod parameter_type;
+mod result_set;
+
+pub use self::fixture::{to_result_set, to_result_set_with};
+pub use self::mem_value::MemValue;
+pub use self::parameter_type::{ColumnType, ParameterType};
+pub use self::result_set::{ResultSet, Row};
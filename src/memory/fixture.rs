@@ -0,0 +1,65 @@
+use crate::memory::{ColumnType, ParameterType, ResultSet};
+use crate::ser::{to_named_params_with, SerializationConfig, SerializationError};
+use std::collections::VecDeque;
+
+/// Builds a result set from rust values, e.g. from test fixtures.
+///
+/// Each element of `rows` becomes a row of the result set.
+/// Structs and maps provide the column names with their field names or keys;
+/// values without a name (e.g. tuple elements) get their position (`"0"`, `"1"`, ...)
+/// as column name. All rows must produce the same columns.
+/// The values keep the representation that is closest to the rust value
+/// (see [`ColumnType::Any`]); `None` becomes `MemValue::Null`.
+///
+/// If `rows` is empty, the result set has no columns.
+///
+/// # Errors
+///
+/// `SerializationError` if a row cannot be serialized, or if the rows differ in their columns.
+pub fn to_result_set<T>(rows: &[T]) -> Result<ResultSet, SerializationError>
+where
+    T: serde::Serialize,
+{
+    to_result_set_with(rows, &SerializationConfig::default())
+}
+
+/// Builds a result set from rust values, using the given configuration.
+///
+/// See [`to_result_set`].
+///
+/// # Errors
+///
+/// `SerializationError` if a row cannot be serialized, or if the rows differ in their columns.
+pub fn to_result_set_with<T>(
+    rows: &[T],
+    config: &SerializationConfig,
+) -> Result<ResultSet, SerializationError>
+where
+    T: serde::Serialize,
+{
+    let mut columns: Option<Vec<String>> = None;
+    let mut values = VecDeque::with_capacity(rows.len());
+    for row in rows {
+        let (row_values, names) = to_named_params_with(
+            row,
+            &mut std::iter::repeat(ParameterType::nullable(ColumnType::Any)),
+            config,
+        )?;
+        let names: Vec<String> = names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| name.unwrap_or_else(|| i.to_string()))
+            .collect();
+        match columns {
+            None => columns = Some(names),
+            Some(ref columns) if *columns != names => {
+                return Err(SerializationError::StructuralMismatch(
+                    "all rows must have the same columns",
+                ));
+            }
+            Some(_) => {}
+        }
+        values.push_back(row_values);
+    }
+    Ok(ResultSet::from_parts(columns.unwrap_or_default(), values))
+}
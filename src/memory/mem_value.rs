@@ -0,0 +1,230 @@
+use crate::de::numeric::{Scalar, ToScalar};
+use crate::de::{ConversionError, DbValue, DbValueInto, DeserializationResult};
+use crate::value::ValueKind;
+
+/// A database value that is held in memory.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MemValue {
+    /// The NULL value.
+    Null,
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Int(i64),
+    /// A floating point value.
+    Float(f64),
+    /// A string value.
+    String(String),
+    /// A binary value.
+    Bytes(Vec<u8>),
+    /// A value of an array type.
+    Array(Vec<MemValue>),
+    /// A value of a map type.
+    Map(Vec<(String, MemValue)>),
+    /// A value of a composite type, with named fields.
+    Composite(Vec<(String, MemValue)>),
+    /// A decimal value.
+    #[cfg(feature = "decimal")]
+    Decimal(crate::decimal::DbDecimal),
+    /// A date, time, or timestamp.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    DateTime(crate::datetime::DbDateTime),
+}
+
+impl MemValue {
+    /// Converts the value into a rust value.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError` if the value cannot be converted into the target type.
+    pub fn try_into<'de, T>(self) -> DeserializationResult<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        DbValue::try_into(self)
+    }
+}
+
+impl DbValue for MemValue {
+    fn is_null(&self) -> bool {
+        matches!(*self, MemValue::Null)
+    }
+
+    fn is_empty_string(&self) -> bool {
+        matches!(*self, MemValue::String(ref s) if s.is_empty())
+    }
+
+    fn is_array(&self) -> bool {
+        matches!(*self, MemValue::Array(_))
+    }
+
+    fn try_into_elements(self) -> Result<Vec<Self>, ConversionError> {
+        match self {
+            MemValue::Array(elements) => Ok(elements),
+            mv => Err(ConversionError::ValueType(format!(
+                "{mv:?} is not an array"
+            ))),
+        }
+    }
+
+    fn is_composite(&self) -> bool {
+        matches!(*self, MemValue::Composite(_))
+    }
+
+    fn try_into_fields(self) -> Result<Vec<(String, Self)>, ConversionError> {
+        match self {
+            MemValue::Composite(fields) => Ok(fields),
+            mv => Err(ConversionError::ValueType(format!(
+                "{mv:?} is not a composite value"
+            ))),
+        }
+    }
+
+    fn is_map(&self) -> bool {
+        matches!(*self, MemValue::Map(_))
+    }
+
+    fn try_into_entries(self) -> Result<Vec<(String, Self)>, ConversionError> {
+        match self {
+            MemValue::Map(entries) => Ok(entries),
+            mv => Err(ConversionError::ValueType(format!("{mv:?} is not a map"))),
+        }
+    }
+
+    fn value_kind(&self) -> ValueKind {
+        match *self {
+            MemValue::Bool(_) => ValueKind::Bool,
+            MemValue::Int(_) => ValueKind::Int,
+            MemValue::Float(_) => ValueKind::Float,
+            MemValue::Bytes(_) => ValueKind::Bytes,
+            #[cfg(feature = "decimal")]
+            MemValue::Decimal(_) => ValueKind::Decimal,
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            MemValue::DateTime(_) => ValueKind::DateTime,
+            _ => ValueKind::String,
+        }
+    }
+
+    fn try_into_i128(self) -> Result<i128, ConversionError> {
+        self.try_into_scalar()?.try_into()
+    }
+
+    fn try_into_u128(self) -> Result<u128, ConversionError> {
+        self.try_into_scalar()?.try_into()
+    }
+
+    #[cfg(feature = "decimal")]
+    fn try_into_decimal(self) -> Result<crate::decimal::DbDecimal, ConversionError> {
+        match self {
+            MemValue::Decimal(d) => Ok(d),
+            MemValue::Int(i) => Ok(crate::decimal::DbDecimal::new(i.into(), 0)),
+            mv => DbValueInto::<String>::try_into(mv)?.parse(),
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn try_into_datetime(self) -> Result<crate::datetime::DbDateTime, ConversionError> {
+        match self {
+            MemValue::DateTime(dt) => Ok(dt),
+            mv => DbValueInto::<String>::try_into(mv)?.parse(),
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    fn try_into_uuid(self) -> Result<::uuid::Uuid, ConversionError> {
+        match self {
+            MemValue::Bytes(v) => {
+                ::uuid::Uuid::from_slice(&v).map_err(|e| ConversionError::ValueType(e.to_string()))
+            }
+            mv => ::uuid::Uuid::parse_str(&DbValueInto::<String>::try_into(mv)?)
+                .map_err(|e| ConversionError::ValueType(e.to_string())),
+        }
+    }
+}
+
+impl ToScalar for MemValue {
+    fn try_into_scalar(self) -> Result<Scalar, ConversionError> {
+        match self {
+            MemValue::Bool(b) => Ok(Scalar::Bool(b)),
+            MemValue::Int(i) => Ok(Scalar::Int(i.into())),
+            MemValue::Float(f) => Ok(Scalar::Float(f)),
+            MemValue::String(s) => Ok(Scalar::Text(s)),
+            #[cfg(feature = "decimal")]
+            MemValue::Decimal(d) => Ok(Scalar::Text(d.to_string())),
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            MemValue::DateTime(dt) => Ok(Scalar::Text(dt.to_string())),
+            mv => Err(ConversionError::ValueType(format!(
+                "{mv:?} is not a scalar value"
+            ))),
+        }
+    }
+}
+
+crate::impl_db_value_into!(MemValue);
+
+impl DbValueInto<Vec<u8>> for MemValue {
+    fn try_into(self) -> Result<Vec<u8>, ConversionError> {
+        match self {
+            MemValue::Bytes(v) => Ok(v),
+            MemValue::String(s) => Ok(s.into_bytes()),
+            mv => Err(ConversionError::ValueType(format!(
+                "{mv:?} cannot be converted into bytes"
+            ))),
+        }
+    }
+}
+
+impl From<bool> for MemValue {
+    fn from(b: bool) -> Self {
+        MemValue::Bool(b)
+    }
+}
+
+macro_rules! from_integers {
+    ($($t:ty),+) => {
+        $(
+            impl From<$t> for MemValue {
+                fn from(i: $t) -> Self {
+                    MemValue::Int(i.into())
+                }
+            }
+        )+
+    };
+}
+from_integers!(i8, i16, i32, i64, u8, u16, u32);
+
+impl From<f32> for MemValue {
+    fn from(f: f32) -> Self {
+        MemValue::Float(f.into())
+    }
+}
+
+impl From<f64> for MemValue {
+    fn from(f: f64) -> Self {
+        MemValue::Float(f)
+    }
+}
+
+impl From<&str> for MemValue {
+    fn from(s: &str) -> Self {
+        MemValue::String(s.to_string())
+    }
+}
+
+impl From<String> for MemValue {
+    fn from(s: String) -> Self {
+        MemValue::String(s)
+    }
+}
+
+impl From<Vec<u8>> for MemValue {
+    fn from(v: Vec<u8>) -> Self {
+        MemValue::Bytes(v)
+    }
+}
+
+impl<T: Into<MemValue>> From<Option<T>> for MemValue {
+    fn from(o: Option<T>) -> Self {
+        o.map_or(MemValue::Null, Into::into)
+    }
+}
@@ -0,0 +1,323 @@
+use crate::de::numeric::Scalar;
+use crate::de::ConversionError;
+use crate::memory::MemValue;
+use crate::ser::{parse_error, type_error, DbvFactory, SerializationError};
+
+/// The database types of [`ParameterType`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnType {
+    /// Accepts booleans.
+    Bool,
+    /// Accepts integers within the range of `i64`, integral floats, and numeric strings.
+    Int,
+    /// Accepts numbers that can be represented as `f64` without loss of precision,
+    /// and numeric strings.
+    Float,
+    /// Accepts strings and characters, and numbers and booleans in their textual form.
+    String,
+    /// Accepts byte arrays.
+    Bytes,
+    /// Accepts maps.
+    Map,
+    /// Accepts decimals, integers, and decimal strings.
+    #[cfg(feature = "decimal")]
+    Decimal,
+    /// Accepts dates, times, and timestamps, and strings in the ISO 8601 formats.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    DateTime,
+    /// Accepts all values, and keeps the representation that is closest to the rust value.
+    Any,
+}
+
+/// Describes a parameter of a (simulated) prepared statement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParameterType {
+    column_type: ColumnType,
+    nullable: bool,
+}
+
+impl ParameterType {
+    /// Describes a parameter that does not accept NULL values.
+    #[must_use]
+    pub fn new(column_type: ColumnType) -> Self {
+        ParameterType {
+            column_type,
+            nullable: false,
+        }
+    }
+
+    /// Describes a parameter that accepts NULL values.
+    #[must_use]
+    pub fn nullable(column_type: ColumnType) -> Self {
+        ParameterType {
+            column_type,
+            nullable: true,
+        }
+    }
+
+    /// Returns the database type.
+    #[must_use]
+    pub fn column_type(&self) -> ColumnType {
+        self.column_type
+    }
+
+    /// Returns true if the parameter accepts NULL values.
+    #[must_use]
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    // Converts scalar values into the database type.
+    fn scalar(
+        self,
+        scalar: Scalar,
+        value_type: &'static str,
+    ) -> Result<MemValue, SerializationError> {
+        let text = match scalar {
+            Scalar::Text(ref s) => Some(s.clone()),
+            _ => None,
+        };
+        let conversion_error = |e: ConversionError| match (e, text) {
+            (ConversionError::NumberRange(_), _) => {
+                SerializationError::Range(value_type, self.descriptor())
+            }
+            (e, Some(text)) => parse_error(text, self.descriptor(), Some(Box::new(e))),
+            (_, None) => type_error(value_type, self.descriptor()),
+        };
+        match (self.column_type, scalar) {
+            (ColumnType::Bool | ColumnType::Any, Scalar::Bool(b)) => Ok(MemValue::Bool(b)),
+            (ColumnType::Any, Scalar::Float(f)) => Ok(MemValue::Float(f)),
+            (ColumnType::Any, Scalar::Text(s)) => Ok(MemValue::String(s)),
+            (ColumnType::Int, scalar @ (Scalar::Int(_) | Scalar::Float(_) | Scalar::Text(_)))
+            | (ColumnType::Any, scalar @ Scalar::Int(_)) => scalar
+                .try_into()
+                .map(MemValue::Int)
+                .map_err(conversion_error),
+            (ColumnType::Float, scalar @ (Scalar::Int(_) | Scalar::Float(_) | Scalar::Text(_))) => {
+                scalar
+                    .try_into()
+                    .map(MemValue::Float)
+                    .map_err(conversion_error)
+            }
+            (ColumnType::String, scalar) => scalar
+                .try_into()
+                .map(MemValue::String)
+                .map_err(conversion_error),
+            #[cfg(feature = "decimal")]
+            (ColumnType::Decimal, Scalar::Int(i)) => {
+                Ok(MemValue::Decimal(crate::decimal::DbDecimal::new(i, 0)))
+            }
+            #[cfg(feature = "decimal")]
+            (ColumnType::Decimal, Scalar::Text(s)) => s
+                .parse()
+                .map(MemValue::Decimal)
+                .map_err(|e| parse_error(s, self.descriptor(), Some(Box::new(e)))),
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            (ColumnType::DateTime, Scalar::Text(s)) => s
+                .parse()
+                .map(MemValue::DateTime)
+                .map_err(|e| parse_error(s, self.descriptor(), Some(Box::new(e)))),
+            _ => Err(type_error(value_type, self.descriptor())),
+        }
+    }
+}
+
+impl DbvFactory for ParameterType {
+    type DBV = MemValue;
+
+    fn serialize_bool(&self, value: bool) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Bool(value), "bool")
+    }
+    fn serialize_i8(&self, value: i8) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value.into()), "i8")
+    }
+    fn serialize_i16(&self, value: i16) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value.into()), "i16")
+    }
+    fn serialize_i32(&self, value: i32) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value.into()), "i32")
+    }
+    fn serialize_i64(&self, value: i64) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value.into()), "i64")
+    }
+    fn serialize_i128(&self, value: i128) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value), "i128")
+    }
+    fn serialize_u8(&self, value: u8) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value.into()), "u8")
+    }
+    fn serialize_u16(&self, value: u16) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value.into()), "u16")
+    }
+    fn serialize_u32(&self, value: u32) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value.into()), "u32")
+    }
+    fn serialize_u64(&self, value: u64) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Int(value.into()), "u64")
+    }
+    fn serialize_u128(&self, value: u128) -> Result<MemValue, SerializationError> {
+        match i128::try_from(value) {
+            Ok(i) => self.scalar(Scalar::Int(i), "u128"),
+            Err(_) => Err(SerializationError::Range("u128", self.descriptor())),
+        }
+    }
+    fn serialize_f32(&self, value: f32) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Float(value.into()), "f32")
+    }
+    fn serialize_f64(&self, value: f64) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Float(value), "f64")
+    }
+    fn serialize_char(&self, value: char) -> Result<MemValue, SerializationError> {
+        match self.column_type {
+            ColumnType::String | ColumnType::Any => Ok(MemValue::String(value.to_string())),
+            _ => Err(type_error("char", self.descriptor())),
+        }
+    }
+    fn serialize_str(&self, value: &str) -> Result<MemValue, SerializationError> {
+        self.scalar(Scalar::Text(value.to_string()), "str")
+    }
+    fn serialize_bytes(&self, value: &[u8]) -> Result<MemValue, SerializationError> {
+        match self.column_type {
+            ColumnType::Bytes | ColumnType::Any => Ok(MemValue::Bytes(value.to_vec())),
+            _ => Err(type_error("bytes", self.descriptor())),
+        }
+    }
+    #[cfg(feature = "decimal")]
+    fn serialize_decimal(
+        &self,
+        value: crate::decimal::DbDecimal,
+    ) -> Result<MemValue, SerializationError> {
+        match self.column_type {
+            ColumnType::Decimal | ColumnType::Any => Ok(MemValue::Decimal(value)),
+            _ => self.serialize_str(&value.to_string()),
+        }
+    }
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn serialize_datetime(
+        &self,
+        value: crate::datetime::DbDateTime,
+    ) -> Result<MemValue, SerializationError> {
+        match self.column_type {
+            ColumnType::DateTime | ColumnType::Any => Ok(MemValue::DateTime(value)),
+            _ => self.serialize_str(&value.to_string()),
+        }
+    }
+    #[cfg(feature = "uuid")]
+    fn serialize_uuid(&self, value: ::uuid::Uuid) -> Result<MemValue, SerializationError> {
+        match self.column_type {
+            ColumnType::Bytes => Ok(MemValue::Bytes(value.as_bytes().to_vec())),
+            _ => self.serialize_str(&value.hyphenated().to_string()),
+        }
+    }
+    fn serialize_map(
+        &self,
+        entries: Vec<(String, Option<String>)>,
+    ) -> Result<MemValue, SerializationError> {
+        match self.column_type {
+            ColumnType::Map | ColumnType::Any => Ok(MemValue::Map(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, v.map_or(MemValue::Null, MemValue::String)))
+                    .collect(),
+            )),
+            _ => Err(type_error("map", self.descriptor())),
+        }
+    }
+    fn serialize_none(&self) -> Result<MemValue, SerializationError> {
+        if self.nullable {
+            Ok(MemValue::Null)
+        } else {
+            Err(type_error("none", self.descriptor()))
+        }
+    }
+    fn descriptor(&self) -> String {
+        if self.nullable {
+            format!("Nullable{:?}", self.column_type)
+        } else {
+            format!("{:?}", self.column_type)
+        }
+    }
+}
+
+impl DbvFactory for &ParameterType {
+    type DBV = MemValue;
+
+    fn serialize_bool(&self, value: bool) -> Result<MemValue, SerializationError> {
+        (**self).serialize_bool(value)
+    }
+    fn serialize_i8(&self, value: i8) -> Result<MemValue, SerializationError> {
+        (**self).serialize_i8(value)
+    }
+    fn serialize_i16(&self, value: i16) -> Result<MemValue, SerializationError> {
+        (**self).serialize_i16(value)
+    }
+    fn serialize_i32(&self, value: i32) -> Result<MemValue, SerializationError> {
+        (**self).serialize_i32(value)
+    }
+    fn serialize_i64(&self, value: i64) -> Result<MemValue, SerializationError> {
+        (**self).serialize_i64(value)
+    }
+    fn serialize_i128(&self, value: i128) -> Result<MemValue, SerializationError> {
+        (**self).serialize_i128(value)
+    }
+    fn serialize_u8(&self, value: u8) -> Result<MemValue, SerializationError> {
+        (**self).serialize_u8(value)
+    }
+    fn serialize_u16(&self, value: u16) -> Result<MemValue, SerializationError> {
+        (**self).serialize_u16(value)
+    }
+    fn serialize_u32(&self, value: u32) -> Result<MemValue, SerializationError> {
+        (**self).serialize_u32(value)
+    }
+    fn serialize_u64(&self, value: u64) -> Result<MemValue, SerializationError> {
+        (**self).serialize_u64(value)
+    }
+    fn serialize_u128(&self, value: u128) -> Result<MemValue, SerializationError> {
+        (**self).serialize_u128(value)
+    }
+    fn serialize_f32(&self, value: f32) -> Result<MemValue, SerializationError> {
+        (**self).serialize_f32(value)
+    }
+    fn serialize_f64(&self, value: f64) -> Result<MemValue, SerializationError> {
+        (**self).serialize_f64(value)
+    }
+    fn serialize_char(&self, value: char) -> Result<MemValue, SerializationError> {
+        (**self).serialize_char(value)
+    }
+    fn serialize_str(&self, value: &str) -> Result<MemValue, SerializationError> {
+        (**self).serialize_str(value)
+    }
+    fn serialize_bytes(&self, value: &[u8]) -> Result<MemValue, SerializationError> {
+        (**self).serialize_bytes(value)
+    }
+    #[cfg(feature = "decimal")]
+    fn serialize_decimal(
+        &self,
+        value: crate::decimal::DbDecimal,
+    ) -> Result<MemValue, SerializationError> {
+        (**self).serialize_decimal(value)
+    }
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn serialize_datetime(
+        &self,
+        value: crate::datetime::DbDateTime,
+    ) -> Result<MemValue, SerializationError> {
+        (**self).serialize_datetime(value)
+    }
+    #[cfg(feature = "uuid")]
+    fn serialize_uuid(&self, value: ::uuid::Uuid) -> Result<MemValue, SerializationError> {
+        (**self).serialize_uuid(value)
+    }
+    fn serialize_map(
+        &self,
+        entries: Vec<(String, Option<String>)>,
+    ) -> Result<MemValue, SerializationError> {
+        (**self).serialize_map(entries)
+    }
+    fn serialize_none(&self) -> Result<MemValue, SerializationError> {
+        (**self).serialize_none()
+    }
+    fn descriptor(&self) -> String {
+        (**self).descriptor()
+    }
+}
@@ -0,0 +1,183 @@
+use crate::de::{
+    DeserializableResultSet, DeserializableRow, DeserializationError, DeserializationResult,
+};
+use crate::memory::MemValue;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// A result set whose rows are held in memory.
+#[derive(Clone, Debug)]
+pub struct ResultSet {
+    columns: Arc<[String]>,
+    rows: VecDeque<Vec<MemValue>>,
+}
+
+impl ResultSet {
+    /// Creates an empty result set with the given column names.
+    #[must_use]
+    pub fn new(columns: &[&str]) -> Self {
+        ResultSet {
+            columns: columns.iter().map(ToString::to_string).collect(),
+            rows: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn from_parts(columns: Vec<String>, rows: VecDeque<Vec<MemValue>>) -> Self {
+        ResultSet {
+            columns: columns.into(),
+            rows,
+        }
+    }
+
+    /// Appends a row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values differs from the number of columns.
+    pub fn push(&mut self, values: Vec<MemValue>) {
+        assert_eq!(
+            self.columns.len(),
+            values.len(),
+            "the row must have a value for each column"
+        );
+        self.rows.push_back(values);
+    }
+
+    /// Appends a row, builder style.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values differs from the number of columns.
+    #[must_use]
+    pub fn with_row(mut self, values: Vec<MemValue>) -> Self {
+        self.push(values);
+        self
+    }
+
+    /// Returns the names of the columns.
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Returns the number of rows that were not yet consumed.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if all rows were consumed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Converts the result set into a rust value.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError` if the rows cannot be converted into the target type.
+    pub fn try_into<'de, T>(self) -> DeserializationResult<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        DeserializableResultSet::try_into(self)
+    }
+}
+
+impl DeserializableResultSet for ResultSet {
+    type Error = DeserializationError;
+    type Row = Row;
+
+    fn has_multiple_rows(&mut self) -> Result<bool, DeserializationError> {
+        Ok(self.rows.len() > 1)
+    }
+
+    fn next(&mut self) -> Result<Option<Row>, DeserializationError> {
+        Ok(self.rows.pop_front().map(|values| Row {
+            columns: Arc::clone(&self.columns),
+            values: values.into_iter(),
+        }))
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.columns.len()
+    }
+
+    fn field_name(&self, i: usize) -> Option<&str> {
+        self.columns.get(i).map(String::as_str)
+    }
+}
+
+impl Iterator for ResultSet {
+    type Item = Row;
+    fn next(&mut self) -> Option<Row> {
+        DeserializableResultSet::next(self).unwrap_or_default()
+    }
+}
+
+/// A row of a [`ResultSet`].
+#[derive(Clone, Debug)]
+pub struct Row {
+    columns: Arc<[String]>,
+    values: std::vec::IntoIter<MemValue>,
+}
+
+impl Row {
+    /// Creates a row with the given column names and values.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values differs from the number of columns.
+    #[must_use]
+    pub fn new(columns: &[&str], values: Vec<MemValue>) -> Self {
+        assert_eq!(
+            columns.len(),
+            values.len(),
+            "the row must have a value for each column"
+        );
+        Row {
+            columns: columns.iter().map(ToString::to_string).collect(),
+            values: values.into_iter(),
+        }
+    }
+
+    /// Returns the values that were not yet consumed.
+    #[must_use]
+    pub fn values(&self) -> &[MemValue] {
+        self.values.as_slice()
+    }
+
+    /// Converts the row into a rust value.
+    ///
+    /// # Errors
+    ///
+    /// `DeserializationError` if the row cannot be converted into the target type.
+    pub fn try_into<'de, T>(self) -> DeserializationResult<T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        DeserializableRow::try_into(self)
+    }
+}
+
+impl DeserializableRow for Row {
+    type Value = MemValue;
+    type Error = DeserializationError;
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn next(&mut self) -> Option<MemValue> {
+        self.values.next()
+    }
+
+    fn number_of_fields(&self) -> usize {
+        self.columns.len()
+    }
+
+    fn field_name(&self, field_idx: usize) -> Option<&str> {
+        self.columns.get(field_idx).map(String::as_str)
+    }
+}
@@ -0,0 +1,202 @@
+//! Hooks for monitoring the conversions, e.g. for collecting metrics.
+//!
+//! An [`Observer`] is registered with
+//! [`DeserializationConfig::with_observer()`](../de/struct.DeserializationConfig.html#method.with_observer)
+//! and [`SerializationConfig::with_observer()`](../ser/struct.SerializationConfig.html#method.with_observer),
+//! and is then called at well-defined points of the conversions:
+//!
+//! - when the deserialization of a row starts, and when it succeeded,
+//! - for each value of a successfully deserialized row (or a single value),
+//! - when a parameter value was produced,
+//! - when a deserialization or serialization fails.
+//!
+//! All methods have empty default implementations, so that observers only implement
+//! what they are interested in.
+//! [`Counters`] is a ready-made observer that counts in memory:
+//!
+//! ```rust
+//! use serde_db::de::DeserializationConfig;
+//! use serde_db::observer::Counters;
+//!
+//! use std::sync::Arc;
+//!
+//! let counters = Arc::new(Counters::new());
+//! let config = DeserializationConfig::default().with_observer(counters.clone());
+//! // ... result_set.try_into_with(&config)?
+//! println!("{} rows, {} bytes", counters.rows(), counters.bytes_deserialized());
+//! ```
+//!
+//! Observers are shared by all threads that use the configuration,
+//! and are called synchronously, so they should be cheap.
+use crate::de::DeserializationError;
+use crate::ser::SerializationError;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Is called by the deserializers and the serializer of this crate.
+///
+/// Observers are shared by the configurations, which can be used from several threads.
+pub trait Observer: std::fmt::Debug + Send + Sync {
+    /// The deserialization of a row with the given number of columns starts.
+    fn row_started(&self, _columns: usize) {}
+
+    /// A row was deserialized successfully;
+    /// it is preceded by `value_deserialized()` for each of its values.
+    ///
+    /// Rows whose deserialization fails are not reported.
+    fn row_deserialized(&self) {}
+
+    /// A value of a row (or a single value) was deserialized successfully.
+    ///
+    /// Only the columns of a row are values; the elements of arrays, maps or composite values
+    /// are part of their column.
+    /// `bytes` is the total length of the strings and byte arrays within the value,
+    /// if they were deserialized through serde, and `0` for all other values.
+    fn value_deserialized(&self, _bytes: usize) {}
+
+    /// A parameter value was produced.
+    ///
+    /// `bytes` is the length of strings and byte arrays, and `0` for all other values.
+    fn parameter_serialized(&self, _bytes: usize) {}
+
+    /// A call of `try_into_with()`, `try_into_rows_with()`, or `from_row_with()` failed.
+    fn deserialization_failed(&self, _error: &DeserializationError) {}
+
+    /// A call of `to_params_with()` failed.
+    fn serialization_failed(&self, _error: &SerializationError) {}
+}
+
+/// The observer that is used if none is configured; it does nothing.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopObserver;
+
+impl Observer for NoopObserver {}
+
+/// An observer that counts rows, values, bytes, and errors in memory.
+///
+/// The counters accumulate over all conversions that use the observer;
+/// figures for a single query can be obtained by calling [`reset()`](#method.reset) before it.
+#[derive(Debug, Default)]
+pub struct Counters {
+    rows: AtomicU64,
+    values: AtomicU64,
+    bytes_deserialized: AtomicU64,
+    parameters: AtomicU64,
+    bytes_serialized: AtomicU64,
+    errors: Mutex<BTreeMap<&'static str, u64>>,
+}
+
+impl Counters {
+    /// Creates a set of counters, all zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        Counters {
+            rows: AtomicU64::new(0),
+            values: AtomicU64::new(0),
+            bytes_deserialized: AtomicU64::new(0),
+            parameters: AtomicU64::new(0),
+            bytes_serialized: AtomicU64::new(0),
+            errors: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the number of successfully deserialized rows.
+    #[must_use]
+    pub fn rows(&self) -> u64 {
+        self.rows.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of values of successfully deserialized rows, and of single values.
+    #[must_use]
+    pub fn values(&self) -> u64 {
+        self.values.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes of deserialized strings and byte arrays.
+    #[must_use]
+    pub fn bytes_deserialized(&self) -> u64 {
+        self.bytes_deserialized.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of serialized parameters.
+    #[must_use]
+    pub fn parameters(&self) -> u64 {
+        self.parameters.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes of serialized strings and byte arrays.
+    #[must_use]
+    pub fn bytes_serialized(&self) -> u64 {
+        self.bytes_serialized.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of failed conversions, by kind of error
+    /// (the name of the variant of `DeserializationError` or `SerializationError`).
+    #[must_use]
+    pub fn errors(&self) -> BTreeMap<&'static str, u64> {
+        self.lock_errors().clone()
+    }
+
+    /// Sets all counters back to zero.
+    pub fn reset(&self) {
+        self.rows.store(0, Ordering::Relaxed);
+        self.values.store(0, Ordering::Relaxed);
+        self.bytes_deserialized.store(0, Ordering::Relaxed);
+        self.parameters.store(0, Ordering::Relaxed);
+        self.bytes_serialized.store(0, Ordering::Relaxed);
+        self.lock_errors().clear();
+    }
+
+    fn lock_errors(&self) -> std::sync::MutexGuard<'_, BTreeMap<&'static str, u64>> {
+        self.errors
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn count_error(&self, kind: &'static str) {
+        *self.lock_errors().entry(kind).or_default() += 1;
+    }
+}
+
+impl Observer for Counters {
+    fn row_deserialized(&self) {
+        self.rows.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn value_deserialized(&self, bytes: usize) {
+        self.values.fetch_add(1, Ordering::Relaxed);
+        self.bytes_deserialized
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn parameter_serialized(&self, bytes: usize) {
+        self.parameters.fetch_add(1, Ordering::Relaxed);
+        self.bytes_serialized
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn deserialization_failed(&self, error: &DeserializationError) {
+        self.count_error(match error {
+            DeserializationError::ConversionError(_) => "ConversionError",
+            DeserializationError::SerdeError(_) => "SerdeError",
+            DeserializationError::Usage(_) => "Usage",
+            DeserializationError::NotImplemented(_) => "NotImplemented",
+            DeserializationError::UnknownField(_) => "UnknownField",
+            DeserializationError::UnexpectedNull(_) => "UnexpectedNull",
+            DeserializationError::LimitExceeded(_) => "LimitExceeded",
+            DeserializationError::TrailingRows => "TrailingRows",
+            DeserializationError::TrailingCols => "TrailingCols",
+        });
+    }
+
+    fn serialization_failed(&self, error: &SerializationError) {
+        self.count_error(match error {
+            SerializationError::Serde(_) => "Serde",
+            SerializationError::Parse { .. } => "Parse",
+            SerializationError::StructuralMismatch(_) => "StructuralMismatch",
+            SerializationError::Type { .. } => "Type",
+            SerializationError::Range(..) => "Range",
+        });
+    }
+}
@@ -21,7 +21,8 @@
 //! In its implementation of [`DbvFactory`](trait.DbvFactory.html),
 //! the DB driver can decide to make their life even easier
 //! by converting flexibly between
-//! different number types (an example can be found in the tests of this crate)
+//! different number types (an example can be found in the tests of this crate);
+//! with the feature `derive`, `#[derive(DbvFactory)]` generates such an implementation.
 //!
 //! The implementation of `add_batch()` converts `input` into
 //! a Vec of the driver's database values that can subsequently be sent to the DB server:
@@ -34,11 +35,16 @@
 //! which implement [`DbvFactory`](trait.DbvFactory.html).
 
 mod dbv_factory;
+mod entry_serializer;
+mod serialization_config;
 mod serialization_error;
 mod serializer;
 
 pub use self::dbv_factory::DbvFactory;
+pub use self::serialization_config::SerializationConfig;
 pub use self::serialization_error::{parse_error, type_error, SerializationError};
+#[cfg(feature = "derive")]
+pub use serde_db_derive::DbvFactory;
 
 use self::serializer::Serializer;
 
@@ -61,7 +67,71 @@ where
 {
     #[cfg(feature = "trace")]
     log::trace!("serde_db::to_params()");
-    let mut serializer = Serializer::new(metadata);
-    value.serialize(&mut serializer)?;
-    Ok(serializer.into_inner())
+    to_params_with(value, metadata, &SerializationConfig::default())
+}
+
+/// Provided method that translates the input into a Vec of database values,
+/// using the given configuration.
+///
+/// # Errors
+///
+/// `SerializationError` if the value cannot be translated into a database value of
+/// the required type.
+pub fn to_params_with<T, DF>(
+    value: &T,
+    metadata: &mut dyn std::iter::Iterator<Item = DF>,
+    config: &SerializationConfig,
+) -> Result<Vec<DF::DBV>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    DF: DbvFactory,
+{
+    #[cfg(feature = "trace")]
+    log::trace!("serde_db::to_params_with()");
+    #[cfg(feature = "tracing")]
+    let operation = crate::instrument::Operation::start(tracing::debug_span!(
+        "serde_db::to_params",
+        params = tracing::field::Empty,
+        duration_us = tracing::field::Empty
+    ));
+    let mut serializer = Serializer::new(metadata, config.clone());
+    let result = value
+        .serialize(&mut serializer)
+        .map(|()| serializer.into_inner());
+    if let Err(ref e) = result {
+        config.observer().serialization_failed(e);
+    }
+    #[cfg(feature = "tracing")]
+    {
+        if let Ok(ref params) = result {
+            operation.record("params", params.len());
+        }
+        operation.finish(&result);
+    }
+    result
+}
+
+// The produced values, and the field name (or map key), if any, of each of them.
+#[cfg(feature = "memory")]
+pub(crate) type NamedParams<DBV> = (Vec<DBV>, Vec<Option<String>>);
+
+// Like `to_params_with()`, but also returns the field names (or map keys).
+#[cfg(feature = "memory")]
+pub(crate) fn to_named_params_with<T, DF>(
+    value: &T,
+    metadata: &mut dyn std::iter::Iterator<Item = DF>,
+    config: &SerializationConfig,
+) -> Result<NamedParams<DF::DBV>, SerializationError>
+where
+    T: Sized + serde::Serialize,
+    DF: DbvFactory,
+{
+    let mut serializer = Serializer::new(metadata, config.clone()).with_names();
+    let result = value
+        .serialize(&mut serializer)
+        .map(|()| serializer.into_inner_with_names());
+    if let Err(ref e) = result {
+        config.observer().serialization_failed(e);
+    }
+    result
 }
@@ -1,4 +1,4 @@
-use super::SerializationError;
+use super::{type_error, SerializationError};
 
 /// A factory for database objects.
 ///
@@ -47,6 +47,21 @@ pub trait DbvFactory: Sized {
     /// `SerializationError` if the required database parameter cannot be constructed
     /// from the given value.
     fn serialize_i64(&self, value: i64) -> Result<Self::DBV, SerializationError>;
+    /// Serialize an i128.
+    ///
+    /// The default implementation delegates to `serialize_i64()` if the value fits into an i64,
+    /// and returns `SerializationError::Range` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// `SerializationError` if the required database parameter cannot be constructed
+    /// from the given value.
+    fn serialize_i128(&self, value: i128) -> Result<Self::DBV, SerializationError> {
+        match i64::try_from(value) {
+            Ok(value) => self.serialize_i64(value),
+            Err(_) => Err(SerializationError::Range("i128", self.descriptor())),
+        }
+    }
     /// Serialize an u8.
     ///
     /// # Errors
@@ -75,6 +90,21 @@ pub trait DbvFactory: Sized {
     /// `SerializationError` if the required database parameter cannot be constructed
     /// from the given value.
     fn serialize_u64(&self, value: u64) -> Result<Self::DBV, SerializationError>;
+    /// Serialize an u128.
+    ///
+    /// The default implementation delegates to `serialize_u64()` if the value fits into an u64,
+    /// and returns `SerializationError::Range` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// `SerializationError` if the required database parameter cannot be constructed
+    /// from the given value.
+    fn serialize_u128(&self, value: u128) -> Result<Self::DBV, SerializationError> {
+        match u64::try_from(value) {
+            Ok(value) => self.serialize_u64(value),
+            Err(_) => Err(SerializationError::Range("u128", self.descriptor())),
+        }
+    }
     /// Serialize an f32.
     ///
     /// # Errors
@@ -110,6 +140,85 @@ pub trait DbvFactory: Sized {
     /// `SerializationError` if the required database parameter cannot be constructed
     /// from the given value.
     fn serialize_bytes(&self, value: &[u8]) -> Result<Self::DBV, SerializationError>;
+    /// Serialize a decimal value.
+    ///
+    /// The default implementation delegates to `serialize_str()`.
+    /// Drivers with a binary representation of decimal values should override this method.
+    ///
+    /// # Errors
+    ///
+    /// `SerializationError` if the required database parameter cannot be constructed
+    /// from the given value.
+    #[cfg(feature = "decimal")]
+    fn serialize_decimal(
+        &self,
+        value: crate::decimal::DbDecimal,
+    ) -> Result<Self::DBV, SerializationError> {
+        self.serialize_str(&value.to_string())
+    }
+    /// Serialize a date or time value.
+    ///
+    /// The default implementation delegates to `serialize_str()`, using the ISO 8601 formats.
+    /// Drivers with a binary representation of date and time values should override
+    /// this method.
+    ///
+    /// # Errors
+    ///
+    /// `SerializationError` if the required database parameter cannot be constructed
+    /// from the given value.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn serialize_datetime(
+        &self,
+        value: crate::datetime::DbDateTime,
+    ) -> Result<Self::DBV, SerializationError> {
+        self.serialize_str(&value.to_string())
+    }
+    /// Serialize a UUID.
+    ///
+    /// The default implementation delegates to `serialize_str()`, using the hyphenated format.
+    /// Drivers should override this method if some parameter types require the
+    /// 16-byte binary form (see `Uuid::as_bytes()`).
+    ///
+    /// # Errors
+    ///
+    /// `SerializationError` if the required database parameter cannot be constructed
+    /// from the given value.
+    #[cfg(feature = "uuid")]
+    fn serialize_uuid(&self, value: ::uuid::Uuid) -> Result<Self::DBV, SerializationError> {
+        self.serialize_str(&value.hyphenated().to_string())
+    }
+    /// Serialize a value as JSON text.
+    ///
+    /// The default implementation delegates to `serialize_str()`.
+    /// Drivers with a dedicated JSON parameter type can override this method.
+    ///
+    /// # Errors
+    ///
+    /// `SerializationError` if the required database parameter cannot be constructed
+    /// from the given value.
+    #[cfg(feature = "json")]
+    fn serialize_json(&self, value: &str) -> Result<Self::DBV, SerializationError> {
+        self.serialize_str(value)
+    }
+    /// Serialize a map as a single parameter (e.g. for `hstore` or `MAP` types).
+    ///
+    /// Maps that are fields of a struct or tuple are handed over with their keys and values
+    /// in textual representation. A map that is the parameter row itself, or that is an
+    /// element of a sequence of rows (like `Vec<HashMap<..>>`), is still flattened into
+    /// one parameter per value.
+    /// The default implementation returns a type error.
+    ///
+    /// # Errors
+    ///
+    /// `SerializationError` if the required database parameter cannot be constructed
+    /// from the given value.
+    #[allow(unused_variables)]
+    fn serialize_map(
+        &self,
+        entries: Vec<(String, Option<String>)>,
+    ) -> Result<Self::DBV, SerializationError> {
+        Err(type_error("map", self.descriptor()))
+    }
     /// Serialize a none.
     ///
     /// # Errors
@@ -0,0 +1,153 @@
+use super::{type_error, SerializationError};
+use serde::ser::Impossible;
+
+type SerializationResult<T> = Result<T, SerializationError>;
+
+// Serializes the keys and values of maps that are bound as a single parameter
+// (see `DbvFactory::serialize_map()`) into their textual representation.
+pub(crate) struct EntrySerializer;
+
+fn entry_type_error(value_type: &'static str) -> SerializationError {
+    type_error(value_type, "map entry".to_string())
+}
+
+impl serde::Serializer for EntrySerializer {
+    type Ok = Option<String>;
+    type Error = SerializationError;
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+    fn serialize_bool(self, value: bool) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_i8(self, value: i8) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_i16(self, value: i16) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_i32(self, value: i32) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_i64(self, value: i64) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_i128(self, value: i128) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_u8(self, value: u8) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_u16(self, value: u16) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_u32(self, value: u32) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_u64(self, value: u64) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_u128(self, value: u128) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_f32(self, value: f32) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_f64(self, value: f64) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_char(self, value: char) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_str(self, value: &str) -> SerializationResult<Self::Ok> {
+        Ok(Some(value.to_string()))
+    }
+    fn serialize_bytes(self, _value: &[u8]) -> SerializationResult<Self::Ok> {
+        Err(entry_type_error("bytes"))
+    }
+    fn serialize_none(self) -> SerializationResult<Self::Ok> {
+        Ok(None)
+    }
+    fn serialize_some<T: ?Sized + serde::Serialize>(
+        self,
+        value: &T,
+    ) -> SerializationResult<Self::Ok> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> SerializationResult<Self::Ok> {
+        Ok(None)
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> SerializationResult<Self::Ok> {
+        Err(entry_type_error("unit_struct"))
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> SerializationResult<Self::Ok> {
+        Ok(Some(variant.to_string()))
+    }
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> SerializationResult<Self::Ok> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> SerializationResult<Self::Ok> {
+        Err(entry_type_error("newtype_variant"))
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> SerializationResult<Self::SerializeSeq> {
+        Err(entry_type_error("seq"))
+    }
+    fn serialize_tuple(self, _len: usize) -> SerializationResult<Self::SerializeTuple> {
+        Err(entry_type_error("tuple"))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> SerializationResult<Self::SerializeTupleStruct> {
+        Err(entry_type_error("tuple_struct"))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> SerializationResult<Self::SerializeTupleVariant> {
+        Err(entry_type_error("tuple_variant"))
+    }
+    fn serialize_map(self, _len: Option<usize>) -> SerializationResult<Self::SerializeMap> {
+        Err(entry_type_error("map"))
+    }
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> SerializationResult<Self::SerializeStruct> {
+        Err(entry_type_error("struct"))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> SerializationResult<Self::SerializeStructVariant> {
+        Err(entry_type_error("struct_variant"))
+    }
+}
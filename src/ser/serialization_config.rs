@@ -0,0 +1,53 @@
+use crate::observer::{NoopObserver, Observer};
+use std::sync::Arc;
+
+/// Options that control the serialization.
+///
+/// Use [`Default`] to get the standard behavior, and the `with_*` methods to deviate from it:
+///
+/// ```rust
+/// use serde_db::ser::SerializationConfig;
+///
+/// let config = SerializationConfig::default().with_empty_string_as_null(true);
+/// ```
+///
+/// The options are applied with [`to_params_with()`](fn.to_params_with.html).
+#[derive(Clone, Debug, Default)]
+pub struct SerializationConfig {
+    empty_string_as_null: bool,
+    observer: Option<Arc<dyn Observer>>,
+}
+
+impl SerializationConfig {
+    /// Sets whether empty strings are bound as NULL values,
+    /// like databases do that do not distinguish between the two (e.g. Oracle).
+    ///
+    /// Default is `false`.
+    #[must_use]
+    pub fn with_empty_string_as_null(mut self, empty_string_as_null: bool) -> Self {
+        self.empty_string_as_null = empty_string_as_null;
+        self
+    }
+
+    /// Returns whether empty strings are bound as NULL values.
+    #[must_use]
+    pub fn empty_string_as_null(&self) -> bool {
+        self.empty_string_as_null
+    }
+
+    /// Sets an observer that is informed about the produced parameter values,
+    /// e.g. [`Counters`](../observer/struct.Counters.html).
+    ///
+    /// Default is no observer.
+    #[must_use]
+    pub fn with_observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Returns the observer, or a [`NoopObserver`](../observer/struct.NoopObserver.html).
+    #[must_use]
+    pub fn observer(&self) -> &dyn Observer {
+        self.observer.as_deref().unwrap_or(&NoopObserver)
+    }
+}
@@ -1,6 +1,8 @@
 use super::dbv_factory::DbvFactory;
+use super::entry_serializer::EntrySerializer;
 use super::type_error;
-use super::SerializationError;
+use super::{SerializationConfig, SerializationError};
+use crate::transport;
 #[cfg(feature = "trace")]
 use log::{log_enabled, trace};
 
@@ -11,15 +13,41 @@ type SerializationResult<T> = Result<T, SerializationError>;
 pub struct Serializer<'m, DF: 'm + DbvFactory> {
     output: Vec<DF::DBV>,
     metadata: &'m mut dyn std::iter::Iterator<Item = DF>,
+    config: SerializationConfig,
+    // the name of the transport whose bytes (or, for a `Value`, whose string form)
+    // are to be serialized next
+    pending_transport: Option<&'static str>,
+    // true while the innermost open compound is a struct or tuple;
+    // maps that are fields of structs or tuples are bound as single parameters,
+    // while top-level maps and maps in sequences are flattened, as before
+    in_struct: bool,
+    // the names of the produced values (field names or map keys), if requested
+    names: Option<Vec<Option<String>>>,
+    pending_name: Option<String>,
 }
 
 impl<'m, DF: DbvFactory> Serializer<'m, DF> {
-    pub fn new(metadata: &'m mut dyn std::iter::Iterator<Item = DF>) -> Self {
+    pub fn new(
+        metadata: &'m mut dyn std::iter::Iterator<Item = DF>,
+        config: SerializationConfig,
+    ) -> Self {
         Serializer {
             output: Vec::<DF::DBV>::new(),
             metadata,
+            config,
+            pending_transport: None,
+            in_struct: false,
+            names: None,
+            pending_name: None,
         }
     }
+
+    // Makes the serializer record the field name (or map key) of each produced value.
+    #[cfg(feature = "memory")]
+    pub fn with_names(mut self) -> Self {
+        self.names = Some(Vec::new());
+        self
+    }
     fn get_current_field(&mut self) -> SerializationResult<DF> {
         match self.metadata.next() {
             Some(df) => Ok(df),
@@ -30,12 +58,35 @@ impl<'m, DF: DbvFactory> Serializer<'m, DF> {
     }
 
     fn push(&mut self, value: DF::DBV) {
+        self.push_sized(value, 0);
+    }
+
+    // For strings and byte arrays, whose length is reported to the observer.
+    fn push_sized(&mut self, value: DF::DBV, bytes: usize) {
         self.output.push(value);
+        if let Some(ref mut names) = self.names {
+            names.push(self.pending_name.take());
+        }
+        self.config.observer().parameter_serialized(bytes);
     }
 
     pub fn into_inner(self) -> Vec<DF::DBV> {
         self.output
     }
+
+    #[cfg(feature = "memory")]
+    pub fn into_inner_with_names(self) -> (Vec<DF::DBV>, Vec<Option<String>>) {
+        (self.output, self.names.unwrap_or_default())
+    }
+
+    fn compound<'a>(&'a mut self, in_struct: bool) -> Compound<'a, 'm, DF> {
+        let outer_in_struct = std::mem::replace(&mut self.in_struct, in_struct);
+        Compound {
+            ser: self,
+            entries: None,
+            outer_in_struct,
+        }
+    }
 }
 
 impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF> {
@@ -75,7 +126,7 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
 
     fn serialize_i32(self, value: i32) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
-        trace!("Serializer::serialize_i32() for {}", value);
+        trace!("Serializer::serialize_i32() for {value}");
         let val = self.get_current_field()?.serialize_i32(value)?;
         self.push(val);
         Ok(())
@@ -89,6 +140,14 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
         Ok(())
     }
 
+    fn serialize_i128(self, value: i128) -> SerializationResult<Self::Ok> {
+        #[cfg(feature = "trace")]
+        trace!("Serializer::serialize_i128()");
+        let val = self.get_current_field()?.serialize_i128(value)?;
+        self.push(val);
+        Ok(())
+    }
+
     fn serialize_u8(self, value: u8) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_u8()");
@@ -121,6 +180,14 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
         Ok(())
     }
 
+    fn serialize_u128(self, value: u128) -> SerializationResult<Self::Ok> {
+        #[cfg(feature = "trace")]
+        trace!("Serializer::serialize_u128()");
+        let val = self.get_current_field()?.serialize_u128(value)?;
+        self.push(val);
+        Ok(())
+    }
+
     fn serialize_f32(self, value: f32) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_f32()");
@@ -150,7 +217,7 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
         if log_enabled!(log::Level::Debug) {
             let l = value.len();
             if l < 100 {
-                trace!("Serializer::serialize_str() with {}", value);
+                trace!("Serializer::serialize_str() with {value}");
             } else {
                 trace!(
                     "Serializer::serialize_str() with {}..{}",
@@ -159,16 +226,70 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
                 );
             }
         }
+        match self.pending_transport.take() {
+            #[cfg(feature = "decimal")]
+            Some(transport::DECIMAL) => {
+                let dbd: crate::decimal::DbDecimal = value.parse().map_err(|_| {
+                    SerializationError::StructuralMismatch("invalid decimal transport")
+                })?;
+                let val = self.get_current_field()?.serialize_decimal(dbd)?;
+                self.push(val);
+                return Ok(());
+            }
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            Some(transport::DATETIME) => {
+                let dbdt: crate::datetime::DbDateTime = value.parse().map_err(|_| {
+                    SerializationError::StructuralMismatch("invalid date or time transport")
+                })?;
+                let val = self.get_current_field()?.serialize_datetime(dbdt)?;
+                self.push(val);
+                return Ok(());
+            }
+            _ => {}
+        }
+        if value.is_empty() && self.config.empty_string_as_null() {
+            let val = self.get_current_field()?.serialize_none()?;
+            self.push(val);
+            return Ok(());
+        }
         let val = self.get_current_field()?.serialize_str(value)?;
-        self.push(val);
+        self.push_sized(val, value.len());
         Ok(())
     }
 
     fn serialize_bytes(self, value: &[u8]) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_bytes()");
-        let val = self.get_current_field()?.serialize_bytes(value)?;
-        self.push(val);
+        match self.pending_transport.take() {
+            #[cfg(feature = "decimal")]
+            Some(transport::DECIMAL) => {
+                let dbd = crate::decimal::DbDecimal::from_bytes(value).ok_or(
+                    SerializationError::StructuralMismatch("invalid decimal transport"),
+                )?;
+                let val = self.get_current_field()?.serialize_decimal(dbd)?;
+                self.push(val);
+            }
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            Some(transport::DATETIME) => {
+                let (dbdt, _) = crate::datetime::DbDateTime::from_bytes(value).ok_or(
+                    SerializationError::StructuralMismatch("invalid date or time transport"),
+                )?;
+                let val = self.get_current_field()?.serialize_datetime(dbdt)?;
+                self.push(val);
+            }
+            #[cfg(feature = "uuid")]
+            Some(transport::UUID) => {
+                let uuid = ::uuid::Uuid::from_slice(value).map_err(|_| {
+                    SerializationError::StructuralMismatch("invalid UUID transport")
+                })?;
+                let val = self.get_current_field()?.serialize_uuid(uuid)?;
+                self.push(val);
+            }
+            _ => {
+                let val = self.get_current_field()?.serialize_bytes(value)?;
+                self.push_sized(val, value.len());
+            }
+        }
         Ok(())
     }
 
@@ -203,6 +324,7 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
         ))
     }
 
+    #[allow(clippy::used_underscore_binding)]
     fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(
         self,
         _name: &'static str,
@@ -210,6 +332,15 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     ) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_newtype_struct()");
+        self.pending_transport = transport::is_transport(_name).then_some(_name);
+        #[cfg(feature = "json")]
+        if _name == transport::JSON {
+            let json = serde_json::to_string(value)
+                .map_err(|e| SerializationError::Serde(e.to_string()))?;
+            let val = self.get_current_field()?.serialize_json(&json)?;
+            self.push(val);
+            return Ok(());
+        }
         value.serialize(self)
     }
 
@@ -245,13 +376,13 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     fn serialize_seq(self, _len: Option<usize>) -> SerializationResult<Self::SerializeSeq> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_seq()");
-        Ok(Compound { ser: self })
+        Ok(self.compound(false))
     }
 
     fn serialize_tuple(self, _len: usize) -> SerializationResult<Self::SerializeTuple> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_tuple()");
-        Ok(Compound { ser: self })
+        Ok(self.compound(true))
     }
 
     fn serialize_tuple_struct(
@@ -261,7 +392,7 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     ) -> SerializationResult<Self::SerializeTupleStruct> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_tuple_struct()");
-        Ok(Compound { ser: self })
+        Ok(self.compound(true))
     }
 
     fn serialize_tuple_variant(
@@ -273,13 +404,21 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     ) -> SerializationResult<Self::SerializeTupleVariant> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_tuple_variant()");
-        Ok(Compound { ser: self })
+        Ok(self.compound(true))
     }
 
     fn serialize_map(self, _len: Option<usize>) -> SerializationResult<Self::SerializeMap> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_map()");
-        Ok(Compound { ser: self })
+        if self.in_struct {
+            Ok(Compound {
+                ser: self,
+                entries: Some(Vec::new()),
+                outer_in_struct: true,
+            })
+        } else {
+            Ok(self.compound(false))
+        }
     }
 
     fn serialize_struct(
@@ -289,7 +428,7 @@ impl<'a, 'm: 'a, DF: DbvFactory> serde::Serializer for &'a mut Serializer<'m, DF
     ) -> SerializationResult<Self::SerializeStruct> {
         #[cfg(feature = "trace")]
         trace!("Serializer::serialize_struct()");
-        Ok(Compound { ser: self })
+        Ok(self.compound(true))
     }
 
     fn serialize_struct_variant(
@@ -321,9 +460,13 @@ fn tail(count: usize, s: &str) -> String {
 #[allow(missing_debug_implementations)]
 pub struct Compound<'a, 'm: 'a, DF: 'm + DbvFactory> {
     ser: &'a mut Serializer<'m, DF>,
+    // the entries of a map that is bound as a single parameter
+    entries: Option<Vec<(String, Option<String>)>>,
+    // restored on end()
+    outer_in_struct: bool,
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeSeq for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeSeq for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -340,11 +483,12 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeSeq for Compound<'a, 'm,
     fn end(self) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeSeq::end()");
+        self.ser.in_struct = self.outer_in_struct;
         Ok(())
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTuple for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeTuple for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -360,11 +504,12 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTuple for Compound<'a, 'm
     fn end(self) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeTuple::end()");
+        self.ser.in_struct = self.outer_in_struct;
         Ok(())
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTupleStruct for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeTupleStruct for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -384,7 +529,7 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTupleStruct for Compound<
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTupleVariant for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeTupleVariant for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -400,17 +545,28 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeTupleVariant for Compound
     fn end(self) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeTupleVariant::end()");
+        self.ser.in_struct = self.outer_in_struct;
         Ok(())
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeMap for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeMap for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
-    fn serialize_key<T: ?Sized + serde::Serialize>(&mut self, _key: &T) -> SerializationResult<()> {
+    fn serialize_key<T: ?Sized + serde::Serialize>(&mut self, key: &T) -> SerializationResult<()> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeMap::serialize_key()");
+        if let Some(ref mut entries) = self.entries {
+            let key =
+                key.serialize(EntrySerializer)?
+                    .ok_or(SerializationError::StructuralMismatch(
+                        "map keys must not be None",
+                    ))?;
+            entries.push((key, None));
+        } else if self.ser.names.is_some() {
+            self.ser.pending_name = key.serialize(EntrySerializer)?;
+        }
         Ok(())
     }
 
@@ -420,6 +576,15 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeMap for Compound<'a, 'm,
     ) -> SerializationResult<()> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeMap::serialize_value()");
+        if let Some(ref mut entries) = self.entries {
+            let entry = entries
+                .last_mut()
+                .ok_or(SerializationError::StructuralMismatch(
+                    "map value without key",
+                ))?;
+            entry.1 = value.serialize(EntrySerializer)?;
+            return Ok(());
+        }
         let t: &mut Serializer<DF> = self.ser;
         value.serialize(t)
     }
@@ -427,11 +592,16 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeMap for Compound<'a, 'm,
     fn end(self) -> SerializationResult<Self::Ok> {
         #[cfg(feature = "trace")]
         trace!("Compound: SerializeMap::end()");
+        self.ser.in_struct = self.outer_in_struct;
+        if let Some(entries) = self.entries {
+            let val = self.ser.get_current_field()?.serialize_map(entries)?;
+            self.ser.push(val);
+        }
         Ok(())
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeStruct for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeStruct for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -453,7 +623,7 @@ impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeStruct for Compound<'a, '
     }
 }
 
-impl<'a, 'm, DF: 'm + DbvFactory> serde::ser::SerializeStructVariant for Compound<'a, 'm, DF> {
+impl<'m, DF: 'm + DbvFactory> serde::ser::SerializeStructVariant for Compound<'_, 'm, DF> {
     type Ok = ();
     type Error = SerializationError;
 
@@ -0,0 +1,547 @@
+//! A conformance test kit for implementors of database drivers.
+//!
+//! The functions of this module check that a driver's implementations of
+//! [`DbValue`], [`DeserializableRow`], [`DeserializableResultSet`], and [`DbvFactory`]
+//! behave like `serde_db` expects: NULL handling, range errors, string fallbacks,
+//! rows that shrink when their values are consumed, and the conversion of result sets
+//! of all shapes (m×n, m×1, 1×n, 1×1, empty).
+//!
+//! The driver provides its value constructors by implementing [`Driver`], and calls
+//! [`check_all`] (or the individual checks) from its own tests.
+//! All checks panic with a descriptive message if the driver does not conform.
+//!
+//! With the feature `proptest`, the module [`round_trip`] adds property-based checks
+//! that values survive the way from parameters to rows unchanged.
+//!
+//! ```rust,ignore
+//! struct MyDriver;
+//!
+//! impl serde_db::testkit::Driver for MyDriver {
+//!     // ...
+//! }
+//!
+//! #[test]
+//! fn conformance() {
+//!     serde_db::testkit::check_all(&MyDriver);
+//! }
+//! ```
+
+#[cfg(feature = "proptest")]
+pub mod round_trip;
+
+use crate::de::{DbValue, DeserializableResultSet, DeserializableRow, DeserializationError};
+use crate::ser::{to_params, DbvFactory, SerializationError};
+use serde::Deserialize;
+use std::fmt::Debug;
+
+/// Provides the value constructors of a database driver to the checks of this module.
+pub trait Driver {
+    /// The driver's database value.
+    type Value: DbValue + Debug;
+    /// The driver's row.
+    type Row: DeserializableRow<Value = Self::Value, Error = Self::Error>;
+    /// The driver's result set.
+    type ResultSet: DeserializableResultSet<Row = Self::Row, Error = Self::Error>;
+    /// The error type of the driver's rows and result sets.
+    type Error: From<DeserializationError> + Debug;
+    /// The driver's parameter descriptor.
+    type Factory: DbvFactory<DBV = Self::Value> + Clone;
+
+    /// Returns the NULL value.
+    fn null(&self) -> Self::Value;
+
+    /// Returns an integer value.
+    fn int(&self, value: i16) -> Self::Value;
+
+    /// Returns a string value.
+    fn string(&self, value: &str) -> Self::Value;
+
+    /// Returns a floating point value, if the driver supports them.
+    ///
+    /// The default implementation returns `None`, which skips the respective checks.
+    fn float(&self, _value: f64) -> Option<Self::Value> {
+        None
+    }
+
+    /// Returns a result set with the given column names and rows.
+    fn result_set(&self, columns: &[&'static str], rows: Vec<Vec<Self::Value>>) -> Self::ResultSet;
+
+    /// Returns the descriptor of an integer parameter, if the driver supports parameters.
+    ///
+    /// The default implementation returns `None`, which skips the respective checks.
+    fn int_parameter(&self, _nullable: bool) -> Option<Self::Factory> {
+        None
+    }
+
+    /// Returns the descriptor of a string parameter, if the driver supports parameters.
+    ///
+    /// The default implementation returns `None`, which skips the respective checks.
+    fn string_parameter(&self, _nullable: bool) -> Option<Self::Factory> {
+        None
+    }
+}
+
+/// Runs all checks of this module.
+///
+/// # Panics
+///
+/// If the driver does not conform.
+pub fn check_all<D: Driver>(driver: &D) {
+    check_values(driver);
+    check_rows(driver);
+    check_result_sets(driver);
+    check_parameters(driver);
+}
+
+/// Checks the conversion of individual values:
+/// NULL handling, integer conversions with range checks, and string fallbacks.
+///
+/// # Panics
+///
+/// If the driver does not conform.
+pub fn check_values<D: Driver>(driver: &D) {
+    // NULL
+    assert!(driver.null().is_null(), "testkit: null() is not NULL");
+    assert!(!driver.int(0).is_null(), "testkit: int(0) is NULL");
+    assert_eq!(
+        None,
+        ok(
+            DbValue::try_into::<Option<i32>>(driver.null()),
+            "NULL into Option<i32>"
+        )
+    );
+    assert_eq!(
+        None,
+        ok(
+            DbValue::try_into::<Option<String>>(driver.null()),
+            "NULL into Option<String>"
+        )
+    );
+    err(DbValue::try_into::<i32>(driver.null()), "NULL into i32");
+    err(
+        DbValue::try_into::<String>(driver.null()),
+        "NULL into String",
+    );
+
+    // integers
+    let int = |i| driver.int(i);
+    assert_eq!(42, ok(DbValue::try_into::<i8>(int(42)), "42 into i8"));
+    assert_eq!(42, ok(DbValue::try_into::<i16>(int(42)), "42 into i16"));
+    assert_eq!(42, ok(DbValue::try_into::<i32>(int(42)), "42 into i32"));
+    assert_eq!(42, ok(DbValue::try_into::<i64>(int(42)), "42 into i64"));
+    assert_eq!(42, ok(DbValue::try_into::<i128>(int(42)), "42 into i128"));
+    assert_eq!(42, ok(DbValue::try_into::<u8>(int(42)), "42 into u8"));
+    assert_eq!(42, ok(DbValue::try_into::<u16>(int(42)), "42 into u16"));
+    assert_eq!(42, ok(DbValue::try_into::<u32>(int(42)), "42 into u32"));
+    assert_eq!(42, ok(DbValue::try_into::<u64>(int(42)), "42 into u64"));
+    assert_eq!(42, ok(DbValue::try_into::<u128>(int(42)), "42 into u128"));
+    assert_eq!(
+        Some(42),
+        ok(
+            DbValue::try_into::<Option<u8>>(int(42)),
+            "42 into Option<u8>"
+        )
+    );
+    assert_eq!(
+        i16::MIN,
+        ok(DbValue::try_into::<i16>(int(i16::MIN)), "i16::MIN into i16")
+    );
+    let f: f64 = ok(DbValue::try_into(int(42)), "42 into f64");
+    assert!(
+        (f - 42.0).abs() < f64::EPSILON,
+        "testkit: 42 into f64 gave {f}"
+    );
+
+    // range errors
+    err(DbValue::try_into::<u8>(int(300)), "300 into u8");
+    err(DbValue::try_into::<i8>(int(300)), "300 into i8");
+    err(DbValue::try_into::<i8>(int(-129)), "-129 into i8");
+    err(DbValue::try_into::<u8>(int(-1)), "-1 into u8");
+    err(DbValue::try_into::<u16>(int(-1)), "-1 into u16");
+    err(DbValue::try_into::<u32>(int(-1)), "-1 into u32");
+    err(DbValue::try_into::<u64>(int(-1)), "-1 into u64");
+    err(DbValue::try_into::<u128>(int(-1)), "-1 into u128");
+
+    // strings and string fallbacks
+    assert_eq!(
+        "hello",
+        ok(
+            DbValue::try_into::<String>(driver.string("hello")),
+            "string into String"
+        )
+    );
+    assert_eq!(
+        "42",
+        ok(DbValue::try_into::<String>(int(42)), "42 into String")
+    );
+    assert_eq!(
+        17,
+        ok(
+            DbValue::try_into::<i32>(driver.string("17")),
+            "\"17\" into i32"
+        )
+    );
+    err(
+        DbValue::try_into::<i32>(driver.string("x")),
+        "\"x\" into i32",
+    );
+    err(
+        DbValue::try_into::<u8>(driver.string("300")),
+        "\"300\" into u8",
+    );
+
+    // floats
+    if let Some(value) = driver.float(0.5) {
+        let f: f64 = ok(DbValue::try_into(value), "0.5 into f64");
+        assert!(
+            (f - 0.5).abs() < f64::EPSILON,
+            "testkit: 0.5 into f64 gave {f}"
+        );
+    }
+    if let Some(value) = driver.float(0.5) {
+        err(DbValue::try_into::<i32>(value), "0.5 into i32");
+    }
+    if let Some(value) = driver.float(17.0) {
+        assert_eq!(17, ok(DbValue::try_into::<u8>(value), "17.0 into u8"));
+    }
+}
+
+/// Checks rows: their metadata, that `len()` decreases with each call of `next()`,
+/// and their conversion into tuples and structs.
+///
+/// # Panics
+///
+/// If the driver does not conform.
+pub fn check_rows<D: Driver>(driver: &D) {
+    let mut row = single_row(driver, row_values(driver, 1));
+    assert_eq!(3, row.number_of_fields(), "testkit: number_of_fields()");
+    assert_eq!(Some("id"), row.field_name(0), "testkit: field_name(0)");
+    assert_eq!(Some("note"), row.field_name(2), "testkit: field_name(2)");
+    assert_eq!(None, row.field_name(3), "testkit: field_name(3)");
+    for remaining in (0..3).rev() {
+        assert!(row.next().is_some(), "testkit: row ends too early");
+        assert_eq!(
+            remaining,
+            row.len(),
+            "testkit: len() does not decrease with next()"
+        );
+    }
+    assert!(row.next().is_none(), "testkit: row does not end");
+
+    let row = single_row(driver, row_values(driver, 1));
+    let t: (i64, String, Option<String>) = ok(DeserializableRow::try_into(row), "row into tuple");
+    assert_eq!((1, "name 1".to_string(), None), t);
+
+    let row = single_row(driver, row_values(driver, 2));
+    let record: Record = ok(DeserializableRow::try_into(row), "row into struct");
+    assert_eq!(Record::new(2), record);
+
+    let row = single_row(driver, row_values(driver, 3));
+    err(
+        DeserializableRow::try_into::<(i64, String, Option<String>, i64)>(row),
+        "row with 3 values into 4-tuple",
+    );
+}
+
+/// Checks the conversion of result sets of all shapes.
+///
+/// # Panics
+///
+/// If the driver does not conform.
+pub fn check_result_sets<D: Driver>(driver: &D) {
+    check_matrix(driver);
+    check_single_column(driver);
+    check_single_row(driver);
+    check_single_value(driver);
+    check_optional(driver);
+}
+
+// Result sets of the shape m×n.
+fn check_matrix<D: Driver>(driver: &D) {
+    let rs = driver.result_set(&COLUMNS, (1..=5).map(|i| row_values(driver, i)).collect());
+    assert_eq!(3, rs.number_of_fields(), "testkit: number_of_fields()");
+    assert_eq!(Some("name"), rs.field_name(1), "testkit: field_name(1)");
+    let records: Vec<Record> = ok(
+        DeserializableResultSet::try_into(rs),
+        "m×n result set into Vec<struct>",
+    );
+    assert_eq!((1..=5).map(Record::new).collect::<Vec<_>>(), records);
+
+    let rs = driver.result_set(&COLUMNS, (1..=5).map(|i| row_values(driver, i)).collect());
+    let tuples: Vec<(u8, String, Option<String>)> = ok(
+        DeserializableResultSet::try_into(rs),
+        "m×n result set into Vec<tuple>",
+    );
+    assert_eq!(5, tuples.len());
+    assert_eq!(Some("note 4".to_string()), tuples[3].2);
+
+    let mut rs = driver.result_set(&COLUMNS, (1..=5).map(|i| row_values(driver, i)).collect());
+    assert!(
+        ok(rs.has_multiple_rows(), "has_multiple_rows()"),
+        "testkit: has_multiple_rows() is false for five rows"
+    );
+    err(
+        DeserializableResultSet::try_into::<Record>(rs),
+        "m×n result set into struct",
+    );
+
+    let mut rs = driver.result_set(&COLUMNS, (1..=5).map(|i| row_values(driver, i)).collect());
+    let mut sum = 0;
+    while let Some(row) = ok(DeserializableResultSet::next(&mut rs), "next()") {
+        let record: Record = ok(DeserializableRow::try_into(row), "streamed row into struct");
+        sum += record.id;
+    }
+    assert_eq!(15, sum, "testkit: streaming rows");
+}
+
+// Result sets of the shape m×1.
+fn check_single_column<D: Driver>(driver: &D) {
+    let rs = driver.result_set(&["id"], (1..=5).map(|i| vec![driver.int(i)]).collect());
+    let ids: Vec<u32> = ok(
+        DeserializableResultSet::try_into(rs),
+        "m×1 result set into Vec<field>",
+    );
+    assert_eq!(vec![1, 2, 3, 4, 5], ids);
+    let rs = driver.result_set(&["id"], (1..=5).map(|i| vec![driver.int(i)]).collect());
+    let ids: Vec<Id> = ok(
+        DeserializableResultSet::try_into(rs),
+        "m×1 result set into Vec<struct>",
+    );
+    assert_eq!(Id { id: 5 }, ids[4]);
+    let rs = driver.result_set(&["id"], (1..=5).map(|i| vec![driver.int(i)]).collect());
+    err(
+        DeserializableResultSet::try_into::<u32>(rs),
+        "m×1 result set into field",
+    );
+}
+
+// Result sets of the shape 1×n.
+fn check_single_row<D: Driver>(driver: &D) {
+    let rs = driver.result_set(&COLUMNS, vec![row_values(driver, 7)]);
+    let record: Record = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×n result set into struct",
+    );
+    assert_eq!(Record::new(7), record);
+    let rs = driver.result_set(&COLUMNS, vec![row_values(driver, 7)]);
+    let records: Vec<Record> = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×n result set into Vec<struct>",
+    );
+    assert_eq!(vec![Record::new(7)], records);
+    let rs = driver.result_set(&COLUMNS, vec![row_values(driver, 7)]);
+    let t: (i16, String, Option<String>) = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×n result set into tuple",
+    );
+    assert_eq!(7, t.0);
+    let rs = driver.result_set(&COLUMNS, vec![row_values(driver, 7)]);
+    err(
+        DeserializableResultSet::try_into::<i16>(rs),
+        "1×n result set into field",
+    );
+}
+
+// Result sets of the shape 1×1.
+fn check_single_value<D: Driver>(driver: &D) {
+    let rs = driver.result_set(&["id"], vec![vec![driver.int(9)]]);
+    let id: i64 = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×1 result set into field",
+    );
+    assert_eq!(9, id);
+    let rs = driver.result_set(&["id"], vec![vec![driver.int(9)]]);
+    let id: Id = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×1 result set into struct",
+    );
+    assert_eq!(Id { id: 9 }, id);
+    let rs = driver.result_set(&["id"], vec![vec![driver.int(9)]]);
+    let ids: Vec<u8> = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×1 result set into Vec<field>",
+    );
+    assert_eq!(vec![9], ids);
+}
+
+// Result sets with zero or one rows, and with NULL values.
+fn check_optional<D: Driver>(driver: &D) {
+    let rs = driver.result_set(&["id"], Vec::new());
+    let id: Option<i32> = ok(
+        DeserializableResultSet::try_into(rs),
+        "empty result set into Option",
+    );
+    assert_eq!(None, id);
+    let rs = driver.result_set(&COLUMNS, Vec::new());
+    let records: Vec<Record> = ok(
+        DeserializableResultSet::try_into(rs),
+        "empty result set into Vec",
+    );
+    assert!(records.is_empty(), "testkit: empty result set into Vec");
+    let rs = driver.result_set(&COLUMNS, vec![row_values(driver, 3)]);
+    let record: Option<Record> = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×n result set into Option",
+    );
+    assert_eq!(Some(Record::new(3)), record);
+    let rs = driver.result_set(&["id"], vec![vec![driver.null()]]);
+    let id: Option<i32> = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×1 NULL into Option",
+    );
+    assert_eq!(None, id);
+    let rs = driver.result_set(&["id"], vec![vec![driver.null()]]);
+    let ids: Vec<Option<i32>> = ok(
+        DeserializableResultSet::try_into(rs),
+        "1×1 NULL into Vec<Option>",
+    );
+    assert_eq!(vec![None], ids);
+    let rs = driver.result_set(&["id"], vec![vec![driver.null()]]);
+    err(
+        DeserializableResultSet::try_into::<i32>(rs),
+        "1×1 NULL into field",
+    );
+}
+
+/// Checks the conversion of rust values into parameters: integer parameters must accept
+/// the signed integer types and reject values out of their range, non-nullable parameters
+/// must reject `None`, and nullable parameters must produce NULL for it.
+///
+/// The checks are skipped for parameter descriptors that the driver does not provide.
+///
+/// # Panics
+///
+/// If the driver does not conform.
+pub fn check_parameters<D: Driver>(driver: &D) {
+    if let Some(df) = driver.int_parameter(false) {
+        macro_rules! check_int {
+            ($($t:ty),+) => {$(
+                let params = ok(
+                    param(&df, &(7 as $t)),
+                    concat!("7_", stringify!($t), " into int parameter"),
+                );
+                let value = single_param(params);
+                assert_eq!(7, ok(DbValue::try_into::<i64>(value), "int parameter into i64"));
+            )+};
+        }
+        check_int!(i8, i16, i32, i64, i128);
+        err(param(&df, &i128::MAX), "i128::MAX into int parameter");
+        err(
+            param(&df, &None::<i32>),
+            "None into non-nullable int parameter",
+        );
+        err(param(&df, &(1, 2)), "two values into one parameter");
+    }
+    if let Some(df) = driver.int_parameter(true) {
+        let params = ok(param(&df, &None::<i32>), "None into nullable int parameter");
+        assert!(
+            single_param(params).is_null(),
+            "testkit: None into nullable int parameter is not NULL"
+        );
+        let params = ok(
+            param(&df, &Some(5_i32)),
+            "Some(5) into nullable int parameter",
+        );
+        assert_eq!(
+            5,
+            ok(
+                DbValue::try_into::<u8>(single_param(params)),
+                "parameter into u8"
+            )
+        );
+    }
+    if let Some(df) = driver.string_parameter(false) {
+        let params = ok(param(&df, &"abc"), "str into string parameter");
+        assert_eq!(
+            "abc",
+            ok(
+                DbValue::try_into::<String>(single_param(params)),
+                "parameter into String"
+            )
+        );
+        err(
+            param(&df, &None::<&str>),
+            "None into non-nullable string parameter",
+        );
+    }
+    if let Some(df) = driver.string_parameter(true) {
+        let params = ok(
+            param(&df, &None::<&str>),
+            "None into nullable string parameter",
+        );
+        assert!(
+            single_param(params).is_null(),
+            "testkit: None into nullable string parameter is not NULL"
+        );
+    }
+}
+
+const COLUMNS: [&str; 3] = ["id", "name", "note"];
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Record {
+    id: u16,
+    name: String,
+    note: Option<String>,
+}
+
+impl Record {
+    fn new(i: i16) -> Self {
+        Record {
+            id: i.unsigned_abs(),
+            name: format!("name {i}"),
+            note: (i % 2 == 0).then(|| format!("note {i}")),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Id {
+    id: u64,
+}
+
+// The values of a row with COLUMNS that corresponds to Record::new(i).
+fn row_values<D: Driver>(driver: &D, i: i16) -> Vec<D::Value> {
+    vec![
+        driver.int(i),
+        driver.string(&format!("name {i}")),
+        if i % 2 == 0 {
+            driver.string(&format!("note {i}"))
+        } else {
+            driver.null()
+        },
+    ]
+}
+
+fn single_row<D: Driver>(driver: &D, values: Vec<D::Value>) -> D::Row {
+    let mut rs = driver.result_set(&COLUMNS, vec![values]);
+    ok(DeserializableResultSet::next(&mut rs), "next()").expect("testkit: result set is empty")
+}
+
+fn param<DF, T>(df: &DF, value: &T) -> Result<Vec<DF::DBV>, SerializationError>
+where
+    DF: DbvFactory + Clone,
+    T: serde::Serialize,
+{
+    to_params(value, &mut std::iter::once(df.clone()))
+}
+
+fn single_param<V: Debug>(mut params: Vec<V>) -> V {
+    assert_eq!(1, params.len(), "testkit: expected a single parameter");
+    params.remove(0)
+}
+
+#[track_caller]
+fn ok<T, E: Debug>(result: Result<T, E>, what: &str) -> T {
+    match result {
+        Ok(t) => t,
+        Err(e) => panic!("testkit: {what} failed with {e:?}"),
+    }
+}
+
+#[track_caller]
+fn err<T: Debug, E>(result: Result<T, E>, what: &str) {
+    if let Ok(t) = result {
+        panic!("testkit: {what} succeeded unexpectedly with {t:?}");
+    }
+}
@@ -0,0 +1,155 @@
+//! Property-based round-trip checks between [`to_params`] and
+//! [`DeserializableRow::try_into`], based on [`proptest`].
+//!
+//! The driver implements [`Bridge`], which provides the parameter descriptors for the
+//! tested type and turns the produced parameters into a row, as the database would
+//! return them. [`check_round_trip`] then generates arbitrary values of the tested type,
+//! pushes each of them through the bridge, and panics with a minimized counterexample
+//! if a value does not come back unchanged.
+//!
+//! ```rust,ignore
+//! use serde_db::testkit::round_trip::{check_round_trip, Bridge};
+//!
+//! struct MyBridge;
+//!
+//! impl Bridge for MyBridge {
+//!     type Factory = MyParameterType;
+//!     type Row = MyRow;
+//!
+//!     fn parameter_types(&self) -> Vec<MyParameterType> {
+//!         vec![MyParameterType::BigInt, MyParameterType::NullableNVarchar]
+//!     }
+//!
+//!     fn row(&self, params: Vec<MyValue>) -> MyRow {
+//!         MyRow::new(&["id", "name"], params)
+//!     }
+//! }
+//!
+//! #[test]
+//! fn round_trip() {
+//!     check_round_trip::<(i64, Option<String>), _>(&MyBridge);
+//! }
+//! ```
+
+use crate::de::DeserializableRow;
+use crate::ser::{to_params, DbvFactory};
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::Strategy;
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+/// Connects a driver's parameters with its rows.
+pub trait Bridge {
+    /// The driver's parameter descriptor.
+    type Factory: DbvFactory;
+    /// The driver's row.
+    type Row: DeserializableRow;
+
+    /// Returns the descriptors of the parameters that the tested type is serialized into.
+    fn parameter_types(&self) -> Vec<Self::Factory>;
+
+    /// Turns the serialized parameters into a row, as the database would return them.
+    fn row(&self, params: Vec<<Self::Factory as DbvFactory>::DBV>) -> Self::Row;
+}
+
+/// A value that does not survive the round trip, and the reason.
+#[derive(Debug)]
+pub struct Counterexample<T> {
+    /// The (minimized) value.
+    pub value: T,
+    /// Describes what went wrong.
+    pub reason: String,
+}
+
+impl<T: Debug> std::fmt::Display for Counterexample<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "round trip failed for {:?}: {}", self.value, self.reason)
+    }
+}
+
+/// Pushes a single value through the bridge and compares the result with the value.
+///
+/// Can be used within `proptest!` blocks.
+///
+/// # Errors
+///
+/// `TestCaseError::Fail` if the value cannot be serialized, cannot be deserialized,
+/// or comes back changed.
+pub fn round_trip<T, B>(bridge: &B, value: &T) -> Result<(), TestCaseError>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+    B: Bridge,
+    <B::Row as DeserializableRow>::Error: Debug,
+{
+    let params = to_params(value, &mut bridge.parameter_types().into_iter())
+        .map_err(|e| TestCaseError::fail(format!("serialization failed: {e}")))?;
+    let row = bridge.row(params);
+    let returned: T = DeserializableRow::try_into(row)
+        .map_err(|e| TestCaseError::fail(format!("deserialization failed: {e:?}")))?;
+    if returned == *value {
+        Ok(())
+    } else {
+        Err(TestCaseError::fail(format!("came back as {returned:?}")))
+    }
+}
+
+/// Runs the round trip with values from the given strategy, and returns
+/// the minimized counterexample if one is found.
+///
+/// # Panics
+///
+/// If the test runner aborts, e.g. because the strategy rejects too many values.
+pub fn find_counterexample<T, B, S>(
+    bridge: &B,
+    strategy: &S,
+    config: Config,
+) -> Option<Counterexample<T>>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+    B: Bridge,
+    <B::Row as DeserializableRow>::Error: Debug,
+    S: Strategy<Value = T>,
+{
+    let mut runner = TestRunner::new(config);
+    match runner.run(strategy, |value| round_trip(bridge, &value)) {
+        Ok(()) => None,
+        Err(TestError::Fail(reason, value)) => Some(Counterexample {
+            value,
+            reason: reason.message().to_string(),
+        }),
+        Err(TestError::Abort(reason)) => panic!("testkit: round trip checks aborted: {reason}"),
+    }
+}
+
+/// Runs the round trip with arbitrary values of type `T`.
+///
+/// # Panics
+///
+/// With the minimized counterexample, if a value does not survive the round trip.
+pub fn check_round_trip<T, B>(bridge: &B)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug + Arbitrary,
+    B: Bridge,
+    <B::Row as DeserializableRow>::Error: Debug,
+{
+    check_round_trip_with(bridge, &any::<T>(), Config::default());
+}
+
+/// Runs the round trip with values from the given strategy, using the given configuration.
+///
+/// # Panics
+///
+/// With the minimized counterexample, if a value does not survive the round trip.
+pub fn check_round_trip_with<T, B, S>(bridge: &B, strategy: &S, config: Config)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+    B: Bridge,
+    <B::Row as DeserializableRow>::Error: Debug,
+    S: Strategy<Value = T>,
+{
+    if let Some(counterexample) = find_counterexample(bridge, strategy, config) {
+        panic!("testkit: {counterexample}");
+    }
+}
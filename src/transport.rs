@@ -0,0 +1,50 @@
+// serde's data model has no types for decimals, dates and times, UUIDs, JSON text,
+// large objects, or dynamic database values.
+// This crate thus wraps such values into newtype structs with the reserved names below.
+// The serializer and the deserializers of this crate recognize these names and exchange
+// the values with the driver through the respective methods of `DbvFactory` and `DbValue`;
+// other serializers and deserializers see ordinary newtype structs.
+
+#[cfg(any(
+    feature = "chrono",
+    feature = "decimal",
+    feature = "time",
+    feature = "uuid"
+))]
+pub(crate) mod binary;
+
+pub(crate) const LOB: &str = "$serde_db::de::Lob";
+pub(crate) const VALUE: &str = "$serde_db::Value";
+#[cfg(feature = "decimal")]
+pub(crate) const DECIMAL: &str = "$serde_db::decimal::DbDecimal";
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) const DATETIME: &str = "$serde_db::datetime::DbDateTime";
+#[cfg(feature = "uuid")]
+pub(crate) const UUID: &str = "$serde_db::uuid::Uuid";
+#[cfg(feature = "json")]
+pub(crate) const JSON: &str = "$serde_db::Json";
+
+// Returns true if the name is reserved for one of the transports;
+// the values of such newtype structs are resolved by the FieldDeserializer.
+pub(crate) fn is_transport(name: &str) -> bool {
+    if name == LOB || name == VALUE {
+        return true;
+    }
+    #[cfg(feature = "decimal")]
+    if name == DECIMAL {
+        return true;
+    }
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    if name == DATETIME {
+        return true;
+    }
+    #[cfg(feature = "uuid")]
+    if name == UUID {
+        return true;
+    }
+    #[cfg(feature = "json")]
+    if name == JSON {
+        return true;
+    }
+    false
+}
@@ -0,0 +1,129 @@
+// The transports that carry a value as a byte array within the newtype struct.
+//
+// The modules `decimal`, `datetime`, and `uuid` implement `Codec` for their types, and
+// delegate their `serialize` and `deserialize` functions (for `#[serde(with = "...")]`)
+// to the generic functions below.
+use crate::de::ConversionError;
+use serde::ser::Error as _;
+use std::marker::PhantomData;
+
+pub(crate) trait Codec {
+    // The transported type.
+    type Value;
+    // The reserved name of the newtype struct.
+    const NAME: &'static str;
+    // Describes the transported type in error messages.
+    const EXPECTING: &'static str;
+
+    fn encode(value: &Self::Value) -> Result<Vec<u8>, ConversionError>;
+
+    // Returns None if the bytes are not a valid transport.
+    fn decode(bytes: &[u8]) -> Option<Result<Self::Value, ConversionError>>;
+}
+
+pub(crate) fn serialize<C: Codec, S: serde::Serializer>(
+    value: &C::Value,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let bytes = C::encode(value).map_err(S::Error::custom)?;
+    serialize_bytes(C::NAME, &bytes, serializer)
+}
+
+// Writes the newtype struct with the given name around the bytes.
+pub(crate) fn serialize_bytes<S: serde::Serializer>(
+    name: &'static str,
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_newtype_struct(name, &Bytes(bytes))
+}
+
+pub(crate) fn serialize_option<C: Codec, S: serde::Serializer>(
+    value: Option<&C::Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serialize::<C, S>(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub(crate) fn deserialize<'de, C: Codec, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<C::Value, D::Error> {
+    deserializer.deserialize_newtype_struct(C::NAME, CodecVisitor::<C>(PhantomData))
+}
+
+pub(crate) fn deserialize_option<'de, C: Codec, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<C::Value>, D::Error> {
+    deserializer.deserialize_option(OptionVisitor::<C>(PhantomData))
+}
+
+// Serializes a byte slice with serialize_bytes() (rather than as a sequence of u8).
+struct Bytes<'a>(&'a [u8]);
+
+impl serde::Serialize for Bytes<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+struct CodecVisitor<C>(PhantomData<C>);
+
+impl<'de, C: Codec> serde::de::Visitor<'de> for CodecVisitor<C> {
+    type Value = C::Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(C::EXPECTING)
+    }
+
+    // the FieldDeserializer provides the bytes directly
+    fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        C::decode(value)
+            .ok_or_else(|| E::invalid_length(value.len(), &self))?
+            .map_err(E::custom)
+    }
+
+    // other deserializers see the newtype struct that serialize() wrote
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(self)
+    }
+
+    // for formats that represent byte arrays as sequences of u8
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+struct OptionVisitor<C>(PhantomData<C>);
+
+impl<'de, C: Codec> serde::de::Visitor<'de> for OptionVisitor<C> {
+    type Value = Option<C::Value>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "{} or NULL", C::EXPECTING)
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize::<C, D>(deserializer).map(Some)
+    }
+}
@@ -0,0 +1,112 @@
+//! Support for transporting UUIDs between the database driver and `uuid::Uuid`,
+//! independent of whether the database stores them as 16-byte binaries or as strings.
+//!
+//! `Uuid`'s own serde implementation chooses between the textual and the binary form
+//! based on `is_human_readable()`, which does not reflect the column type of the database.
+//! With this module, the driver decides instead:
+//! it overrides
+//! [`DbValue::try_into_uuid()`](../de/trait.DbValue.html#method.try_into_uuid) for binary
+//! UUID columns, and
+//! [`DbvFactory::serialize_uuid()`](../ser/trait.DbvFactory.html#method.serialize_uuid)
+//! to produce the form that the parameter type requires.
+//! The default implementations of these methods fall back to the driver's string conversions,
+//! using the hyphenated format.
+//!
+//! `Uuid` fields use the transport by naming this module
+//! (or its submodule [`option`](option/index.html) for `Option<Uuid>`) in a serde attribute:
+//!
+//! ```rust,ignore
+//! use serde::{Deserialize, Serialize};
+//! use uuid::Uuid;
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Booking {
+//!     #[serde(with = "serde_db::uuid")]
+//!     id: Uuid,
+//!     #[serde(with = "serde_db::uuid::option")]
+//!     parent_id: Option<Uuid>,
+//! }
+//! ```
+//!
+//! Other serde formats see such fields as newtype structs around the 16 bytes of the UUID,
+//! also if they are human-readable.
+
+use crate::de::ConversionError;
+use crate::transport::{self, binary};
+use ::uuid::Uuid;
+
+/// Serializes a `Uuid`, which the driver receives in
+/// [`DbvFactory::serialize_uuid()`](../ser/trait.DbvFactory.html#method.serialize_uuid).
+///
+/// # Errors
+///
+/// `S::Error` if serialization fails.
+pub fn serialize<S>(value: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    binary::serialize::<Uuids, S>(value, serializer)
+}
+
+/// Deserializes a `Uuid` from a binary or a textual column.
+///
+/// # Errors
+///
+/// `D::Error` if the value is not a valid UUID.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    binary::deserialize::<Uuids, D>(deserializer)
+}
+
+/// Variants of [`serialize`] and [`deserialize`]
+/// for nullable UUID columns.
+pub mod option {
+    use super::Uuids;
+    use crate::transport::binary;
+    use ::uuid::Uuid;
+
+    /// Serializes `None` as NULL, and other values like [`uuid::serialize`](super::serialize).
+    ///
+    /// # Errors
+    ///
+    /// `S::Error` if serialization fails.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S>(value: &Option<Uuid>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        binary::serialize_option::<Uuids, S>(value.as_ref(), serializer)
+    }
+
+    /// Deserializes NULL as `None`, and other values like
+    /// [`uuid::deserialize`](super::deserialize).
+    ///
+    /// # Errors
+    ///
+    /// `D::Error` if the value is not a valid UUID.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Uuid>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        binary::deserialize_option::<Uuids, D>(deserializer)
+    }
+}
+
+// Transports a Uuid as its 16 bytes.
+struct Uuids;
+
+impl binary::Codec for Uuids {
+    type Value = Uuid;
+    const NAME: &'static str = transport::UUID;
+    const EXPECTING: &'static str = "a UUID";
+
+    fn encode(value: &Uuid) -> Result<Vec<u8>, ConversionError> {
+        Ok(value.as_bytes().to_vec())
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Result<Uuid, ConversionError>> {
+        Uuid::from_slice(bytes).ok().map(Ok)
+    }
+}
@@ -0,0 +1,372 @@
+//! A dynamic representation of database values, for tools that do not know the structure
+//! of the result sets at compile time (admin consoles, exports, ...).
+//!
+//! Every [`DbValue`](../de/trait.DbValue.html) can be deserialized into a [`Value`];
+//! the variant is chosen by probing the database value with
+//! [`DbValue::is_null()`](../de/trait.DbValue.html#tymethod.is_null) and
+//! [`DbValue::value_kind()`](../de/trait.DbValue.html#method.value_kind).
+//! Rows can be deserialized into maps, so that result sets of any shape can be consumed:
+//!
+//! ```rust,ignore
+//! use indexmap::IndexMap;
+//! use serde_db::Value;
+//!
+//! let rows: Vec<IndexMap<String, Value>> = result_set.try_into()?;
+//! ```
+//!
+//! Alternatively, [`DeserializableResultSet::try_into_table()`](
+//! ../de/trait.DeserializableResultSet.html#method.try_into_table) produces a [`Table`],
+//! which keeps the column names only once, and also works for result sets without rows.
+//!
+//! `Value`, `Row` and `Table` implement `serde::Serialize`, so they can be exported
+//! with any serde format; a `Value` can also be used as a parameter.
+//! Decimals and dates/times are serialized as strings, like `12.345` or
+//! `2024-10-13T23:59:01` (ISO 8601); as parameters, they are bound as decimals
+//! and dates/times nevertheless.
+use crate::transport;
+use std::sync::Arc;
+
+// Decimals and dates/times have no counterpart in serde's data model, so the FieldDeserializer
+// hands them to the `ValueVisitor` as a map with a single entry: the name of the transport,
+// and the bytes that the transport carries.
+#[cfg(any(feature = "chrono", feature = "decimal", feature = "time"))]
+pub(crate) fn visit_transport<'de, V, E>(
+    name: &'static str,
+    bytes: &[u8],
+    visitor: V,
+) -> Result<V::Value, E>
+where
+    V: serde::de::Visitor<'de>,
+    E: serde::de::Error,
+{
+    visitor.visit_map(serde::de::value::MapDeserializer::new(std::iter::once((
+        name,
+        serde::de::value::BytesDeserializer::new(bytes),
+    ))))
+}
+
+/// A database value of any type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// The NULL value.
+    Null,
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Int(i64),
+    /// A floating point value.
+    Float(f64),
+    /// A string value.
+    String(String),
+    /// A binary value.
+    Bytes(Vec<u8>),
+    /// A decimal value.
+    #[cfg(feature = "decimal")]
+    Decimal(crate::decimal::DbDecimal),
+    /// A date, time, or timestamp.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    DateTime(crate::datetime::DbDateTime),
+}
+
+impl Value {
+    /// Returns true if this is the NULL value.
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    /// Returns the kind of the value, or `None` for the NULL value.
+    #[must_use]
+    pub fn kind(&self) -> Option<ValueKind> {
+        match self {
+            Value::Null => None,
+            Value::Bool(_) => Some(ValueKind::Bool),
+            Value::Int(_) => Some(ValueKind::Int),
+            Value::Float(_) => Some(ValueKind::Float),
+            Value::String(_) => Some(ValueKind::String),
+            Value::Bytes(_) => Some(ValueKind::Bytes),
+            #[cfg(feature = "decimal")]
+            Value::Decimal(_) => Some(ValueKind::Decimal),
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            Value::DateTime(_) => Some(ValueKind::DateTime),
+        }
+    }
+}
+
+/// The kinds of non-NULL values, which correspond to the variants of [`Value`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    /// Converted with `DbValueInto<bool>`.
+    Bool,
+    /// Converted with `DbValueInto<i64>`.
+    Int,
+    /// Converted with `DbValueInto<f64>`.
+    Float,
+    /// Converted with `DbValueInto<String>`.
+    String,
+    /// Converted with `DbValueInto<Vec<u8>>`.
+    Bytes,
+    /// Converted with `DbValue::try_into_decimal()`.
+    #[cfg(feature = "decimal")]
+    Decimal,
+    /// Converted with `DbValue::try_into_datetime()`.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    DateTime,
+}
+
+impl<'de> serde::Deserialize<'de> for Value {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(transport::VALUE, ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a database value")
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    // other deserializers
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Value::Int(value))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        i64::try_from(value)
+            .map(Value::Int)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Value::Float(value))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Value::String(value.to_string()))
+    }
+
+    fn visit_string<E: serde::de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Value::String(value))
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(Value::Bytes(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Value::Bytes(value))
+    }
+
+    // decimals and dates/times, see visit_transport()
+    #[cfg(any(feature = "chrono", feature = "decimal", feature = "time"))]
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        use serde::de::Error;
+        let invalid = |_| A::Error::custom("invalid transport of a database value");
+        match map.next_key::<String>()?.as_deref() {
+            #[cfg(feature = "decimal")]
+            Some(transport::DECIMAL) => {
+                let bytes = map.next_value_seed(TransportBytes)?;
+                crate::decimal::DbDecimal::from_bytes(&bytes)
+                    .map(Value::Decimal)
+                    .ok_or_else(|| invalid(bytes))
+            }
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            Some(transport::DATETIME) => {
+                let bytes = map.next_value_seed(TransportBytes)?;
+                crate::datetime::DbDateTime::from_bytes(&bytes)
+                    .map(|(dt, _)| Value::DateTime(dt))
+                    .ok_or_else(|| invalid(bytes))
+            }
+            _ => Err(A::Error::invalid_type(serde::de::Unexpected::Map, &self)),
+        }
+    }
+}
+
+// Reads the bytes of a transport.
+#[cfg(any(feature = "chrono", feature = "decimal", feature = "time"))]
+struct TransportBytes;
+
+#[cfg(any(feature = "chrono", feature = "decimal", feature = "time"))]
+impl<'de> serde::de::DeserializeSeed<'de> for TransportBytes {
+    type Value = Vec<u8>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_bytes(self)
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "decimal", feature = "time"))]
+impl serde::de::Visitor<'_> for TransportBytes {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("the bytes of a transport")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(value.to_vec())
+    }
+}
+
+impl serde::Serialize for Value {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_none(),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Int(i) => serializer.serialize_i64(*i),
+            Value::Float(f) => serializer.serialize_f64(*f),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Bytes(b) => serializer.serialize_bytes(b),
+            #[cfg(feature = "decimal")]
+            Value::Decimal(d) => {
+                serializer.serialize_newtype_struct(transport::DECIMAL, &format_args!("{d}"))
+            }
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            Value::DateTime(dt) => {
+                serializer.serialize_newtype_struct(transport::DATETIME, &format_args!("{dt}"))
+            }
+        }
+    }
+}
+
+/// A row of [`Value`]s, with the names of the columns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Row {
+    columns: Arc<[String]>,
+    values: Vec<Value>,
+}
+
+impl Row {
+    pub(crate) fn new(columns: Arc<[String]>, values: Vec<Value>) -> Self {
+        Row { columns, values }
+    }
+
+    /// Returns the names of the columns.
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Returns the values, in the order of the columns.
+    #[must_use]
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// Returns the value of the column with the given name.
+    #[must_use]
+    pub fn get(&self, column: &str) -> Option<&Value> {
+        self.columns
+            .iter()
+            .position(|name| name == column)
+            .and_then(|idx| self.values.get(idx))
+    }
+
+    /// Returns the pairs of column name and value.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.columns
+            .iter()
+            .map(String::as_str)
+            .zip(self.values.iter())
+    }
+
+    /// Returns the values, in the order of the columns.
+    #[must_use]
+    pub fn into_values(self) -> Vec<Value> {
+        self.values
+    }
+}
+
+impl std::ops::Index<usize> for Row {
+    type Output = Value;
+    fn index(&self, idx: usize) -> &Value {
+        &self.values[idx]
+    }
+}
+
+impl serde::Serialize for Row {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// The rows of a result set, as [`Row`]s of [`Value`]s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    columns: Arc<[String]>,
+    rows: Vec<Row>,
+}
+
+impl Table {
+    pub(crate) fn new(columns: Arc<[String]>, rows: Vec<Row>) -> Self {
+        Table { columns, rows }
+    }
+
+    /// Returns the names of the columns.
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Returns the rows.
+    #[must_use]
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if the table has no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the rows.
+    #[must_use]
+    pub fn into_rows(self) -> Vec<Row> {
+        self.rows
+    }
+}
+
+impl serde::Serialize for Table {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.rows)
+    }
+}
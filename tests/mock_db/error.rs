@@ -4,15 +4,15 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
-    DESERIALIZATION(DeserializationError),
-    SERIALIZATION(SerializationError),
+    Deserialization(DeserializationError),
+    Serialization(SerializationError),
 }
 
 impl std::error::Error for Error {
     fn cause(&self) -> Option<&dyn std::error::Error> {
         match *self {
-            Error::DESERIALIZATION(ref e) => Some(e),
-            Error::SERIALIZATION(ref e) => Some(e),
+            Error::Deserialization(ref e) => Some(e),
+            Error::Serialization(ref e) => Some(e),
         }
     }
 }
@@ -20,8 +20,8 @@ impl std::error::Error for Error {
 impl std::fmt::Display for Error {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
-            Error::DESERIALIZATION(ref e) => write!(fmt, "{}", e),
-            Error::SERIALIZATION(ref e) => write!(fmt, "{}", e),
+            Error::Deserialization(ref e) => write!(fmt, "{}", e),
+            Error::Serialization(ref e) => write!(fmt, "{}", e),
         }
     }
 }
@@ -1,6 +1,5 @@
 use crate::mock_db;
 use chrono::{Datelike, NaiveDateTime, Timelike};
-use serde;
 use serde_db::de::DbValue;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -30,8 +29,19 @@ pub enum MValue {
     Double(f64),
     String(String),
     Timestamp(Timestamp),
+    Binary(Vec<u8>),
+    Array(Vec<MValue>),
+    Map(Vec<(String, MValue)>),
+    Composite(Vec<(String, MValue)>),
+    #[cfg(feature = "decimal")]
+    Decimal(serde_db::decimal::DbDecimal),
 }
 
+// A mock value whose implementations of DbValueInto are generated with
+// serde_db::impl_db_value_into!, while those of MValue are written by hand.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KitValue(pub MValue);
+
 impl MValue {
     pub fn new_null() -> MValue {
         MValue::Null
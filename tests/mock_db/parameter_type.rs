@@ -6,4 +6,12 @@ pub enum ParameterType {
     NullableString,
     Timestamp,
     NullableTimestamp,
+    Binary,
+    NullableBinary,
+    Map,
+    NullableMap,
+    #[cfg(feature = "decimal")]
+    Decimal,
+    #[cfg(feature = "decimal")]
+    NullableDecimal,
 }
@@ -1,5 +1,4 @@
-use serde;
-use serde_db::de::DeserializableResultSet;
+use serde_db::de::{DeserializableResultSet, FromRow};
 use std::rc::Rc;
 
 use crate::mock_db;
@@ -61,7 +60,13 @@ impl ResultSet {
         T: serde::Deserialize<'de>,
     {
         trace!("ResultSet::try_into()");
-        Ok(DeserializableResultSet::try_into(self)?)
+        DeserializableResultSet::try_into(self)
+    }
+
+    // Expose the serde-free conversion of rows
+    pub fn try_into_rows<T: FromRow>(self) -> mock_db::Result<Vec<T>> {
+        trace!("ResultSet::try_into_rows()");
+        DeserializableResultSet::try_into_rows(self)
     }
 }
 
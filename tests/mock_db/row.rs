@@ -1,4 +1,3 @@
-use serde;
 use serde_db::de::{DbValue, DeserializableRow, DeserializationError};
 use std::rc::Rc;
 
@@ -14,7 +13,7 @@ pub struct Row {
 impl Row {
     pub fn new(metadata: Rc<mock_db::FieldNames>, values: Vec<mock_db::MValue>) -> mock_db::Row {
         mock_db::Row {
-            metadata: metadata,
+            metadata,
             value_iter: values.into_iter(),
         }
     }
@@ -54,7 +53,7 @@ impl Row {
         T: serde::Deserialize<'de>,
     {
         trace!("Row::try_into()");
-        Ok(DeserializableRow::try_into(self)?)
+        DeserializableRow::try_into(self)
     }
 }
 
@@ -1,2 +1,3 @@
 mod de;
+mod kit;
 mod ser;
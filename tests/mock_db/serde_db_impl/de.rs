@@ -1,24 +1,157 @@
 use crate::mock_db;
 use crate::mock_db::{MValue, ResultSet};
 use serde_db::de::{
-    ConversionError, DbValue, DbValueInto, DeserializableResultSet, DeserializationError,
+    ConversionError, DbValue, DbValueInto, DeserializableResultSet, DeserializationError, Lob,
 };
-use std::{i16, i32, i8, u16, u32, u8};
+use serde_db::value::ValueKind;
 
 fn not_implemented(s: &'static str) -> ConversionError {
     ConversionError::ValueType(format!("{} not implemented", s))
 }
 
+// Simulates the incremental loading of large objects by delivering at most a few bytes per read.
+struct ChunkedReader {
+    data: std::io::Cursor<Vec<u8>>,
+}
+impl ChunkedReader {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            data: std::io::Cursor::new(data),
+        }
+    }
+}
+impl std::io::Read for ChunkedReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(3);
+        self.data.read(&mut buf[..len])
+    }
+}
+
 impl DbValue for MValue {
     fn is_null(&self) -> bool {
-        if let MValue::Null = *self {
-            true
-        } else {
-            false
+        matches!(*self, MValue::Null)
+    }
+
+    fn is_empty_string(&self) -> bool {
+        matches!(*self, MValue::String(ref s) if s.is_empty())
+    }
+
+    fn is_array(&self) -> bool {
+        matches!(*self, MValue::Array(_))
+    }
+
+    fn try_into_elements(self) -> Result<Vec<MValue>, ConversionError> {
+        match self {
+            MValue::Array(elements) => Ok(elements),
+            mv => Err(ConversionError::ValueType(format!(
+                "{:?} is not an array",
+                mv
+            ))),
+        }
+    }
+
+    fn is_map(&self) -> bool {
+        matches!(*self, MValue::Map(_))
+    }
+
+    fn try_into_entries(self) -> Result<Vec<(String, MValue)>, ConversionError> {
+        match self {
+            MValue::Map(entries) => Ok(entries),
+            mv => Err(ConversionError::ValueType(format!("{:?} is not a map", mv))),
+        }
+    }
+
+    fn is_composite(&self) -> bool {
+        matches!(*self, MValue::Composite(_))
+    }
+
+    fn try_into_fields(self) -> Result<Vec<(String, MValue)>, ConversionError> {
+        match self {
+            MValue::Composite(fields) => Ok(fields),
+            mv => Err(ConversionError::ValueType(format!(
+                "{:?} is not a composite value",
+                mv
+            ))),
+        }
+    }
+
+    fn value_kind(&self) -> ValueKind {
+        match *self {
+            MValue::Short(_) => ValueKind::Int,
+            MValue::Double(_) => ValueKind::Float,
+            MValue::Binary(_) => ValueKind::Bytes,
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            MValue::Timestamp(_) => ValueKind::DateTime,
+            #[cfg(feature = "decimal")]
+            MValue::Decimal(_) => ValueKind::Decimal,
+            _ => ValueKind::String,
+        }
+    }
+
+    fn try_into_lob(self) -> Result<Lob, ConversionError> {
+        match self {
+            MValue::Binary(v) => Ok(Lob::new(ChunkedReader::new(v))),
+            MValue::String(s) => Ok(Lob::new(ChunkedReader::new(s.into_bytes()))),
+            mv => Err(ConversionError::ValueType(format!(
+                "{:?} is not a large object",
+                mv
+            ))),
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    fn try_into_decimal(self) -> Result<serde_db::decimal::DbDecimal, ConversionError> {
+        match self {
+            MValue::Decimal(d) => Ok(d),
+            mv => DbValueInto::<String>::try_into(mv)?.parse(),
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn try_into_datetime(self) -> Result<serde_db::datetime::DbDateTime, ConversionError> {
+        use chrono::{Datelike, Timelike};
+        use serde_db::datetime::{DbDate, DbDateTime, DbTime};
+        match self {
+            MValue::Timestamp(ts) => Ok(DbDateTime::Timestamp(
+                DbDate::new(ts.0.year(), ts.0.month(), ts.0.day()),
+                DbTime::new(ts.0.hour(), ts.0.minute(), ts.0.second(), ts.0.nanosecond()),
+            )),
+            mv => DbValueInto::<String>::try_into(mv)?.parse(),
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    fn try_into_uuid(self) -> Result<uuid::Uuid, ConversionError> {
+        match self {
+            MValue::Binary(v) => {
+                uuid::Uuid::from_slice(&v).map_err(|e| ConversionError::ValueType(e.to_string()))
+            }
+            mv => uuid::Uuid::parse_str(&DbValueInto::<String>::try_into(mv)?)
+                .map_err(|e| ConversionError::ValueType(e.to_string())),
         }
     }
 }
 
+// Integers are converted from Short, from integral Double values, and from numeric strings.
+fn into_integer<T: TryFrom<i64>>(mv: MValue, target: &str) -> Result<T, ConversionError> {
+    let i = match mv {
+        MValue::Short(i) => i64::from(i),
+        MValue::Double(f) if f.fract() == 0.0 && f.abs() < 1e15 => f as i64,
+        MValue::String(ref s) => s.trim().parse().map_err(|_| {
+            ConversionError::ValueType(format!("{:?} cannot be parsed as {}", s, target))
+        })?,
+        mv => {
+            return Err(ConversionError::ValueType(format!(
+                "DbValueInto<{}> not implemented for {:?}",
+                target, mv
+            )))
+        }
+    };
+    T::try_from(i).map_err(|_| {
+        ConversionError::NumberRange(format!("value {} out of range for {}", i, target))
+    })
+}
+
 impl DbValueInto<bool> for MValue {
     fn try_into(self) -> Result<bool, ConversionError> {
         Err(not_implemented("DbValueInto<bool>"))
@@ -26,54 +159,42 @@ impl DbValueInto<bool> for MValue {
 }
 impl DbValueInto<u8> for MValue {
     fn try_into(self) -> Result<u8, ConversionError> {
-        Err(not_implemented("DbValueInto<u8>"))
+        into_integer(self, "u8")
     }
 }
 impl DbValueInto<u16> for MValue {
     fn try_into(self) -> Result<u16, ConversionError> {
-        Err(not_implemented("DbValueInto<u16>"))
+        into_integer(self, "u16")
     }
 }
 impl DbValueInto<u32> for MValue {
     fn try_into(self) -> Result<u32, ConversionError> {
-        Err(not_implemented("DbValueInto<u32>"))
+        into_integer(self, "u32")
     }
 }
 impl DbValueInto<u64> for MValue {
     fn try_into(self) -> Result<u64, ConversionError> {
-        Err(not_implemented("DbValueInto<u64>"))
+        into_integer(self, "u64")
     }
 }
 impl DbValueInto<i8> for MValue {
     fn try_into(self) -> Result<i8, ConversionError> {
-        Err(not_implemented("DbValueInto<i8>"))
+        into_integer(self, "i8")
     }
 }
 impl DbValueInto<i16> for MValue {
     fn try_into(self) -> Result<i16, ConversionError> {
-        match self {
-            MValue::Short(i) => Ok(i),
-            mv => Err(ConversionError::ValueType(format!(
-                "DbValueInto<i16> not implemented for {:?}",
-                mv
-            ))),
-        }
+        into_integer(self, "i16")
     }
 }
 impl DbValueInto<i32> for MValue {
     fn try_into(self) -> Result<i32, ConversionError> {
-        match self {
-            MValue::Short(i) => Ok(i32::from(i)),
-            mv => Err(ConversionError::ValueType(format!(
-                "DbValueInto<i32> not implemented for {:?}",
-                mv
-            ))),
-        }
+        into_integer(self, "i32")
     }
 }
 impl DbValueInto<i64> for MValue {
     fn try_into(self) -> Result<i64, ConversionError> {
-        Err(not_implemented("DbValueInto<i64>"))
+        into_integer(self, "i64")
     }
 }
 impl DbValueInto<f32> for MValue {
@@ -85,6 +206,7 @@ impl DbValueInto<f64> for MValue {
     fn try_into(self) -> Result<f64, ConversionError> {
         match self {
             MValue::Double(f) => Ok(f),
+            MValue::Short(i) => Ok(f64::from(i)),
             mv => Err(ConversionError::ValueType(format!(
                 "DbValueInto<f64> not implemented for {:?}",
                 mv
@@ -99,6 +221,7 @@ impl DbValueInto<String> for MValue {
             MValue::String(s) => Ok(s),
             MValue::Timestamp(ts) => Ok(ts.to_string()),
             MValue::Double(f) => Ok(f.to_string()),
+            MValue::Short(i) => Ok(i.to_string()),
             mv => Err(ConversionError::ValueType(format!(
                 "DbValueInto<String> not implemented for {:?}",
                 mv
@@ -108,7 +231,10 @@ impl DbValueInto<String> for MValue {
 }
 impl DbValueInto<Vec<u8>> for MValue {
     fn try_into(self) -> Result<Vec<u8>, ConversionError> {
-        Err(not_implemented("DbValueInto<Vec<u8>>"))
+        match self {
+            MValue::Binary(v) => Ok(v),
+            _ => Err(not_implemented("DbValueInto<Vec<u8>>")),
+        }
     }
 }
 
@@ -135,6 +261,12 @@ impl DeserializableResultSet for ResultSet {
 
 impl From<DeserializationError> for mock_db::Error {
     fn from(e: DeserializationError) -> mock_db::Error {
-        mock_db::Error::DESERIALIZATION(e)
+        mock_db::Error::Deserialization(e)
+    }
+}
+
+impl From<ConversionError> for mock_db::Error {
+    fn from(e: ConversionError) -> mock_db::Error {
+        mock_db::Error::Deserialization(e.into())
     }
 }
@@ -0,0 +1,21 @@
+use crate::mock_db::{KitValue, MValue};
+use serde_db::de::numeric::{Scalar, ToScalar};
+use serde_db::de::ConversionError;
+
+impl ToScalar for KitValue {
+    fn try_into_scalar(self) -> Result<Scalar, ConversionError> {
+        trace!("try_into_scalar");
+        match self.0 {
+            MValue::Short(i) => Ok(Scalar::Int(i.into())),
+            MValue::Double(f) => Ok(Scalar::Float(f)),
+            MValue::String(s) => Ok(Scalar::Text(s)),
+            MValue::Timestamp(ts) => Ok(Scalar::Text(ts.to_string())),
+            mv => Err(ConversionError::ValueType(format!(
+                "{:?} is not a scalar value",
+                mv
+            ))),
+        }
+    }
+}
+
+serde_db::impl_db_value_into!(KitValue);
@@ -2,7 +2,6 @@ use crate::mock_db::{self, MValue, ParameterType};
 use chrono::NaiveDateTime;
 use serde_db::ser::{type_error, DbvFactory, SerializationError};
 use std::str::FromStr;
-use std::{i16, i32, i8, u16, u32, u8};
 
 impl DbvFactory for &ParameterType {
     type DBV = MValue;
@@ -81,14 +80,88 @@ impl DbvFactory for &ParameterType {
             _ => Err(type_error("str", self.descriptor())),
         }
     }
-    fn serialize_bytes(&self, _value: &[u8]) -> Result<Self::DBV, SerializationError> {
-        Err(type_error("bytes", self.descriptor()))
+    fn serialize_bytes(&self, value: &[u8]) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Binary | ParameterType::NullableBinary => {
+                Ok(MValue::Binary(value.to_vec()))
+            }
+            _ => Err(type_error("bytes", self.descriptor())),
+        }
+    }
+    #[cfg(feature = "decimal")]
+    fn serialize_decimal(
+        &self,
+        value: serde_db::decimal::DbDecimal,
+    ) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Decimal | ParameterType::NullableDecimal => Ok(MValue::Decimal(value)),
+            ParameterType::String | ParameterType::NullableString => {
+                Ok(MValue::String(value.to_string()))
+            }
+            _ => Err(type_error("decimal", self.descriptor())),
+        }
+    }
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn serialize_datetime(
+        &self,
+        value: serde_db::datetime::DbDateTime,
+    ) -> Result<Self::DBV, SerializationError> {
+        use chrono::{NaiveDate, NaiveTime};
+        use serde_db::datetime::DbDateTime;
+        match (*self, value) {
+            (
+                ParameterType::Timestamp | ParameterType::NullableTimestamp,
+                DbDateTime::Timestamp(date, time),
+            ) => NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())
+                .zip(NaiveTime::from_hms_nano_opt(
+                    time.hour(),
+                    time.minute(),
+                    time.second(),
+                    time.nanosecond(),
+                ))
+                .map(|(date, time)| MValue::Timestamp(mock_db::Timestamp(date.and_time(time))))
+                .ok_or_else(|| type_error("datetime", self.descriptor())),
+            (ParameterType::String | ParameterType::NullableString, value) => {
+                Ok(MValue::String(value.to_string()))
+            }
+            _ => Err(type_error("datetime", self.descriptor())),
+        }
+    }
+    #[cfg(feature = "uuid")]
+    fn serialize_uuid(&self, value: uuid::Uuid) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Binary | ParameterType::NullableBinary => {
+                Ok(MValue::Binary(value.as_bytes().to_vec()))
+            }
+            ParameterType::String | ParameterType::NullableString => {
+                Ok(MValue::String(value.hyphenated().to_string()))
+            }
+            _ => Err(type_error("uuid", self.descriptor())),
+        }
+    }
+    fn serialize_map(
+        &self,
+        entries: Vec<(String, Option<String>)>,
+    ) -> Result<Self::DBV, SerializationError> {
+        match *self {
+            ParameterType::Map | ParameterType::NullableMap => Ok(MValue::Map(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, v.map_or(MValue::Null, MValue::String)))
+                    .collect(),
+            )),
+            _ => Err(type_error("map", self.descriptor())),
+        }
     }
     fn serialize_none(&self) -> Result<Self::DBV, SerializationError> {
         match *self {
             ParameterType::NullableShort => Ok(MValue::Null),
             ParameterType::NullableString => Ok(MValue::Null),
             ParameterType::NullableTimestamp => Ok(MValue::Null),
+            ParameterType::NullableBinary => Ok(MValue::Null),
+            ParameterType::NullableMap => Ok(MValue::Null),
+            #[cfg(feature = "decimal")]
+            ParameterType::NullableDecimal => Ok(MValue::Null),
             _ => Err(type_error("none", self.descriptor())),
         }
     }
@@ -100,6 +173,14 @@ impl DbvFactory for &ParameterType {
             ParameterType::NullableString => "NullableString",
             ParameterType::Timestamp => "Timestamp",
             ParameterType::NullableTimestamp => "NullableTimestamp",
+            ParameterType::Binary => "Binary",
+            ParameterType::NullableBinary => "NullableBinary",
+            ParameterType::Map => "Map",
+            ParameterType::NullableMap => "NullableMap",
+            #[cfg(feature = "decimal")]
+            ParameterType::Decimal => "Decimal",
+            #[cfg(feature = "decimal")]
+            ParameterType::NullableDecimal => "NullableDecimal",
         })
     }
 }
@@ -114,6 +195,6 @@ fn mock_db_timestamp(value: &str) -> Result<mock_db::Timestamp, SerializationErr
 
 impl From<SerializationError> for mock_db::Error {
     fn from(e: SerializationError) -> mock_db::Error {
-        mock_db::Error::SERIALIZATION(e)
+        mock_db::Error::Serialization(e)
     }
 }
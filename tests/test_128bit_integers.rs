@@ -0,0 +1,80 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use serde::Deserialize;
+
+#[test] // cargo test --test test_128bit_integers -- --nocapture
+pub fn test_128bit_integers() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    #[derive(Deserialize)]
+    struct TestData {
+        f1: i128,
+        f2: Option<u128>,
+    }
+
+    info!("=== 128-bit integers ===");
+    {
+        info!("Convert rows into a Vec<struct> with 128-bit fields");
+        let vtd: Vec<TestData> = get_result_set_short_short(5).try_into()?;
+        assert_eq!(5, vtd.len());
+        for (i, td) in vtd.into_iter().enumerate() {
+            assert_eq!(-(i as i128), td.f1);
+            assert_eq!(Some(i as u128), td.f2);
+        }
+    }
+    {
+        info!("Convert a 1x1 result set into an i128");
+        let i: i128 = get_result_set_short(-7).try_into()?;
+        assert_eq!(-7, i);
+    }
+    {
+        let s = "Negative test: no conversion of negative value into u128";
+        info!("{}", s);
+        let test: mock_db::Result<u128> = get_result_set_short(-7).try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        info!("Serialize 128-bit integers that fit into the parameter type");
+        let input_metadata = [PT::Short, PT::NullableShort];
+        let result: Vec<MValue> =
+            serde_db::ser::to_params(&(-5_i128, Some(5_i128)), &mut input_metadata.iter())?;
+        assert_eq!(vec![MValue::Short(-5), MValue::Short(5)], result);
+    }
+    {
+        let s = "Negative test: no serialization of an i128 beyond the 64-bit range";
+        info!("{}", s);
+        let input_metadata = [PT::Short];
+        let test: mock_db::Result<Vec<MValue>> =
+            serde_db::ser::to_params(&(i128::MAX,), &mut input_metadata.iter())
+                .map_err(mock_db::Error::from);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn get_result_set_short_short(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["f1", "f2"]);
+    for i in 0..len {
+        rs.push(vec![MValue::Short(-(i as i16)), MValue::Short(i as i16)]);
+    }
+    rs
+}
+
+fn get_result_set_short(i: i16) -> ResultSet {
+    let mut rs = ResultSet::new(&["f1"]);
+    rs.push(vec![MValue::Short(i)]);
+    rs
+}
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ResultSet};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    numbers: Vec<i32>,
+    tags: HashSet<String>,
+    sparse: Vec<Option<i32>>,
+    optional: Option<Vec<i32>>,
+}
+
+#[test] // cargo test --test test_arrays -- --nocapture
+pub fn test_arrays() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Array columns ===");
+    {
+        info!("Convert a result set with array columns into a Vec<struct>");
+        let vtd: Vec<TestData> = get_result_set_arrays(4).try_into()?;
+        assert_eq!(4, vtd.len());
+        for (i, td) in vtd.into_iter().enumerate() {
+            assert_eq!(i as i32, td.id);
+            assert_eq!((0..i as i32).collect::<Vec<_>>(), td.numbers);
+            assert_eq!(HashSet::from(["a".to_string(), "b".to_string()]), td.tags);
+            assert_eq!(vec![Some(i as i32), None], td.sparse);
+            assert_eq!(if i % 2 == 0 { None } else { Some(vec![]) }, td.optional);
+        }
+    }
+    {
+        info!("Convert a row with array columns into a tuple");
+        let row = get_result_set_arrays(2).nth(1).unwrap();
+        let t: (i32, Vec<i16>, Vec<String>, Vec<Option<i64>>) = row.try_into()?;
+        assert_eq!((1, vec![0], vec![Some(1), None]), (t.0, t.1, t.3));
+    }
+    {
+        info!("Convert a single array value into a Vec");
+        let v: Vec<i32> = MValue::Array(vec![MValue::Short(5), MValue::Short(6)]).try_into()?;
+        assert_eq!(vec![5, 6], v);
+    }
+    {
+        let s = "Negative test: no conversion of array with NULL element into Vec<T>";
+        info!("{}", s);
+        let test: mock_db::Result<Vec<i32>> =
+            MValue::Array(vec![MValue::Short(5), MValue::Null]).try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        let s = "Negative test: no conversion of non-array value into Vec<T>";
+        info!("{}", s);
+        let test: mock_db::Result<Vec<i32>> = MValue::Short(5).try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn get_result_set_arrays(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "numbers", "tags", "sparse", "optional"]);
+    for i in 0..len {
+        rs.push(vec![
+            MValue::Short(i as i16),
+            MValue::Array((0..i as i16).map(MValue::Short).collect()),
+            MValue::Array(vec![
+                MValue::String("a".to_string()),
+                MValue::String("b".to_string()),
+                MValue::String("a".to_string()),
+            ]),
+            MValue::Array(vec![MValue::Short(i as i16), MValue::Null]),
+            if i % 2 == 0 {
+                MValue::Null
+            } else {
+                MValue::Array(vec![])
+            },
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,133 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ResultSet};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Address {
+    street: String,
+    number: i16,
+    location: Location,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Location {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    address: Address,
+    range: (i16, i16),
+    alternative: Option<Address>,
+}
+
+#[test] // cargo test --test test_composite -- --nocapture
+pub fn test_composite() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Composite columns ===");
+    {
+        info!("Convert a result set with composite columns into a Vec<struct>");
+        let vtd: Vec<TestData> = get_result_set_composite(4).try_into()?;
+        assert_eq!(4, vtd.len());
+        for (i, td) in vtd.into_iter().enumerate() {
+            assert_eq!(i as i32, td.id);
+            assert_eq!(expected_address(i as i16), td.address);
+            assert_eq!((i as i16, 2 * i as i16), td.range);
+            if i % 2 == 0 {
+                assert_eq!(None, td.alternative);
+            } else {
+                assert_eq!(Some(expected_address(0)), td.alternative);
+            }
+        }
+    }
+    {
+        info!("Convert a row with composite columns into a tuple");
+        let row = get_result_set_composite(2).nth(1).unwrap();
+        let t: (i32, Address, (i16, i16), Option<Address>) = row.try_into()?;
+        assert_eq!((1, expected_address(1), (1, 2)), (t.0, t.1, t.2));
+    }
+    {
+        info!("Convert a single composite value into a struct");
+        let loc: Location = composite_location(1.5).try_into()?;
+        assert_eq!(Location { lat: 1.5, lon: 3.0 }, loc);
+    }
+    {
+        let s = "Negative test: no conversion of composite with missing field into struct";
+        info!("{}", s);
+        let test: mock_db::Result<Location> =
+            MValue::Composite(vec![("lat".to_string(), MValue::Double(1.5))]).try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        let s = "Negative test: no conversion of non-composite field into nested struct";
+        info!("{}", s);
+        let mut rs = ResultSet::new(&["lat", "lon"]);
+        rs.push(vec![MValue::Double(1.0), MValue::Short(2)]);
+        let test: mock_db::Result<Vec<(f64, Location)>> = rs.try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn expected_address(i: i16) -> Address {
+    Address {
+        street: format!("Street {i}"),
+        number: i,
+        location: Location {
+            lat: f64::from(i),
+            lon: 2.0 * f64::from(i),
+        },
+    }
+}
+
+fn composite_location(lat: f64) -> MValue {
+    MValue::Composite(vec![
+        ("lat".to_string(), MValue::Double(lat)),
+        ("lon".to_string(), MValue::Double(2.0 * lat)),
+    ])
+}
+
+fn composite_address(i: i16) -> MValue {
+    MValue::Composite(vec![
+        ("street".to_string(), MValue::String(format!("Street {i}"))),
+        ("number".to_string(), MValue::Short(i)),
+        ("location".to_string(), composite_location(f64::from(i))),
+    ])
+}
+
+fn get_result_set_composite(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "address", "range", "alternative"]);
+    for i in 0..len {
+        let i = i as i16;
+        rs.push(vec![
+            MValue::Short(i),
+            composite_address(i),
+            MValue::Composite(vec![
+                ("lower".to_string(), MValue::Short(i)),
+                ("upper".to_string(), MValue::Short(2 * i)),
+            ]),
+            if i % 2 == 0 {
+                MValue::Null
+            } else {
+                composite_address(0)
+            },
+        ]);
+    }
+    rs
+}
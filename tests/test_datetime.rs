@@ -0,0 +1,236 @@
+#![cfg(feature = "chrono")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet, Timestamp};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_db::datetime::{DbDate, DbDateTime, DbTime, NaiveTimestampPolicy};
+use serde_db::de::{DeserializableResultSet, DeserializationConfig};
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct TestData {
+    #[serde(with = "serde_db::datetime")]
+    f1: NaiveDateTime,
+    #[serde(with = "serde_db::datetime::option")]
+    f2: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StringData {
+    #[serde(with = "serde_db::datetime")]
+    f1: NaiveDate,
+    #[serde(with = "serde_db::datetime")]
+    f2: NaiveTime,
+    #[serde(with = "serde_db::datetime")]
+    f3: DateTime<FixedOffset>,
+}
+
+#[test] // cargo test --features chrono --test test_datetime -- --nocapture
+pub fn test_datetime() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Date and time transport ===");
+    {
+        info!("Convert a result set with binary timestamps into a Vec<struct>");
+        let vtd: Vec<TestData> = get_result_set_timestamps(5).try_into()?;
+        assert_eq!(5, vtd.len());
+        for (i, td) in vtd.into_iter().enumerate() {
+            assert_eq!(timestamp(i), td.f1);
+            assert_eq!(
+                if i % 2 == 0 {
+                    None
+                } else {
+                    Some(timestamp(i).and_utc())
+                },
+                td.f2
+            );
+        }
+    }
+    {
+        info!("Convert a result set with ISO 8601 strings into a struct");
+        let mut rs = ResultSet::new(&["f1", "f2", "f3"]);
+        rs.push(vec![
+            MValue::String("2024-10-13".to_string()),
+            MValue::String("23:59:01.5".to_string()),
+            MValue::String("2024-10-13 23:59:01-02:30".to_string()),
+        ]);
+        let sd: StringData = rs.try_into()?;
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 10, 13).unwrap(), sd.f1);
+        assert_eq!(
+            NaiveTime::from_hms_milli_opt(23, 59, 1, 500).unwrap(),
+            sd.f2
+        );
+        assert_eq!(
+            DateTime::parse_from_rfc3339("2024-10-13T23:59:01-02:30").unwrap(),
+            sd.f3
+        );
+    }
+    {
+        info!("Apply the policy for naive timestamps");
+        let config = DeserializationConfig::default()
+            .with_naive_timestamp_policy(NaiveTimestampPolicy::AssumeLocal);
+        let vtd: Vec<TestData> = get_result_set_timestamps(2).try_into_with(&config)?;
+        assert_eq!(
+            Local
+                .from_local_datetime(&timestamp(1))
+                .unwrap()
+                .with_timezone(&Utc),
+            vtd[1].f2.unwrap()
+        );
+
+        let s = "Negative test: no conversion of naive timestamp with policy Reject";
+        info!("{}", s);
+        let config = DeserializationConfig::default()
+            .with_naive_timestamp_policy(NaiveTimestampPolicy::Reject);
+        let test: mock_db::Result<Vec<TestData>> =
+            get_result_set_timestamps(2).try_into_with(&config);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        info!("Serialize date and time fields into string parameters");
+        let input_metadata = [PT::String, PT::NullableString];
+        let input = TestData {
+            f1: timestamp(3),
+            f2: Some(timestamp(3).and_utc()),
+        };
+        let result: Vec<MValue> = serde_db::ser::to_params(&input, &mut input_metadata.iter())?;
+        assert_eq!(
+            vec![
+                MValue::String("2024-10-04T10:00:03.300000000".to_string()),
+                MValue::String("2024-10-04T10:00:03.300000000+00:00".to_string()),
+            ],
+            result
+        );
+    }
+    {
+        info!("Format and parse DbDateTime");
+        let date = DbDate::new(2024, 2, 29);
+        let time = DbTime::new(8, 5, 0, 0);
+        for (s, dbdt) in [
+            ("2024-02-29", DbDateTime::Date(date)),
+            ("08:05:00", DbDateTime::Time(time)),
+            ("2024-02-29T08:05:00", DbDateTime::Timestamp(date, time)),
+            (
+                "2024-02-29T08:05:00-05:30",
+                DbDateTime::TimestampWithOffset(date, time, -19_800),
+            ),
+        ] {
+            assert_eq!(s, dbdt.to_string());
+            assert_eq!(dbdt, DbDateTime::from_str(s)?);
+        }
+        assert_eq!(
+            DbDateTime::TimestampWithOffset(date, time, 0),
+            DbDateTime::from_str("2024-02-29 08:05Z")?
+        );
+        assert!(DbDateTime::from_str("29.02.2024").is_err());
+
+        let bc = DbDate::new(-1, 1, 1);
+        assert_eq!("-0001-01-01", DbDateTime::Date(bc).to_string());
+        assert_eq!(DbDateTime::Date(bc), DbDateTime::from_str("-0001-01-01")?);
+        let with_seconds = DbDateTime::TimestampWithOffset(date, time, 3_723);
+        assert_eq!("2024-02-29T08:05:00+01:02:03", with_seconds.to_string());
+        assert_eq!(
+            with_seconds,
+            DbDateTime::from_str("2024-02-29T08:05:00+01:02:03")?
+        );
+        reject_malformed_offsets();
+    }
+    ambiguous_local_times()?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn reject_malformed_offsets() {
+    info!("Negative test: malformed offsets are conversion errors, not panics");
+    for s in [
+        "2024-02-29T08:05:00+1\u{e9}1",
+        "2024-02-29T08:05:00+99999999",
+        "2024-02-29T08:05:00+24:00",
+        "2024-02-29T08:05:00-01:60",
+        "2024-02-29T08:05:00+01:02:03:04",
+        "2024-02-29T08:05:00+",
+        "2024-02-29T08:05:00+a1:00",
+    ] {
+        match DbDateTime::from_str(s) {
+            Ok(dbdt) => panic!("{s:?} was parsed as {dbdt:?}"),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+}
+
+fn ambiguous_local_times() -> mock_db::Result<()> {
+    // chrono caches the local time zone per thread, so the conversions run in a new thread
+    std::env::set_var("TZ", "Europe/Berlin");
+    let result = std::thread::spawn(|| -> mock_db::Result<()> {
+        info!("Convert a local time that is repeated by a DST transition into its first instant");
+        let config = DeserializationConfig::default()
+            .with_naive_timestamp_policy(NaiveTimestampPolicy::AssumeLocal);
+        let repeated = NaiveDate::from_ymd_opt(2024, 10, 27)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        let td: TestData = get_result_set_with(repeated).try_into_with(&config)?;
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap(),
+            td.f2.unwrap()
+        );
+
+        let s = "Negative test: no conversion of a local time that is skipped by a DST transition";
+        info!("{}", s);
+        let skipped = NaiveDate::from_ymd_opt(2024, 3, 31)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        let test: mock_db::Result<TestData> = get_result_set_with(skipped).try_into_with(&config);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+        Ok(())
+    })
+    .join()
+    .unwrap();
+    std::env::remove_var("TZ");
+    result
+}
+
+fn get_result_set_with(ts: NaiveDateTime) -> ResultSet {
+    let mut rs = ResultSet::new(&["f1", "f2"]);
+    rs.push(vec![
+        MValue::Timestamp(Timestamp(ts)),
+        MValue::Timestamp(Timestamp(ts)),
+    ]);
+    rs
+}
+
+fn timestamp(i: usize) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 10, i as u32 + 1)
+        .unwrap()
+        .and_hms_milli_opt(10, 0, i as u32, 100 * i as u32)
+        .unwrap()
+}
+
+fn get_result_set_timestamps(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["f1", "f2"]);
+    for i in 0..len {
+        rs.push(vec![
+            MValue::Timestamp(Timestamp(timestamp(i))),
+            if i % 2 == 0 {
+                MValue::Null
+            } else {
+                MValue::Timestamp(Timestamp(timestamp(i)))
+            },
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,209 @@
+#![cfg(feature = "decimal")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use bigdecimal::BigDecimal;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_db::de::ConversionError;
+use serde_db::decimal::{DbDecimal, DecimalType};
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct TestData {
+    #[serde(with = "serde_db::decimal")]
+    f1: BigDecimal,
+    #[serde(with = "serde_db::decimal::option")]
+    f2: Option<BigDecimal>,
+    #[serde(with = "serde_db::decimal")]
+    f3: Decimal,
+    #[serde(with = "serde_db::decimal::option")]
+    f4: Option<Decimal>,
+}
+
+#[derive(Serialize)]
+struct Huge {
+    #[serde(with = "serde_db::decimal")]
+    value: BigDecimal,
+}
+
+#[test] // cargo test --features decimal --test test_decimal -- --nocapture
+pub fn test_decimal() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Decimal transport ===");
+    {
+        info!("Convert a result set with binary decimals into a Vec<struct>");
+        let vtd: Vec<TestData> = get_result_set_decimals(5).try_into()?;
+        assert_eq!(5, vtd.len());
+        for (i, td) in vtd.into_iter().enumerate() {
+            assert_eq!(expected(i), td);
+        }
+    }
+    {
+        info!("Convert a result set with non-binary numbers into a struct");
+        let mut rs = ResultSet::new(&["f1", "f2", "f3", "f4"]);
+        rs.push(vec![
+            MValue::String("-12345678901234567890.123456789".to_string()),
+            MValue::Null,
+            MValue::Double(0.25),
+            MValue::String("1.5E3".to_string()),
+        ]);
+        let td: TestData = rs.try_into()?;
+        assert_eq!(
+            BigDecimal::from_str("-12345678901234567890.123456789").unwrap(),
+            td.f1
+        );
+        assert_eq!(None, td.f2);
+        assert_eq!(Decimal::from_str("0.25").unwrap(), td.f3);
+        assert_eq!(Some(Decimal::from(1500)), td.f4);
+    }
+    {
+        info!("Convert individual values");
+        let value = MValue::Decimal(DbDecimal::new(-31_415, 4));
+        let bd: BigDecimal = serde_db::de::DbValue::try_into_decimal(value)?.into();
+        assert_eq!(BigDecimal::from_str("-3.1415").unwrap(), bd);
+    }
+    {
+        info!("Serialize decimal fields");
+        let input_metadata = [
+            PT::Decimal,
+            PT::NullableDecimal,
+            PT::String,
+            PT::NullableString,
+        ];
+        let result: Vec<MValue> =
+            serde_db::ser::to_params(&expected(3), &mut input_metadata.iter())?;
+        assert_eq!(
+            vec![
+                MValue::Decimal(DbDecimal::new(3_000_000_000_000_000_000_003, 2)),
+                MValue::Decimal(DbDecimal::new(-3, 0)),
+                MValue::String("0.0000000003".to_string()),
+                MValue::Null,
+            ],
+            result
+        );
+    }
+    {
+        let s = "Negative test: no conversion of a too precise decimal into rust_decimal::Decimal";
+        info!("{}", s);
+        let test = Decimal::try_from(DbDecimal::new(1, 40));
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        info!("Format and parse DbDecimal");
+        for (s, dbd) in [
+            ("0", DbDecimal::new(0, 0)),
+            ("-1.05", DbDecimal::new(-105, 2)),
+            ("0.001", DbDecimal::new(1, 3)),
+        ] {
+            assert_eq!(s, dbd.to_string());
+            assert_eq!(dbd, DbDecimal::from_str(s)?);
+        }
+        assert_eq!("4200", DbDecimal::new(42, -2).to_string());
+        assert_eq!(DbDecimal::new(12, 8), DbDecimal::from_str("1.2E-7")?);
+        assert_eq!(
+            Decimal::from(4200),
+            Decimal::try_from(DbDecimal::new(42, -2))?
+        );
+        assert!(DbDecimal::from_str("1.2.3").is_err());
+
+        info!("Use the scientific notation for extreme scales");
+        for (s, dbd) in [
+            ("1E2000000000", DbDecimal::new(1, -2_000_000_000)),
+            ("-12E-100", DbDecimal::new(-12, 100)),
+            ("0E-9223372036854775807", DbDecimal::new(0, i64::MAX)),
+        ] {
+            assert_eq!(s, dbd.to_string());
+            assert_eq!(dbd, DbDecimal::from_str(s)?);
+        }
+        assert_eq!("0", DbDecimal::new(0, i64::MIN).to_string());
+        let huge = BigDecimal::from_str("1e2000000000").unwrap();
+        let input_metadata = [PT::String];
+        let result: Vec<MValue> =
+            serde_db::ser::to_params(&Huge { value: huge }, &mut input_metadata.iter())?;
+        assert_eq!(vec![MValue::String("1E2000000000".to_string())], result);
+    }
+    own_decimal_type()?;
+
+    Ok(())
+}
+
+// Types without serde implementations of their own can use the transport.
+fn own_decimal_type() -> mock_db::Result<()> {
+    #[derive(Debug, PartialEq)]
+    struct Cents(i64);
+
+    impl DecimalType for Cents {
+        fn to_db_decimal(&self) -> Result<DbDecimal, ConversionError> {
+            Ok(DbDecimal::new(i128::from(self.0), 2))
+        }
+        fn from_db_decimal(dbd: DbDecimal) -> Result<Self, ConversionError> {
+            match (i64::try_from(dbd.mantissa()), dbd.scale()) {
+                (Ok(cents), 2) => Ok(Cents(cents)),
+                _ => Err(ConversionError::NumberRange(format!(
+                    "{dbd} is not in cents"
+                ))),
+            }
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Price {
+        #[serde(with = "serde_db::decimal")]
+        amount: Cents,
+        #[serde(with = "serde_db::decimal::option")]
+        discount: Option<Cents>,
+    }
+
+    info!("Use the transport with a type that does not implement serde's traits");
+    let price = Price {
+        amount: Cents(1999),
+        discount: Some(Cents(-500)),
+    };
+    let mut rs = ResultSet::new(&["amount", "discount"]);
+    rs.push(serde_db::ser::to_params(
+        &price,
+        &mut [PT::Decimal, PT::NullableDecimal].iter(),
+    )?);
+    assert_eq!(price, rs.try_into()?);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn expected(i: usize) -> TestData {
+    let i = i as i128;
+    TestData {
+        f1: BigDecimal::from(DbDecimal::new(1_000_000_000_000_000_000_000 * i + i, 2)),
+        f2: if i % 2 == 0 {
+            None
+        } else {
+            Some(BigDecimal::from(-i))
+        },
+        f3: Decimal::from_i128_with_scale(i, 10),
+        f4: None,
+    }
+}
+
+fn get_result_set_decimals(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["f1", "f2", "f3", "f4"]);
+    for i in 0..len {
+        let td = expected(i);
+        let input_metadata = [
+            PT::Decimal,
+            PT::NullableDecimal,
+            PT::Decimal,
+            PT::NullableDecimal,
+        ];
+        rs.push(serde_db::ser::to_params(&td, &mut input_metadata.iter()).unwrap());
+    }
+    rs
+}
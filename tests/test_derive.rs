@@ -0,0 +1,291 @@
+#![cfg(feature = "derive")]
+
+#[macro_use]
+extern crate log;
+
+mod util;
+
+use serde::{Deserialize, Serialize};
+use serde_db::de::{DbValue, DeserializationError};
+use serde_db::ser::{DbvFactory, SerializationError};
+use serde_db::value::{Value, ValueKind};
+
+#[derive(Debug, PartialEq, DbValue)]
+enum DValue {
+    #[db_value(null)]
+    Null,
+    #[db_value(bool)]
+    Boolean(bool),
+    #[db_value(int)]
+    TinyInt(u8),
+    #[db_value(int)]
+    BigInt(i64),
+    #[db_value(float)]
+    Real(f32),
+    #[db_value(text)]
+    Text(String),
+    #[db_value(bytes)]
+    Blob(Vec<u8>),
+    #[db_value(array)]
+    Array(Vec<DValue>),
+    #[db_value(map)]
+    Map(Vec<(String, DValue)>),
+    #[db_value(composite)]
+    Row(Vec<(String, DValue)>),
+    #[allow(dead_code)]
+    Cursor(u64),
+}
+
+#[derive(Debug, DbvFactory)]
+#[dbv_factory(value = DValue, null = DValue::Null)]
+enum DType {
+    #[dbv_factory(DValue::Boolean(bool))]
+    Boolean,
+    #[dbv_factory(DValue::TinyInt(u8), nullable)]
+    NullableTinyInt,
+    #[dbv_factory(DValue::BigInt(i64))]
+    BigInt,
+    #[dbv_factory(DValue::Real(f32))]
+    Real,
+    #[dbv_factory(DValue::Text(String), nullable)]
+    NullableText,
+    #[dbv_factory(DValue::Blob(Vec<u8>))]
+    Blob,
+    #[allow(dead_code)]
+    Cursor,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Person {
+    id: u32,
+    name: String,
+    nickname: Option<String>,
+    active: bool,
+    score: f64,
+    tags: Vec<String>,
+}
+
+#[test] // cargo test --features derive --test test_derive -- --nocapture
+pub fn test_derive() -> Result<(), Box<dyn std::error::Error>> {
+    let _loghandle = util::init_logger();
+
+    derive_db_value()?;
+    derive_dbv_factory()?;
+    Ok(())
+}
+
+fn derive_db_value() -> Result<(), DeserializationError> {
+    info!("=== Derived DbValue ===");
+    {
+        info!("Convert scalar values");
+        assert!(DValue::Null.is_null());
+        assert!(!DValue::TinyInt(0).is_null());
+        let i: i16 = DbValue::try_into(DValue::TinyInt(200))?;
+        assert_eq!(200, i);
+        let f: f64 = DbValue::try_into(DValue::Real(1.5))?;
+        assert_eq!(1.5, f);
+        let b: bool = DbValue::try_into(DValue::BigInt(1))?;
+        assert!(b);
+        let s: String = DbValue::try_into(DValue::Boolean(true))?;
+        assert_eq!("true", s);
+        let u: u64 = DbValue::try_into(DValue::Text("42".to_string()))?;
+        assert_eq!(42, u);
+        let i: i128 = DbValue::try_into(DValue::BigInt(-5))?;
+        assert_eq!(-5, i);
+        let o: Option<i32> = DbValue::try_into(DValue::Null)?;
+        assert_eq!(None, o);
+    }
+    {
+        info!("Convert structured values");
+        let v: Vec<i32> =
+            DbValue::try_into(DValue::Array(vec![DValue::TinyInt(1), DValue::BigInt(2)]))?;
+        assert_eq!(vec![1, 2], v);
+        let m: std::collections::BTreeMap<String, u8> =
+            DbValue::try_into(DValue::Map(vec![("a".to_string(), DValue::TinyInt(1))]))?;
+        assert_eq!(Some(&1), m.get("a"));
+        let p: Person = DbValue::try_into(DValue::Row(vec![
+            ("id".to_string(), DValue::BigInt(7)),
+            ("name".to_string(), DValue::Text("Alice".to_string())),
+            ("nickname".to_string(), DValue::Null),
+            ("active".to_string(), DValue::Boolean(true)),
+            ("score".to_string(), DValue::Real(2.5)),
+            (
+                "tags".to_string(),
+                DValue::Array(vec![DValue::Text("x".to_string())]),
+            ),
+        ]))?;
+        assert_eq!(
+            Person {
+                id: 7,
+                name: "Alice".to_string(),
+                nickname: None,
+                active: true,
+                score: 2.5,
+                tags: vec!["x".to_string()],
+            },
+            p
+        );
+        let bytes: serde_bytes_like::ByteBuf = DbValue::try_into(DValue::Blob(vec![1, 2, 3]))?;
+        assert_eq!(vec![1, 2, 3], bytes.0);
+    }
+    {
+        info!("Convert values into dynamic values of the annotated kind");
+        let v: Value = DbValue::try_into(DValue::TinyInt(3))?;
+        assert_eq!(Value::Int(3), v);
+        let v: Value = DbValue::try_into(DValue::Real(0.5))?;
+        assert_eq!(Value::Float(0.5), v);
+        let v: Value = DbValue::try_into(DValue::Boolean(false))?;
+        assert_eq!(Value::Bool(false), v);
+        let v: Value = DbValue::try_into(DValue::Text("x".to_string()))?;
+        assert_eq!(Value::String("x".to_string()), v);
+        let v: Value = DbValue::try_into(DValue::Blob(vec![4]))?;
+        assert_eq!(Value::Bytes(vec![4]), v);
+        assert_eq!(ValueKind::Int, DValue::BigInt(1).value_kind());
+        assert!(DValue::Text(String::new()).is_empty_string());
+        assert!(!DValue::Text("x".to_string()).is_empty_string());
+        assert!(!DValue::Blob(Vec::new()).is_empty_string());
+    }
+    {
+        for (s, test) in [
+            (
+                "Negative test: no conversion of out-of-range value",
+                DbValue::try_into::<u8>(DValue::BigInt(300)).map(|_| ()),
+            ),
+            (
+                "Negative test: no conversion of unannotated variant",
+                DbValue::try_into::<u64>(DValue::Cursor(1)).map(|_| ()),
+            ),
+            (
+                "Negative test: no conversion of non-array into Vec",
+                DbValue::try_into::<Vec<u8>>(DValue::TinyInt(1)).map(|_| ()),
+            ),
+        ] {
+            info!("{}", s);
+            match test {
+                Ok(_) => panic!("Failed \"{}\"", s),
+                Err(e) => info!("--> Exception: {:?}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn derive_dbv_factory() -> Result<(), SerializationError> {
+    info!("=== Derived DbvFactory ===");
+    {
+        info!("Serialize with widening conversions");
+        #[derive(Serialize)]
+        struct Params<'a> {
+            flag: &'a str,
+            small: u64,
+            big: u8,
+            real: i16,
+            text: f64,
+            blob: &'a serde_bytes_like::Bytes,
+        }
+        let metadata = [
+            DType::Boolean,
+            DType::NullableTinyInt,
+            DType::BigInt,
+            DType::Real,
+            DType::NullableText,
+            DType::Blob,
+        ];
+        let params = Params {
+            flag: "true",
+            small: 200,
+            big: 7,
+            real: -3,
+            text: 2.5,
+            blob: &serde_bytes_like::Bytes(vec![1, 2]),
+        };
+        let result: Vec<DValue> = serde_db::ser::to_params(&params, &mut metadata.iter())?;
+        assert_eq!(
+            vec![
+                DValue::Boolean(true),
+                DValue::TinyInt(200),
+                DValue::BigInt(7),
+                DValue::Real(-3.0),
+                DValue::Text("2.5".to_string()),
+                DValue::Blob(vec![1, 2]),
+            ],
+            result
+        );
+
+        let metadata = [DType::NullableTinyInt, DType::NullableText];
+        let result: Vec<DValue> =
+            serde_db::ser::to_params(&(None::<u8>, None::<String>), &mut metadata.iter())?;
+        assert_eq!(vec![DValue::Null, DValue::Null], result);
+        assert_eq!("NullableText", DType::NullableText.descriptor());
+    }
+    {
+        for (s, test) in [
+            (
+                "Negative test: no conversion of out-of-range value",
+                DType::NullableTinyInt.serialize_i32(256),
+            ),
+            (
+                "Negative test: no conversion with loss of precision",
+                DType::Real.serialize_i64(16_777_217),
+            ),
+            (
+                "Negative test: no conversion of unparsable text",
+                DType::BigInt.serialize_str("seven"),
+            ),
+            (
+                "Negative test: no conversion of float into integer",
+                DType::BigInt.serialize_f64(1.0),
+            ),
+            (
+                "Negative test: no None for non-nullable variant",
+                DType::Boolean.serialize_none(),
+            ),
+            (
+                "Negative test: no conversion into unannotated variant",
+                DType::Cursor.serialize_u8(1),
+            ),
+        ] {
+            info!("{}", s);
+            match test {
+                Ok(_) => panic!("Failed \"{}\"", s),
+                Err(e) => info!("--> Exception: {:?}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Serializes as and deserializes from bytes, like `serde_bytes`.
+mod serde_bytes_like {
+    pub struct ByteBuf(pub Vec<u8>);
+
+    pub struct Bytes(pub Vec<u8>);
+
+    impl serde::Serialize for Bytes {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for ByteBuf {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_byte_buf(Visitor)
+        }
+    }
+
+    struct Visitor;
+
+    impl serde::de::Visitor<'_> for Visitor {
+        type Value = ByteBuf;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("bytes")
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+            Ok(ByteBuf(value.to_vec()))
+        }
+    }
+}
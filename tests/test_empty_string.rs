@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use serde::{Deserialize, Serialize};
+use serde_db::de::{DeserializableResultSet, DeserializationConfig};
+use serde_db::ser::SerializationConfig;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+struct TestData {
+    id: i16,
+    name: Option<String>,
+    comment: Option<String>,
+}
+
+#[test] // cargo test --test test_empty_string -- --nocapture
+pub fn test_empty_string() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Equivalence of empty strings and NULL ===");
+    {
+        info!("By default, empty strings are deserialized as Some(\"\")");
+        let vtd: Vec<TestData> = get_result_set().try_into()?;
+        assert_eq!(Some(String::new()), vtd[0].name);
+        assert_eq!(None, vtd[0].comment);
+    }
+    {
+        info!("Deserialize empty strings as None");
+        let config = DeserializationConfig::default().with_empty_string_as_null(true);
+        let vtd: Vec<TestData> = get_result_set().try_into_with(&config)?;
+        assert_eq!(None, vtd[0].name);
+        assert_eq!(None, vtd[0].comment);
+        assert_eq!(Some("Bob".to_string()), vtd[1].name);
+
+        info!("Strings that are not an Option are not affected");
+        let vs: Vec<(i16, String)> = get_result_set_plain().try_into_with(&config)?;
+        assert_eq!(String::new(), vs[0].1);
+    }
+    {
+        let input_metadata = [PT::Short, PT::NullableString, PT::NullableString];
+        let input = TestData {
+            id: 1,
+            name: Some(String::new()),
+            comment: None,
+        };
+
+        info!("By default, empty strings are bound as empty strings");
+        let result: Vec<MValue> = serde_db::ser::to_params(&input, &mut input_metadata.iter())?;
+        assert_eq!(
+            vec![
+                MValue::Short(1),
+                MValue::String(String::new()),
+                MValue::Null
+            ],
+            result
+        );
+
+        info!("Bind empty strings as NULL");
+        let config = SerializationConfig::default().with_empty_string_as_null(true);
+        let result: Vec<MValue> =
+            serde_db::ser::to_params_with(&input, &mut input_metadata.iter(), &config)?;
+        assert_eq!(vec![MValue::Short(1), MValue::Null, MValue::Null], result);
+
+        let s = "Negative test: no binding of empty string as NULL to a non-nullable parameter";
+        info!("{}", s);
+        let test = serde_db::ser::to_params_with(
+            &(1_i16, ""),
+            &mut [PT::Short, PT::String].iter(),
+            &config,
+        );
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn get_result_set() -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "name", "comment"]);
+    rs.push(vec![
+        MValue::Short(0),
+        MValue::String(String::new()),
+        MValue::Null,
+    ]);
+    rs.push(vec![
+        MValue::Short(1),
+        MValue::String("Bob".to_string()),
+        MValue::String("hello".to_string()),
+    ]);
+    rs
+}
+
+fn get_result_set_plain() -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "name"]);
+    rs.push(vec![MValue::Short(0), MValue::String(String::new())]);
+    rs.push(vec![MValue::Short(1), MValue::String("Bob".to_string())]);
+    rs
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "memory")]
+
+#[macro_use]
+extern crate log;
+
+mod util;
+
+use serde::{Deserialize, Serialize};
+use serde_db::memory::{to_result_set, to_result_set_with, MemValue};
+use serde_db::ser::{SerializationConfig, SerializationError};
+use std::collections::BTreeMap;
+use std::error::Error;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct Person {
+    id: u32,
+    name: String,
+    nickname: Option<String>,
+    score: f64,
+    active: bool,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct PersonRecord {
+    id: i64,
+    name: String,
+    nickname: Option<String>,
+    score: f32,
+    active: bool,
+}
+
+#[test] // cargo test --features memory --test test_fixtures -- --nocapture
+pub fn test_fixtures() -> Result<(), Box<dyn Error>> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Result sets from fixtures ===");
+    {
+        info!("Build a result set from structs and read it back");
+        let fixtures = persons();
+        let rs = to_result_set(&fixtures)?;
+        assert_eq!(["id", "name", "nickname", "score", "active"], rs.columns());
+        assert_eq!(2, rs.len());
+        let persons: Vec<Person> = rs.try_into()?;
+        assert_eq!(fixtures, persons);
+
+        info!("Read it back into a type with different field types");
+        let records: Vec<PersonRecord> = to_result_set(&fixtures)?.try_into()?;
+        assert_eq!(2, records[1].id);
+        assert_eq!(Some("Bobby".to_string()), records[1].nickname);
+        assert!((records[1].score - 1.5).abs() < f32::EPSILON);
+        assert!(!records[1].active && records[0].name == "Alice");
+    }
+    {
+        info!("Values keep their natural representation");
+        let mut rs = to_result_set(&persons())?;
+        let row = rs.next().unwrap();
+        assert_eq!(
+            [
+                MemValue::Int(1),
+                MemValue::String("Alice".to_string()),
+                MemValue::Null,
+                MemValue::Float(0.5),
+                MemValue::Bool(true),
+            ],
+            row.values()
+        );
+    }
+    {
+        info!("Tuples and scalars get positional column names");
+        let rs = to_result_set(&[(1_u8, "a"), (2_u8, "b")])?;
+        assert_eq!(["0", "1"], rs.columns());
+        let tuples: Vec<(u64, String)> = rs.try_into()?;
+        assert_eq!(vec![(1, "a".to_string()), (2, "b".to_string())], tuples);
+
+        let rs = to_result_set(&[10_i16, 20, 30])?;
+        assert_eq!(["0"], rs.columns());
+        let numbers: Vec<i64> = rs.try_into()?;
+        assert_eq!(vec![10, 20, 30], numbers);
+    }
+    {
+        info!("Maps provide the column names with their keys");
+        let mut row = BTreeMap::new();
+        row.insert("id", 7_i64);
+        row.insert("score", 3);
+        let rs = to_result_set(&[row])?;
+        assert_eq!(["id", "score"], rs.columns());
+        let (id, score): (u8, f32) = rs.try_into()?;
+        assert_eq!((7, 3.0), (id, score));
+    }
+    {
+        info!("Rows must have the same columns");
+        let mut first = BTreeMap::new();
+        first.insert("a", 1);
+        let mut second = BTreeMap::new();
+        second.insert("b", 2);
+        assert!(matches!(
+            to_result_set(&[first, second]),
+            Err(SerializationError::StructuralMismatch(_))
+        ));
+
+        info!("Values that are out of range are rejected");
+        assert!(matches!(
+            to_result_set(&[u64::MAX]),
+            Err(SerializationError::Range("u64", _))
+        ));
+    }
+    {
+        info!("Empty fixtures produce an empty result set");
+        let rs = to_result_set::<Person>(&[])?;
+        assert!(rs.is_empty());
+        let persons: Vec<Person> = rs.try_into()?;
+        assert!(persons.is_empty());
+    }
+    {
+        info!("The configuration is applied");
+        let config = SerializationConfig::default().with_empty_string_as_null(true);
+        let rs = to_result_set_with(&[("", 1)], &config)?;
+        let (text, _): (Option<String>, u8) = rs.try_into()?;
+        assert_eq!(None, text);
+    }
+    #[cfg(feature = "chrono")]
+    dates()?;
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+fn dates() -> Result<(), Box<dyn Error>> {
+    use chrono::NaiveDate;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Event {
+        #[serde(with = "serde_db::datetime")]
+        day: NaiveDate,
+    }
+
+    info!("Dates are transported as dates");
+    let fixtures = [Event {
+        day: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+    }];
+    let rs = to_result_set(&fixtures)?;
+    assert!(matches!(
+        rs.clone().next().unwrap().values()[0],
+        MemValue::DateTime(_)
+    ));
+    let event: Event = rs.try_into()?;
+    assert_eq!(fixtures[0], event);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn persons() -> Vec<Person> {
+    vec![
+        Person {
+            id: 1,
+            name: "Alice".to_string(),
+            nickname: None,
+            score: 0.5,
+            active: true,
+        },
+        Person {
+            id: 2,
+            name: "Bob".to_string(),
+            nickname: Some("Bobby".to_string()),
+            score: 1.5,
+            active: false,
+        },
+    ]
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "derive")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ResultSet};
+use serde::Deserialize;
+use serde_db::de::{
+    DeserializableResultSet, DeserializationConfig, DeserializationError, FromRow, NullPolicy,
+};
+
+#[derive(Debug, Deserialize, FromRow, PartialEq)]
+struct TestData {
+    id: i32,
+    name: String,
+    nickname: Option<String>,
+    score: f64,
+    #[from_row(serde)]
+    numbers: Vec<i32>,
+}
+
+#[derive(Debug, FromRow)]
+struct Renamed {
+    #[from_row(rename = "id")]
+    key: u8,
+    #[from_row(rename = "name")]
+    label: String,
+}
+
+#[test] // cargo test --features derive --test test_from_row -- --nocapture
+pub fn test_from_row() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== FromRow ===");
+    {
+        info!("Convert rows with the expected column order");
+        let vtd: Vec<TestData> =
+            get_result_set(&["id", "name", "nickname", "score", "numbers"]).try_into_rows()?;
+        assert_eq!(expected(), vtd);
+
+        info!("Results are the same as with serde");
+        let vtd: Vec<TestData> =
+            get_result_set(&["id", "name", "nickname", "score", "numbers"]).try_into()?;
+        assert_eq!(expected(), vtd);
+    }
+    {
+        info!("Convert rows with reordered columns by name");
+        let mut rs = ResultSet::new(&["numbers", "score", "name", "id", "nickname"]);
+        rs.push(vec![
+            MValue::Array(vec![MValue::Short(1)]),
+            MValue::Double(1.5),
+            MValue::String("Bob".to_string()),
+            MValue::Short(3),
+            MValue::Null,
+        ]);
+        let row = rs.next().unwrap();
+        let td = TestData::from_row(row)?;
+        assert_eq!(
+            TestData {
+                id: 3,
+                name: "Bob".to_string(),
+                nickname: None,
+                score: 1.5,
+                numbers: vec![1],
+            },
+            td
+        );
+    }
+    {
+        info!("Convert rows into renamed fields");
+        let mut rs = ResultSet::new(&["id", "name"]);
+        rs.push(vec![MValue::Short(7), MValue::String("seven".to_string())]);
+        let vr: Vec<Renamed> = rs.try_into_rows()?;
+        assert_eq!((7, "seven"), (vr[0].key, vr[0].label.as_str()));
+    }
+    {
+        info!("Apply the configuration");
+        let config = DeserializationConfig::default()
+            .with_null_policy(NullPolicy::Default)
+            .with_empty_string_as_null(true);
+        let mut rs = ResultSet::new(&["id", "name", "nickname", "score", "numbers"]);
+        rs.push(vec![
+            MValue::Short(4),
+            MValue::Null,
+            MValue::String(String::new()),
+            MValue::Null,
+            MValue::Null,
+        ]);
+        let vtd: Vec<TestData> = DeserializableResultSet::try_into_rows_with(rs, &config)?;
+        assert_eq!(
+            (4, "", None, 0.0),
+            (
+                vtd[0].id,
+                vtd[0].name.as_str(),
+                vtd[0].nickname.as_deref(),
+                vtd[0].score
+            )
+        );
+        assert!(vtd[0].numbers.is_empty());
+    }
+    {
+        let s = "Negative test: NULL values are rejected by default, with the column name";
+        info!("{}", s);
+        let mut rs = ResultSet::new(&["id", "name"]);
+        rs.push(vec![MValue::Short(1), MValue::Null]);
+        match rs.try_into_rows::<Renamed>() {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(mock_db::Error::Deserialization(DeserializationError::UnexpectedNull(column))) => {
+                assert_eq!("name", column);
+            }
+            Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+        }
+    }
+    {
+        for (s, test) in [
+            (
+                "Negative test: unknown column",
+                ResultSet::new(&["id", "name", "color"])
+                    .try_into_rows::<Renamed>()
+                    .map(|_| ()),
+            ),
+            (
+                "Negative test: missing column",
+                get_result_set(&["id", "name"])
+                    .try_into_rows::<TestData>()
+                    .map(|_| ()),
+            ),
+            ("Negative test: value out of range", {
+                let mut rs = ResultSet::new(&["id", "name"]);
+                rs.push(vec![MValue::Short(300), MValue::String("x".to_string())]);
+                rs.try_into_rows::<Renamed>().map(|_| ())
+            }),
+        ] {
+            info!("{}", s);
+            match test {
+                Ok(_) => panic!("Failed \"{}\"", s),
+                Err(e) => info!("--> Exception: {:?}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn expected() -> Vec<TestData> {
+    (0..3)
+        .map(|i| TestData {
+            id: i,
+            name: format!("name {i}"),
+            nickname: if i % 2 == 0 {
+                None
+            } else {
+                Some(format!("nick {i}"))
+            },
+            score: f64::from(i) * 0.5,
+            numbers: vec![i; 2],
+        })
+        .collect()
+}
+
+fn get_result_set(fields: &[&'static str]) -> ResultSet {
+    let mut rs = ResultSet::new(fields);
+    for td in expected() {
+        let id = i16::try_from(td.id).unwrap();
+        let row = [
+            MValue::Short(id),
+            MValue::String(td.name),
+            td.nickname.map_or(MValue::Null, MValue::String),
+            MValue::Double(td.score),
+            MValue::Array(vec![MValue::Short(id); 2]),
+        ];
+        rs.push(row[..fields.len()].to_vec());
+    }
+    rs
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "json")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use serde::{Deserialize, Serialize};
+use serde_db::de::{DeserializableResultSet, DeserializationConfig, DeserializationError};
+use serde_db::Json;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Item {
+    name: String,
+    qty: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    id: i32,
+    items: Vec<Item>,
+    attributes: Option<BTreeMap<String, String>>,
+    first: Item,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct ExplicitOrder {
+    id: i32,
+    items: Json<Vec<Item>>,
+    attributes: Option<Json<BTreeMap<String, String>>>,
+    first: Option<Json<Item>>,
+}
+
+const ITEMS: &str = r#"[{"name":"apple","qty":3},{"name":"pear","qty":1}]"#;
+
+#[test] // cargo test --features json --test test_json -- --nocapture
+pub fn test_json() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== JSON columns ===");
+    {
+        info!("Convert JSON columns implicitly into nested structs, Vecs, and maps");
+        let vo: Vec<Order> = get_result_set_orders(3).try_into()?;
+        assert_eq!(3, vo.len());
+        for (i, o) in vo.into_iter().enumerate() {
+            assert_eq!(i as i32, o.id);
+            assert_eq!(items(), o.items);
+            assert_eq!(
+                if i % 2 == 0 {
+                    None
+                } else {
+                    Some(BTreeMap::from([("prio".to_string(), "high".to_string())]))
+                },
+                o.attributes
+            );
+            assert_eq!(items().remove(0), o.first);
+        }
+    }
+    {
+        info!("Convert JSON columns explicitly with Json<T>");
+        let vo: Vec<ExplicitOrder> = get_result_set_orders(2).try_into()?;
+        assert_eq!(items(), *vo[1].items);
+        assert_eq!(items().remove(0), vo[1].first.as_ref().unwrap().0);
+    }
+    {
+        info!("Convert a single-column result set into a Vec<Json<T>>");
+        let mut rs = ResultSet::new(&["item"]);
+        rs.push(vec![MValue::String(
+            r#"{"name":"fig","qty":7}"#.to_string(),
+        )]);
+        rs.push(vec![MValue::String(
+            r#"{"name":"kiwi","qty":2}"#.to_string(),
+        )]);
+        let vi: Vec<Json<Item>> = rs.try_into()?;
+        assert_eq!(
+            vec!["fig", "kiwi"],
+            vi.iter().map(|i| i.name.as_str()).collect::<Vec<_>>()
+        );
+    }
+    {
+        let s = "Negative test: no conversion of invalid JSON";
+        info!("{}", s);
+        let mut rs = ResultSet::new(&["id", "items", "attributes", "first"]);
+        rs.push(vec![
+            MValue::Short(1),
+            MValue::String("[{\"name\":".to_string()),
+            MValue::Null,
+            MValue::String(r#"{"name":"fig","qty":7}"#.to_string()),
+        ]);
+        let test: mock_db::Result<Order> = rs.try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        info!("Serialize Json<T> fields as JSON text");
+        let input_metadata = [
+            PT::Short,
+            PT::String,
+            PT::NullableString,
+            PT::NullableString,
+        ];
+        let input = ExplicitOrder {
+            id: 4,
+            items: Json(items()),
+            attributes: Some(Json(BTreeMap::from([(
+                "prio".to_string(),
+                "low".to_string(),
+            )]))),
+            first: None,
+        };
+        let result: Vec<MValue> = serde_db::ser::to_params(&input, &mut input_metadata.iter())?;
+        assert_eq!(
+            vec![
+                MValue::Short(4),
+                MValue::String(ITEMS.to_string()),
+                MValue::String(r#"{"prio":"low"}"#.to_string()),
+                MValue::Null,
+            ],
+            result
+        );
+    }
+    json_within_byte_limit()?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn json_within_byte_limit() -> mock_db::Result<()> {
+    // the row has 50 + 24 bytes of JSON text
+    info!("JSON text counts against the byte limit");
+    let config = DeserializationConfig::default().with_max_bytes(74);
+    let vo: Vec<Order> = get_result_set_orders(1).try_into_with(&config)?;
+    assert_eq!(items(), vo[0].items);
+
+    for config in [
+        DeserializationConfig::default().with_max_bytes(73),
+        DeserializationConfig::default().with_max_bytes(20),
+    ] {
+        let s = "Negative test: JSON text exceeds the byte limit";
+        info!("{}", s);
+        let test: mock_db::Result<Vec<Order>> = get_result_set_orders(1).try_into_with(&config);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(mock_db::Error::Deserialization(DeserializationError::LimitExceeded(e))) => {
+                info!("--> Exception: {}", e);
+            }
+            Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+        }
+        let test: mock_db::Result<Vec<ExplicitOrder>> =
+            get_result_set_orders(1).try_into_with(&config);
+        assert!(matches!(
+            test,
+            Err(mock_db::Error::Deserialization(
+                DeserializationError::LimitExceeded(_)
+            ))
+        ));
+    }
+    Ok(())
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item {
+            name: "apple".to_string(),
+            qty: 3,
+        },
+        Item {
+            name: "pear".to_string(),
+            qty: 1,
+        },
+    ]
+}
+
+fn get_result_set_orders(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "items", "attributes", "first"]);
+    for i in 0..len {
+        rs.push(vec![
+            MValue::Short(i as i16),
+            MValue::String(ITEMS.to_string()),
+            if i % 2 == 0 {
+                MValue::Null
+            } else {
+                MValue::String(r#"{"prio":"high"}"#.to_string())
+            },
+            MValue::String(r#"{"name":"apple","qty":3}"#.to_string()),
+        ]);
+    }
+    rs
+}
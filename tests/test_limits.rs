@@ -0,0 +1,142 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ResultSet};
+use serde::Deserialize;
+use serde_db::de::{
+    DbValue, DeserializableResultSet, DeserializableRow, DeserializationConfig,
+    DeserializationError, DeserializationResult, FromRow, Lob, RowFields,
+};
+use serde_db::observer::Counters;
+use std::io::Read;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    name: String,
+}
+
+impl FromRow for TestData {
+    const COLUMNS: &'static [&'static str] = &["id", "name"];
+
+    fn from_fields<R: DeserializableRow>(
+        fields: &mut RowFields<'_, R>,
+    ) -> DeserializationResult<Self> {
+        Ok(TestData {
+            id: fields.required()?,
+            name: fields.required()?,
+        })
+    }
+}
+
+#[test] // cargo test --test test_limits -- --nocapture
+pub fn test_limits() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Resource limits ===");
+    {
+        info!("Deserialize result sets within the limits");
+        let config = DeserializationConfig::default()
+            .with_max_rows(5)
+            .with_max_bytes(30);
+        let vtd: Vec<TestData> = get_result_set(5).try_into_with(&config)?;
+        assert_eq!(5, vtd.len());
+        assert_eq!((4, "name 4"), (vtd[4].id, vtd[4].name.as_str()));
+
+        info!("Limits apply to each deserialization separately");
+        let vtd: Vec<TestData> = get_result_set(5).try_into_with(&config)?;
+        assert_eq!(5, vtd.len());
+    }
+    {
+        let s = "Negative test: too many rows";
+        info!("{}", s);
+        let config = DeserializationConfig::default().with_max_rows(4);
+        let test: mock_db::Result<Vec<TestData>> = get_result_set(5).try_into_with(&config);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(mock_db::Error::Deserialization(DeserializationError::LimitExceeded(e))) => {
+                info!("--> Exception: {}", e);
+            }
+            Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+        }
+    }
+    {
+        let s = "Negative test: too many bytes";
+        info!("{}", s);
+        let config = DeserializationConfig::default().with_max_bytes(29);
+        let test: mock_db::Result<Vec<TestData>> = get_result_set(5).try_into_with(&config);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(mock_db::Error::Deserialization(DeserializationError::LimitExceeded(e))) => {
+                info!("--> Exception: {}", e);
+            }
+            Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+        }
+        let test: Result<String, _> =
+            DbValue::try_into_with(MValue::String("x".repeat(30)), &config);
+        assert!(matches!(test, Err(DeserializationError::LimitExceeded(_))));
+    }
+    {
+        info!("Read a LOB within its limit");
+        let config = DeserializationConfig::default().with_max_lob_size(10);
+        let mut lob: Lob = DbValue::try_into_with(MValue::Binary(vec![7; 10]), &config)?;
+        let mut content = Vec::new();
+        lob.read_to_end(&mut content).unwrap();
+        assert_eq!(vec![7; 10], content);
+
+        let s = "Negative test: LOB too big";
+        info!("{}", s);
+        let mut lob: Lob = DbValue::try_into_with(MValue::Binary(vec![7; 11]), &config)?;
+        let mut content = Vec::new();
+        let e = lob.read_to_end(&mut content).unwrap_err();
+        info!("--> Exception: {}", e);
+        assert!(matches!(
+            e.into_inner()
+                .unwrap()
+                .downcast::<DeserializationError>()
+                .map(|e| *e),
+            Ok(DeserializationError::LimitExceeded(_))
+        ));
+        assert_eq!(10, content.len());
+    }
+    from_row_limits()?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn from_row_limits() -> mock_db::Result<()> {
+    info!("Convert rows with FromRow within the byte limit");
+    let counters = Arc::new(Counters::new());
+    let config = DeserializationConfig::default()
+        .with_max_bytes(30)
+        .with_observer(counters.clone());
+    let vtd: Vec<TestData> = get_result_set(5).try_into_rows_with(&config)?;
+    assert_eq!(5, vtd.len());
+    assert_eq!(30, counters.bytes_deserialized());
+
+    let s = "Negative test: too many bytes with FromRow";
+    info!("{}", s);
+    let config = DeserializationConfig::default().with_max_bytes(29);
+    let test: mock_db::Result<Vec<TestData>> = get_result_set(5).try_into_rows_with(&config);
+    match test {
+        Ok(_) => panic!("Failed \"{}\"", s),
+        Err(mock_db::Error::Deserialization(DeserializationError::LimitExceeded(e))) => {
+            info!("--> Exception: {}", e);
+        }
+        Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+    }
+    Ok(())
+}
+
+fn get_result_set(len: i16) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "name"]);
+    for i in 0..len {
+        rs.push(vec![MValue::Short(i), MValue::String(format!("name {i}"))]);
+    }
+    rs
+}
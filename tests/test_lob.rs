@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ResultSet};
+use serde::Deserialize;
+use serde_db::de::Lob;
+use std::io::{BufRead, Read};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    blob: Lob,
+    clob: Option<Lob>,
+}
+
+#[test] // cargo test --test test_lob -- --nocapture
+pub fn test_lob() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== LOB columns ===");
+    {
+        info!("Convert a result set with LOB columns into a Vec<struct> and stream the LOBs");
+        let vtd: Vec<TestData> = get_result_set_lobs(4).try_into()?;
+        assert_eq!(4, vtd.len());
+        for (i, mut td) in vtd.into_iter().enumerate() {
+            assert_eq!(i as i32, td.id);
+
+            let mut blob = Vec::new();
+            td.blob.read_to_end(&mut blob).unwrap();
+            assert_eq!(blob_content(i), blob);
+
+            if i % 2 == 0 {
+                assert!(td.clob.is_none());
+            } else {
+                let lines: Vec<String> = td.clob.unwrap().lines().map(Result::unwrap).collect();
+                assert_eq!(clob_lines(i), lines);
+            }
+        }
+    }
+    {
+        info!("Convert a row with LOB columns into a tuple");
+        let row = get_result_set_lobs(2).nth(1).unwrap();
+        let (id, blob, clob): (i32, Lob, Lob) = row.try_into()?;
+        assert_eq!(1, id);
+        let mut blob_copy = Vec::new();
+        std::io::copy(&mut blob.into_reader(), &mut blob_copy).unwrap();
+        assert_eq!(blob_content(1), blob_copy);
+        let mut text = String::new();
+        clob.take(6).read_to_string(&mut text).unwrap();
+        assert_eq!("line 0", text);
+    }
+    {
+        info!("Convert a 1x1 result set into a Lob");
+        let mut rs = ResultSet::new(&["blob"]);
+        rs.push(vec![MValue::Binary(blob_content(7))]);
+        let mut lob: Lob = rs.try_into()?;
+        let mut blob = Vec::new();
+        lob.read_to_end(&mut blob).unwrap();
+        assert_eq!(blob_content(7), blob);
+    }
+    {
+        let s = "Negative test: no conversion of a number into Lob";
+        info!("{}", s);
+        let test: mock_db::Result<Lob> = MValue::Short(5).try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        let s = "Negative test: no conversion of NULL into Lob";
+        info!("{}", s);
+        let test: mock_db::Result<Lob> = MValue::Null.try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn blob_content(i: usize) -> Vec<u8> {
+    (0..100 * i).map(|b| (b % 256) as u8).collect()
+}
+
+fn clob_lines(i: usize) -> Vec<String> {
+    (0..3 * i).map(|l| format!("line {l}")).collect()
+}
+
+fn get_result_set_lobs(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "blob", "clob"]);
+    for i in 0..len {
+        rs.push(vec![
+            MValue::Short(i as i16),
+            MValue::Binary(blob_content(i)),
+            if i % 2 == 0 {
+                MValue::Null
+            } else {
+                MValue::String(clob_lines(i).join("\n"))
+            },
+        ]);
+    }
+    rs
+}
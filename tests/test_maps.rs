@@ -0,0 +1,168 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Dimensions {
+    width: i32,
+    height: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    props: HashMap<String, Option<String>>,
+    dims: Dimensions,
+    counters: Option<BTreeMap<String, i16>>,
+}
+
+#[derive(Debug, Serialize)]
+struct Input {
+    id: i32,
+    props: BTreeMap<String, Option<String>>,
+    counters: Option<BTreeMap<&'static str, i16>>,
+}
+
+#[test] // cargo test --test test_maps -- --nocapture
+pub fn test_maps() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Map columns ===");
+    {
+        info!("Convert a result set with map columns into a Vec<struct>");
+        let vtd: Vec<TestData> = get_result_set_maps(3).try_into()?;
+        assert_eq!(3, vtd.len());
+        for (i, td) in vtd.into_iter().enumerate() {
+            assert_eq!(i as i32, td.id);
+            assert_eq!(
+                HashMap::from([
+                    ("color".to_string(), Some("red".to_string())),
+                    ("size".to_string(), None),
+                ]),
+                td.props
+            );
+            assert_eq!(
+                Dimensions {
+                    width: 10 * i as i32,
+                    height: 5
+                },
+                td.dims
+            );
+            assert_eq!(
+                if i % 2 == 0 {
+                    None
+                } else {
+                    Some(BTreeMap::from([("hits".to_string(), i as i16)]))
+                },
+                td.counters
+            );
+        }
+    }
+    {
+        info!("Convert a single map value into a HashMap");
+        let m: HashMap<String, Option<String>> =
+            MValue::Map(vec![("a".to_string(), MValue::Null)]).try_into()?;
+        assert_eq!(HashMap::from([("a".to_string(), None)]), m);
+    }
+    {
+        let s = "Negative test: no conversion of non-map value into HashMap";
+        info!("{}", s);
+        let test: mock_db::Result<HashMap<String, String>> =
+            MValue::String("a=>b".to_string()).try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        info!("Serialize maps as single parameters");
+        let input_metadata = [PT::Short, PT::Map, PT::NullableMap];
+        let input = Input {
+            id: 7,
+            props: BTreeMap::from([
+                ("color".to_string(), Some("red".to_string())),
+                ("size".to_string(), None),
+            ]),
+            counters: Some(BTreeMap::from([("hits", 3)])),
+        };
+        let result: Vec<MValue> = serde_db::ser::to_params(&input, &mut input_metadata.iter())?;
+        assert_eq!(
+            vec![
+                MValue::Short(7),
+                MValue::Map(vec![
+                    ("color".to_string(), MValue::String("red".to_string())),
+                    ("size".to_string(), MValue::Null),
+                ]),
+                MValue::Map(vec![("hits".to_string(), MValue::String("3".to_string()))]),
+            ],
+            result
+        );
+
+        let s = "Negative test: no serialization of map into non-map parameter";
+        info!("{}", s);
+        let input_metadata = [PT::Short, PT::String, PT::NullableMap];
+        let test = serde_db::ser::to_params(&input, &mut input_metadata.iter());
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    flatten_row_maps()?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn flatten_row_maps() -> mock_db::Result<()> {
+    info!("Serialize maps that are parameter rows into one parameter per value");
+    let input = vec![
+        BTreeMap::from([("a", 1_i16), ("b", 2)]),
+        BTreeMap::from([("a", 3), ("b", 4)]),
+    ];
+    let input_metadata = [PT::Short, PT::Short, PT::Short, PT::Short];
+    let result: Vec<MValue> = serde_db::ser::to_params(&input, &mut input_metadata.iter())?;
+    assert_eq!(
+        vec![
+            MValue::Short(1),
+            MValue::Short(2),
+            MValue::Short(3),
+            MValue::Short(4),
+        ],
+        result
+    );
+
+    let input_metadata = [PT::Short, PT::Short];
+    let result: Vec<MValue> = serde_db::ser::to_params(&input[0], &mut input_metadata.iter())?;
+    assert_eq!(vec![MValue::Short(1), MValue::Short(2)], result);
+    Ok(())
+}
+
+fn get_result_set_maps(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "props", "dims", "counters"]);
+    for i in 0..len {
+        rs.push(vec![
+            MValue::Short(i as i16),
+            MValue::Map(vec![
+                ("color".to_string(), MValue::String("red".to_string())),
+                ("size".to_string(), MValue::Null),
+            ]),
+            MValue::Map(vec![
+                ("height".to_string(), MValue::Short(5)),
+                ("depth".to_string(), MValue::Short(1)),
+                ("width".to_string(), MValue::Short(10 * i as i16)),
+            ]),
+            if i % 2 == 0 {
+                MValue::Null
+            } else {
+                MValue::Map(vec![("hits".to_string(), MValue::Short(i as i16))])
+            },
+        ]);
+    }
+    rs
+}
@@ -0,0 +1,233 @@
+#![cfg(feature = "memory")]
+
+#[macro_use]
+extern crate log;
+
+mod util;
+
+use crate::bytes::ByteBuf;
+use serde::{Deserialize, Serialize};
+use serde_db::de::{DbValue, DeserializableResultSet, DeserializationError};
+use serde_db::memory::{ColumnType, MemValue, ParameterType, ResultSet, Row};
+use serde_db::ser::{to_params, SerializationError};
+use std::error::Error;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct TestData {
+    id: u16,
+    name: String,
+    score: f32,
+    data: ByteBuf,
+    note: Option<String>,
+}
+
+#[test] // cargo test --features memory --test test_memory -- --nocapture
+pub fn test_memory() -> Result<(), Box<dyn Error>> {
+    let _loghandle = util::init_logger();
+
+    info!("=== In-memory driver ===");
+    deserialize_result_sets()?;
+    convert_numbers()?;
+    serialize_parameters()?;
+    #[cfg(feature = "chrono")]
+    convert_dates()?;
+    Ok(())
+}
+
+fn deserialize_result_sets() -> Result<(), DeserializationError> {
+    info!("Convert a result set into a Vec<struct>");
+    let vtd: Vec<TestData> = get_result_set().try_into()?;
+    assert_eq!(2, vtd.len());
+    assert_eq!(ByteBuf(vec![1, 2]), vtd[0].data);
+    assert_eq!(None, vtd[0].note);
+    assert_eq!(Some("second".to_string()), vtd[1].note);
+
+    info!("Convert a result set into a Vec of tuples, row by row");
+    let mut rs = get_result_set();
+    assert_eq!(["id", "name", "score", "data", "note"], rs.columns());
+    let mut names = Vec::new();
+    while let Some(row) = DeserializableResultSet::next(&mut rs)? {
+        let (_, name, _, _, _): (i64, String, f64, ByteBuf, Option<String>) = row.try_into()?;
+        names.push(name);
+    }
+    assert_eq!(vec!["one", "two"], names);
+    assert!(rs.is_empty());
+
+    info!("Convert a single row and a single value");
+    let row = Row::new(&["a", "b"], vec![MemValue::Bool(true), MemValue::Null]);
+    assert_eq!(2, row.values().len());
+    let (a, b): (bool, Option<u8>) = row.try_into()?;
+    assert!(a);
+    assert_eq!(None, b);
+    let s: String = DbValue::try_into(MemValue::Int(42))?;
+    assert_eq!("42", s);
+
+    info!("NULL cannot be converted into a non-optional field");
+    let rs = ResultSet::new(&["id"]).with_row(vec![MemValue::Null]);
+    let result: Result<u32, _> = rs.try_into();
+    assert!(result.is_err());
+    Ok(())
+}
+
+fn convert_numbers() -> Result<(), DeserializationError> {
+    info!("Convert numbers into all numeric types");
+    let i: i8 = DbValue::try_into(MemValue::Int(-128))?;
+    assert_eq!(-128, i);
+    let u: u64 = DbValue::try_into(MemValue::Float(17.0))?;
+    assert_eq!(17, u);
+    let f: f32 = DbValue::try_into(MemValue::Int(3))?;
+    assert!((f - 3.0).abs() < f32::EPSILON);
+    let u: u128 = DbValue::try_into(MemValue::String("12345".to_string()))?;
+    assert_eq!(12_345, u);
+    let b: bool = DbValue::try_into(MemValue::Int(1))?;
+    assert!(b);
+
+    info!("Numeric conversions are range and precision checked");
+    assert!(DbValue::try_into::<u8>(MemValue::Int(256)).is_err());
+    assert!(DbValue::try_into::<u32>(MemValue::Int(-1)).is_err());
+    assert!(DbValue::try_into::<i64>(MemValue::Float(0.5)).is_err());
+    assert!(DbValue::try_into::<i16>(MemValue::String("x".to_string())).is_err());
+    assert!(DbValue::try_into::<i16>(MemValue::Bytes(vec![1])).is_err());
+    Ok(())
+}
+
+fn serialize_parameters() -> Result<(), SerializationError> {
+    info!("Serialize parameters");
+    let metadata = [
+        ParameterType::new(ColumnType::Int),
+        ParameterType::new(ColumnType::String),
+        ParameterType::new(ColumnType::Float),
+        ParameterType::new(ColumnType::Bytes),
+        ParameterType::nullable(ColumnType::String),
+    ];
+    let td = TestData {
+        id: 5,
+        name: "five".to_string(),
+        score: 5.5,
+        data: ByteBuf(vec![5]),
+        note: None,
+    };
+    let params: Vec<MemValue> = to_params(&td, &mut metadata.iter())?;
+    assert_eq!(
+        vec![
+            MemValue::Int(5),
+            MemValue::String("five".to_string()),
+            MemValue::Float(5.5),
+            MemValue::Bytes(vec![5]),
+            MemValue::Null,
+        ],
+        params
+    );
+
+    info!("Round trip through the in-memory driver");
+    let rs = ResultSet::new(&["id", "name", "score", "data", "note"]).with_row(params);
+    let round_tripped: TestData = rs
+        .try_into()
+        .map_err(|e| SerializationError::Serde(format!("round trip failed: {e}")))?;
+    assert_eq!(td, round_tripped);
+
+    info!("Parameters are converted and checked");
+    let int = [ParameterType::new(ColumnType::Int)];
+    let params: Vec<MemValue> = to_params(&"17", &mut int.iter())?;
+    assert_eq!(vec![MemValue::Int(17)], params);
+    let params: Vec<MemValue> = to_params(&2.0_f64, &mut int.iter())?;
+    assert_eq!(vec![MemValue::Int(2)], params);
+    assert!(matches!(
+        to_params::<_, &ParameterType>(&u64::MAX, &mut int.iter()),
+        Err(SerializationError::Range("u64", _))
+    ));
+    assert!(matches!(
+        to_params::<_, &ParameterType>(&"seventeen", &mut int.iter()),
+        Err(SerializationError::Parse { .. })
+    ));
+    assert!(matches!(
+        to_params::<_, &ParameterType>(&None::<i32>, &mut int.iter()),
+        Err(SerializationError::Type { .. })
+    ));
+    let text = [ParameterType::new(ColumnType::String)];
+    let params: Vec<MemValue> = to_params(&true, &mut text.iter())?;
+    assert_eq!(vec![MemValue::String("true".to_string())], params);
+    assert_eq!("NullableBytes", {
+        use serde_db::ser::DbvFactory;
+        ParameterType::nullable(ColumnType::Bytes).descriptor()
+    });
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+fn convert_dates() -> Result<(), Box<dyn Error>> {
+    use chrono::NaiveDate;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Event {
+        #[serde(with = "serde_db::datetime")]
+        day: NaiveDate,
+    }
+
+    info!("Convert dates");
+    let timestamp = [ParameterType::new(ColumnType::DateTime)];
+    let event = Event {
+        day: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+    };
+    let params: Vec<MemValue> = to_params(&event, &mut timestamp.iter())?;
+    assert!(matches!(params[0], MemValue::DateTime(_)));
+    let rs = ResultSet::new(&["day"]).with_row(params);
+    let round_tripped: Event = rs.try_into()?;
+    assert_eq!(event, round_tripped);
+
+    let rs = ResultSet::new(&["day"]).with_row(vec!["2024-02-29".into()]);
+    let parsed: Event = rs.try_into()?;
+    assert_eq!(event, parsed);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn get_result_set() -> ResultSet {
+    ResultSet::new(&["id", "name", "score", "data", "note"])
+        .with_row(vec![
+            1.into(),
+            "one".into(),
+            1.5.into(),
+            vec![1_u8, 2].into(),
+            MemValue::Null,
+        ])
+        .with_row(vec![
+            2.into(),
+            "two".into(),
+            2.5.into(),
+            vec![3_u8].into(),
+            "second".into(),
+        ])
+}
+
+// Serializes as and deserializes from bytes, like `serde_bytes::ByteBuf`.
+mod bytes {
+    #[derive(Debug, PartialEq)]
+    pub struct ByteBuf(pub Vec<u8>);
+
+    impl serde::Serialize for ByteBuf {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for ByteBuf {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_byte_buf(Visitor)
+        }
+    }
+
+    struct Visitor;
+
+    impl serde::de::Visitor<'_> for Visitor {
+        type Value = ByteBuf;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("bytes")
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+            Ok(ByteBuf(value.to_vec()))
+        }
+    }
+}
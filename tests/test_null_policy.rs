@@ -0,0 +1,198 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ResultSet};
+use serde::Deserialize;
+use serde_db::de::{
+    DbValue, DeserializableResultSet, DeserializationConfig, DeserializationError, NullPolicy,
+};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    name: String,
+    score: f64,
+    numbers: Vec<i32>,
+}
+
+fn default_name() -> String {
+    "anonymous".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+struct TestDataWithDefaults {
+    id: i32,
+    #[serde(default = "default_name")]
+    name: String,
+    #[serde(default)]
+    score: f64,
+    numbers: Option<Vec<i32>>,
+}
+
+#[test] // cargo test --test test_null_policy -- --nocapture
+pub fn test_null_policy() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== NULL values in fields that are not an Option ===");
+    {
+        let s = "Negative test: NULL values are rejected by default, with the column name";
+        info!("{}", s);
+        let test: mock_db::Result<Vec<TestData>> = get_result_set_with_nulls().try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(mock_db::Error::Deserialization(DeserializationError::UnexpectedNull(column))) => {
+                assert_eq!("name", column);
+            }
+            Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+        }
+    }
+    {
+        let s = "Negative test: NULL values in tuples are rejected, with the column name";
+        info!("{}", s);
+        let mut rs = ResultSet::new(&["id", "name", "score"]);
+        rs.push(vec![MValue::Short(0), MValue::Null, MValue::Double(2.5)]);
+        let test: mock_db::Result<Vec<(i32, String, f64)>> = rs.try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(mock_db::Error::Deserialization(DeserializationError::UnexpectedNull(column))) => {
+                assert_eq!("name", column);
+            }
+            Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+        }
+    }
+    {
+        info!("Replace NULL values with the default values of the target types");
+        let config = DeserializationConfig::default().with_null_policy(NullPolicy::Default);
+        let vtd: Vec<TestData> = get_result_set_with_nulls().try_into_with(&config)?;
+        assert_eq!(2, vtd.len());
+        assert_eq!(
+            (1, "", 0.0),
+            (vtd[1].id, vtd[1].name.as_str(), vtd[1].score)
+        );
+        assert!(vtd[1].numbers.is_empty());
+
+        let i: i32 = DbValue::try_into_with(MValue::Null, &config)?;
+        assert_eq!(0, i);
+    }
+    {
+        info!("Apply serde's field defaults to NULL values");
+        let config = DeserializationConfig::default().with_null_policy(NullPolicy::SerdeDefault);
+        let vtd: Vec<TestDataWithDefaults> = get_result_set_with_nulls().try_into_with(&config)?;
+        assert_eq!(2, vtd.len());
+        assert_eq!(
+            (0, "Alice", 2.5, Some(vec![1, 2])),
+            (
+                vtd[0].id,
+                vtd[0].name.as_str(),
+                vtd[0].score,
+                vtd[0].numbers.clone()
+            )
+        );
+        assert_eq!(
+            (1, "anonymous", 0.0, None),
+            (
+                vtd[1].id,
+                vtd[1].name.as_str(),
+                vtd[1].score,
+                vtd[1].numbers.clone()
+            )
+        );
+
+        let s = "Negative test: NULL values for fields without serde default are rejected";
+        info!("{}", s);
+        let test: mock_db::Result<Vec<TestData>> =
+            get_result_set_with_nulls().try_into_with(&config);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(mock_db::Error::Deserialization(DeserializationError::UnexpectedNull(column))) => {
+                assert_eq!("name", column);
+            }
+            Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+        }
+    }
+    {
+        let s = "Negative test: NULL values are rejected for target types without default";
+        info!("{}", s);
+        let config = DeserializationConfig::default().with_null_policy(NullPolicy::Default);
+        let test: Result<(i32, i32), _> = DbValue::try_into_with(MValue::Null, &config);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    null_bytes()?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn null_bytes() -> mock_db::Result<()> {
+    #[derive(Debug, Deserialize)]
+    struct Blob {
+        #[allow(dead_code)]
+        id: i32,
+        data: ByteBuf,
+    }
+    let get_result_set = || {
+        let mut rs = ResultSet::new(&["id", "data"]);
+        rs.push(vec![MValue::Short(0), MValue::Null]);
+        rs
+    };
+
+    let s = "Negative test: NULL values in byte columns are rejected, with the column name";
+    info!("{}", s);
+    let test: mock_db::Result<Blob> = get_result_set().try_into();
+    match test {
+        Ok(_) => panic!("Failed \"{}\"", s),
+        Err(mock_db::Error::Deserialization(DeserializationError::UnexpectedNull(column))) => {
+            assert_eq!("data", column);
+        }
+        Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+    }
+
+    info!("Replace NULL values in byte columns with empty byte arrays");
+    let config = DeserializationConfig::default().with_null_policy(NullPolicy::Default);
+    let blob: Blob = get_result_set().try_into_with(&config)?;
+    assert!(blob.data.0.is_empty());
+    Ok(())
+}
+
+// Deserializes from bytes, like `serde_bytes::ByteBuf`.
+#[derive(Debug)]
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = ByteBuf;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("bytes")
+            }
+            fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<ByteBuf, E> {
+                Ok(ByteBuf(value.to_vec()))
+            }
+        }
+        deserializer.deserialize_byte_buf(Visitor)
+    }
+}
+
+fn get_result_set_with_nulls() -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "name", "score", "numbers"]);
+    rs.push(vec![
+        MValue::Short(0),
+        MValue::String("Alice".to_string()),
+        MValue::Double(2.5),
+        MValue::Array(vec![MValue::Short(1), MValue::Short(2)]),
+    ]);
+    rs.push(vec![
+        MValue::Short(1),
+        MValue::Null,
+        MValue::Null,
+        MValue::Null,
+    ]);
+    rs
+}
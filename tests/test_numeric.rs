@@ -0,0 +1,139 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{KitValue, MValue};
+use serde_db::de::numeric::Scalar;
+use serde_db::de::{ConversionError, DbValueInto};
+
+#[test] // cargo test --test test_numeric -- --nocapture
+pub fn test_numeric() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Numeric conversions ===");
+    {
+        info!("Convert between integers, floats, booleans, and text");
+        assert_eq!(200_u8, Scalar::Int(200).try_into::<u8>()?);
+        assert_eq!(-3_i8, Scalar::Float(-3.0).try_into::<i8>()?);
+        assert_eq!(1_u64, Scalar::Bool(true).try_into::<u64>()?);
+        assert_eq!(42_i32, Scalar::Text(" 42 ".to_string()).try_into::<i32>()?);
+        assert_eq!(16_777_216_f32, Scalar::Int(16_777_216).try_into::<f32>()?);
+        assert_eq!(
+            -(2_f64.powi(127)),
+            Scalar::Int(i128::MIN).try_into::<f64>()?
+        );
+        assert_eq!(0.5_f32, Scalar::Float(0.5).try_into::<f32>()?);
+        assert_eq!(
+            1_u128 << 127,
+            Scalar::Float(2_f64.powi(127)).try_into::<u128>()?
+        );
+        assert_eq!(
+            u128::MAX - (u128::MAX >> 53),
+            Scalar::Float(2_f64.powi(128) - 2_f64.powi(75)).try_into::<u128>()?
+        );
+        assert_eq!(255_u8, Scalar::Float(255.0).try_into::<u8>()?);
+        assert_eq!(2.5_f64, Scalar::Text("2.5".to_string()).try_into::<f64>()?);
+        assert!(Scalar::Int(1).try_into::<bool>()?);
+        assert_eq!("-7", Scalar::Int(-7).try_into::<String>()?);
+    }
+    {
+        info!("Convert mock values with the generated DbValueInto implementations");
+        let u: u8 = DbValueInto::try_into(KitValue(MValue::Double(7.0)))?;
+        assert_eq!(7, u);
+        let f: f64 = DbValueInto::try_into(KitValue(MValue::Short(-5)))?;
+        assert_eq!(-5.0, f);
+        let s: String = DbValueInto::try_into(KitValue(MValue::Short(5)))?;
+        assert_eq!("5", s);
+        assert!(DbValueInto::<u8>::try_into(KitValue(MValue::Short(-1))).is_err());
+    }
+    {
+        info!("Negative tests: range and precision violations");
+        for (scalar, target, result) in [
+            (
+                Scalar::Int(256),
+                "u8",
+                Scalar::Int(256).try_into::<u8>().map(|_| ()),
+            ),
+            (
+                Scalar::Int(-1),
+                "u64",
+                Scalar::Int(-1).try_into::<u64>().map(|_| ()),
+            ),
+            (
+                Scalar::Float(1.5),
+                "i32",
+                Scalar::Float(1.5).try_into::<i32>().map(|_| ()),
+            ),
+            (
+                Scalar::Float(f64::NAN),
+                "i64",
+                Scalar::Float(f64::NAN).try_into::<i64>().map(|_| ()),
+            ),
+            (
+                Scalar::Float(2_f64.powi(128)),
+                "u128",
+                Scalar::Float(2_f64.powi(128))
+                    .try_into::<u128>()
+                    .map(|_| ()),
+            ),
+            (
+                Scalar::Float(2_f64.powi(127)),
+                "i128",
+                Scalar::Float(2_f64.powi(127))
+                    .try_into::<i128>()
+                    .map(|_| ()),
+            ),
+            (
+                Scalar::Float(256.0),
+                "u8",
+                Scalar::Float(256.0).try_into::<u8>().map(|_| ()),
+            ),
+            (
+                Scalar::Int(16_777_217),
+                "f32",
+                Scalar::Int(16_777_217).try_into::<f32>().map(|_| ()),
+            ),
+            (
+                Scalar::Int(i128::MAX),
+                "f64",
+                Scalar::Int(i128::MAX).try_into::<f64>().map(|_| ()),
+            ),
+            (
+                Scalar::Int(i128::MAX),
+                "f32",
+                Scalar::Int(i128::MAX).try_into::<f32>().map(|_| ()),
+            ),
+            (
+                Scalar::Float(0.1),
+                "f32",
+                Scalar::Float(0.1).try_into::<f32>().map(|_| ()),
+            ),
+            (
+                Scalar::Float(1e40),
+                "f32",
+                Scalar::Float(1e40).try_into::<f32>().map(|_| ()),
+            ),
+            (
+                Scalar::Int(2),
+                "bool",
+                Scalar::Int(2).try_into::<bool>().map(|_| ()),
+            ),
+        ] {
+            match result {
+                Err(ConversionError::NumberRange(s)) => info!("--> Exception: {}", s),
+                _ => panic!("Failed: {:?} into {} gives {:?}", scalar, target, result),
+            }
+        }
+
+        let s = "Negative test: no conversion of unparsable text into i16";
+        info!("{}", s);
+        match Scalar::Text("twelve".to_string()).try_into::<i16>() {
+            Err(ConversionError::ValueType(e)) => info!("--> Exception: {}", e),
+            result => panic!("Failed \"{}\": {:?}", s, result),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultSet, DeserializableRow, DeserializationConfig, Lob};
+use serde_db::observer::Counters;
+use serde_db::ser::SerializationConfig;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    name: String,
+}
+
+#[test] // cargo test --test test_observer -- --nocapture
+pub fn test_observer() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Observer ===");
+    deserialization()?;
+    counted_values()?;
+    serialization()?;
+    configs_are_send_sync();
+    Ok(())
+}
+
+fn deserialization() -> mock_db::Result<()> {
+    let de_counters = Arc::new(Counters::new());
+    let config = DeserializationConfig::default().with_observer(de_counters.clone());
+
+    info!("Rows, values and bytes are counted");
+    let vtd: Vec<TestData> = get_result_set(5).try_into_with(&config)?;
+    assert_eq!(5, vtd.len());
+    assert_eq!("name 4", vtd[4].name);
+    assert_eq!(5, de_counters.rows());
+    assert_eq!(10, de_counters.values());
+    assert_eq!(5 * 6, de_counters.bytes_deserialized());
+    assert!(de_counters.errors().is_empty());
+
+    info!("Single rows are counted as well");
+    let td: TestData = get_result_set(1).next().unwrap().try_into_with(&config)?;
+    assert_eq!(0, td.id);
+    assert_eq!(6, de_counters.rows());
+    assert_eq!(12, de_counters.values());
+
+    info!("Errors are counted by kind");
+    de_counters.reset();
+    assert_eq!(0, de_counters.rows());
+    let mut rs = ResultSet::new(&["id", "name"]);
+    rs.push(vec![MValue::Null, MValue::String("null".to_string())]);
+    let test: mock_db::Result<Vec<TestData>> = rs.try_into_with(&config);
+    assert!(test.is_err());
+    let test: mock_db::Result<Vec<TestData>> =
+        get_result_set(5).try_into_with(&config.clone().with_max_rows(2));
+    assert!(test.is_err());
+    let errors = de_counters.errors();
+    assert_eq!(Some(&1), errors.get("UnexpectedNull"));
+    assert_eq!(Some(&1), errors.get("LimitExceeded"));
+    assert_eq!(2, errors.len());
+    Ok(())
+}
+
+fn counted_values() -> mock_db::Result<()> {
+    #[derive(Debug, Deserialize)]
+    struct Document {
+        #[allow(dead_code)]
+        id: i32,
+        tags: Vec<String>,
+        #[allow(dead_code)]
+        content: Lob,
+    }
+    let counters = Arc::new(Counters::new());
+    let config = DeserializationConfig::default().with_observer(counters.clone());
+
+    info!("Elements of arrays are part of their column, LOBs are values");
+    let mut rs = ResultSet::new(&["id", "tags", "content"]);
+    rs.push(vec![
+        MValue::Short(1),
+        MValue::Array(vec![
+            MValue::String("red".to_string()),
+            MValue::String("green".to_string()),
+        ]),
+        MValue::Binary(vec![0; 100]),
+    ]);
+    let docs: Vec<Document> = rs.try_into_with(&config)?;
+    assert_eq!(vec!["red", "green"], docs[0].tags);
+    assert_eq!(1, counters.rows());
+    assert_eq!(3, counters.values());
+    assert_eq!(8, counters.bytes_deserialized());
+
+    info!("Failed rows and their values are not counted");
+    counters.reset();
+    let mut rs = ResultSet::new(&["id", "name"]);
+    rs.push(vec![MValue::Short(1), MValue::String("one".to_string())]);
+    rs.push(vec![MValue::Short(2), MValue::Null]);
+    let test: mock_db::Result<Vec<TestData>> = rs.try_into_with(&config);
+    assert!(test.is_err());
+    assert_eq!(1, counters.rows());
+    assert_eq!(2, counters.values());
+    assert_eq!(3, counters.bytes_deserialized());
+    assert_eq!(Some(&1), counters.errors().get("UnexpectedNull"));
+    Ok(())
+}
+
+fn serialization() -> mock_db::Result<()> {
+    info!("Parameters and bytes are counted");
+    let ser_counters = Arc::new(Counters::new());
+    let config = SerializationConfig::default().with_observer(ser_counters.clone());
+    let input_metadata = [PT::Short, PT::String];
+    let params: Vec<MValue> =
+        serde_db::ser::to_params_with(&(7_i16, "seven"), &mut input_metadata.iter(), &config)?;
+    assert_eq!(2, params.len());
+    assert_eq!(2, ser_counters.parameters());
+    assert_eq!(5, ser_counters.bytes_serialized());
+
+    info!("Serialization errors are counted by kind");
+    let test = serde_db::ser::to_params_with(
+        &(7_i16, "seven", 8_i16),
+        &mut input_metadata.iter(),
+        &config,
+    );
+    assert!(test.is_err());
+    assert_eq!(Some(&1), ser_counters.errors().get("StructuralMismatch"));
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn configs_are_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    info!("Configurations with observers can be shared across threads");
+    assert_send_sync::<DeserializationConfig>();
+    assert_send_sync::<SerializationConfig>();
+}
+
+fn get_result_set(len: i16) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "name"]);
+    for i in 0..len {
+        rs.push(vec![MValue::Short(i), MValue::String(format!("name {i}"))]);
+    }
+    rs
+}
@@ -42,7 +42,7 @@ pub fn test_result_set_1xn() -> mock_db::Result<()> {
         let test: mock_db::Result<Vec<String>> =
             get_result_set_string_ts_short_short(SIZE).try_into();
         match test {
-            Ok(_) => assert!(false, "Failed \"{}\"", s),
+            Ok(_) => panic!("Failed \"{}\"", s),
             Err(e) => info!("--> Exception: {:?}", e),
         }
     }
@@ -51,11 +51,11 @@ pub fn test_result_set_1xn() -> mock_db::Result<()> {
         info!("{}", s);
         let test: mock_db::Result<String> = get_result_set_string_ts_short_short(SIZE).try_into();
         if test.is_ok() {
-            assert!(false, "Failed \"{}\" (1)", s);
+            panic!("Failed \"{}\" (1)", s);
         }
         let test: mock_db::Result<i32> = get_result_set_string_ts_short_short(SIZE).try_into();
         match test {
-            Ok(_) => assert!(false, "Failed \"{}\"", s),
+            Ok(_) => panic!("Failed \"{}\"", s),
             Err(e) => info!("--> Exception: {:?}", e),
         }
     }
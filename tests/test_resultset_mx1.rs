@@ -17,7 +17,7 @@ pub fn test_result_set_mx1() {
     match evaluate_column_rs(&mut loghandle) {
         Err(e) => {
             error!("test_result_set_mx1() failed with {:?}", e);
-            assert!(false)
+            panic!()
         }
         Ok(_) => debug!("test_result_set_mx1() ended successful"),
     }
@@ -66,7 +66,7 @@ fn not_into_struct(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("{}", s);
     let test: mock_db::Result<TestDataMin> = get_result_set_string(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -76,7 +76,7 @@ fn not_into_field(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("{}", s);
     let test: mock_db::Result<String> = get_result_set_string(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -107,7 +107,6 @@ fn row_into_value(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
 fn row_map_fold(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("Iterate over rows, map, fold");
     let s = get_result_set_string(7)
-        .into_iter()
         .map(|r| {
             let s: String = r.try_into().unwrap();
             s
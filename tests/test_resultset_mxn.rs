@@ -19,7 +19,7 @@ pub fn test_result_set_mxn() {
     match evaluate_matrix_rs(&mut loghandle) {
         Err(e) => {
             error!("test_result_set_mxn() failed with {:?}", e);
-            assert!(false)
+            panic!()
         }
         Ok(_) => debug!("test_result_set_mxn() ended successful"),
     }
@@ -103,7 +103,7 @@ fn not_into_vec_field(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("{}", s);
     let test: mock_db::Result<Vec<String>> = get_result_set_string_ts_short_short(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -113,7 +113,7 @@ fn not_into_struct(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("{}", s);
     let test: mock_db::Result<TestData> = get_result_set_string_ts_short_short(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -123,7 +123,7 @@ fn not_into_field(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("{}", s);
     let test: mock_db::Result<String> = get_result_set_string_ts_short_short(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -144,7 +144,7 @@ fn not_into_short_struct(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     let test: mock_db::Result<Vec<ShortData>> =
         get_result_set_string_ts_short_short(SIZE).try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{}\"", s),
+        Ok(_) => panic!("Failed \"{}\"", s),
         Err(e) => info!("--> Exception: {:?}", e),
     }
     Ok(())
@@ -165,7 +165,7 @@ fn not_rows_into_long_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()
         let test: Result<(String, NaiveDateTime, i32, Option<i32>, i32), mock_db::Error> =
             row.try_into();
         match test {
-            Ok(_) => assert!(false, "Failed \"{}\"", s),
+            Ok(_) => panic!("Failed \"{}\"", s),
             Err(e) => info!("--> Exception: {:?}", e),
         }
     }
@@ -183,12 +183,11 @@ fn rows_into_short_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
 fn rows_map_fold(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     info!("Iterate over rows, map, fold");
     let sum = get_result_set_string_ts_short_short(SIZE)
-        .into_iter()
         .map(|r| {
             let i: i32 = r.cloned_value(2).unwrap().try_into().unwrap();
             i
         })
-        .fold(0, |acc, i| acc + i);
+        .sum::<i32>();
     assert_eq!(sum as usize, SIZE * (SIZE + 1) / 2);
     Ok(())
 }
@@ -222,7 +221,7 @@ fn not_rows_into_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     let result_set = get_result_set_string_ts_short_short(4);
     let test: Result<(String, chrono::NaiveDateTime, i32, i32), _> = result_set.try_into();
     match test {
-        Ok(_) => assert!(false, "Deserialization was unexpectedly successful"),
+        Ok(_) => panic!("Deserialization was unexpectedly successful"),
         Err(e) => {
             info!("--> Exception: {:?}", e);
         }
@@ -233,14 +232,12 @@ fn not_rows_into_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
 fn not_row_into_value(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     let s = "Negative test: no conversion of row into field if two or more colums";
     info!("{}", s);
-    for row in get_result_set_string_ts_short_short(1) {
-        let test: Result<String, _> = row.try_into();
-        match test {
-            Ok(_) => assert!(false, "Failed \"{}\"", s),
-            Err(e) => {
-                info!("--> Exception: {:?}", e);
-                break;
-            }
+    let row = get_result_set_string_ts_short_short(1).next().unwrap();
+    let test: Result<String, _> = row.try_into();
+    match test {
+        Ok(_) => panic!("Failed \"{}\"", s),
+        Err(e) => {
+            info!("--> Exception: {:?}", e);
         }
     }
 
@@ -259,7 +256,7 @@ fn not_rows_into_vec_of_short_struct(_loghandle: &mut LoggerHandle) -> mock_db::
     let result_set = get_result_set_string_ts_short_short(4);
     let test: Result<Vec<TestData>, _> = result_set.try_into();
     match test {
-        Ok(test) => assert!(false, "Could deserialize \"{:?}\"", test),
+        Ok(test) => panic!("Could deserialize \"{:?}\"", test),
         Err(e) => {
             info!("--> Exception: {:?}", e);
         }
@@ -268,13 +265,14 @@ fn not_rows_into_vec_of_short_struct(_loghandle: &mut LoggerHandle) -> mock_db::
     Ok(())
 }
 
+#[allow(clippy::type_complexity)]
 fn not_rows_into_vec_of_long_tuple(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
     let s = "Negative test: no conversion of rows into vec of too long tuple";
     info!("{}", s);
     let result_set = get_result_set_string_ts_short_short(4);
     let test: Result<Vec<(String, NaiveDateTime, i32, i32, i32)>, _> = result_set.try_into();
     match test {
-        Ok(_) => assert!(false, "Failed \"{:?}\"", s),
+        Ok(_) => panic!("Failed \"{:?}\"", s),
         Err(e) => {
             info!("--> Exception: {:?}", e);
         }
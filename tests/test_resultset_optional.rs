@@ -0,0 +1,112 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ResultSet};
+use serde::Deserialize;
+use serde_db::de::DeserializationError;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+#[test] // cargo test --test test_resultset_optional -- --nocapture
+pub fn test_resultset_optional() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Zero or one rows (Option) ===");
+    {
+        info!("Convert an empty result set into None");
+        let user: Option<User> = get_result_set_users(0).try_into()?;
+        assert_eq!(None, user);
+        let t: Option<(i32, String)> = get_result_set_users(0).try_into()?;
+        assert_eq!(None, t);
+        let i: Option<i32> = ResultSet::new(&["id"]).try_into()?;
+        assert_eq!(None, i);
+    }
+    {
+        info!("Convert a result set with a single row into Some");
+        let user: Option<User> = get_result_set_users(1).try_into()?;
+        assert_eq!(
+            Some(User {
+                id: 0,
+                name: "user 0".to_string()
+            }),
+            user
+        );
+        let t: Option<(i32, String)> = get_result_set_users(1).try_into()?;
+        assert_eq!(Some((0, "user 0".to_string())), t);
+    }
+    {
+        info!("Convert a NULL value in a 1x1 result set into None");
+        let mut rs = ResultSet::new(&["id"]);
+        rs.push(vec![MValue::Null]);
+        let i: Option<i32> = rs.try_into()?;
+        assert_eq!(None, i);
+        let mut rs = ResultSet::new(&["id"]);
+        rs.push(vec![MValue::Null]);
+        let i: Option<Option<i32>> = rs.try_into()?;
+        assert_eq!(Some(None), i);
+
+        let mut rs = ResultSet::new(&["id"]);
+        rs.push(vec![MValue::Short(5)]);
+        let i: Option<i32> = rs.try_into()?;
+        assert_eq!(Some(5), i);
+        let i: Option<Option<i32>> = ResultSet::new(&["id"]).try_into()?;
+        assert_eq!(None, i);
+
+        info!("Distinguish a missing row from a NULL value");
+        let mut rs = ResultSet::new(&["id"]);
+        rs.push(vec![MValue::Null]);
+        let vi: Vec<Option<i32>> = rs.try_into()?;
+        assert_eq!(vec![None], vi);
+        let vi: Vec<Option<i32>> = ResultSet::new(&["id"]).try_into()?;
+        assert!(vi.is_empty());
+    }
+    single_column_struct()?;
+    {
+        let s = "Negative test: more than one row";
+        info!("{}", s);
+        let test: mock_db::Result<Option<User>> = get_result_set_users(2).try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(mock_db::Error::Deserialization(DeserializationError::TrailingRows)) => {}
+            Err(e) => panic!("Failed \"{}\": unexpected error {:?}", s, e),
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn single_column_struct() -> mock_db::Result<()> {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct One {
+        id: i32,
+    }
+
+    info!("Convert a result set with a single column into Option<struct>");
+    let mut rs = ResultSet::new(&["id"]);
+    rs.push(vec![MValue::Short(7)]);
+    let one: Option<One> = rs.try_into()?;
+    assert_eq!(Some(One { id: 7 }), one);
+    let one: Option<One> = ResultSet::new(&["id"]).try_into()?;
+    assert_eq!(None, one);
+    let mut rs = ResultSet::new(&["id"]);
+    rs.push(vec![MValue::Null]);
+    let one: Option<One> = rs.try_into()?;
+    assert_eq!(None, one);
+    Ok(())
+}
+
+fn get_result_set_users(len: i16) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "name"]);
+    for i in 0..len {
+        rs.push(vec![MValue::Short(i), MValue::String(format!("user {i}"))]);
+    }
+    rs
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "proptest")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{FieldNames, MValue, ParameterType as PT, Row};
+use proptest::prelude::*;
+use proptest::test_runner::Config;
+use serde::{Deserialize, Serialize};
+use serde_db::testkit::round_trip::{
+    check_round_trip, check_round_trip_with, find_counterexample, round_trip, Bridge,
+};
+use std::rc::Rc;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Item {
+    id: i16,
+    label: Option<String>,
+}
+
+// Binds a short and a nullable string, and returns them as a row of the mock driver.
+struct MockBridge;
+
+impl Bridge for MockBridge {
+    type Factory = &'static PT;
+    type Row = Row;
+
+    fn parameter_types(&self) -> Vec<&'static PT> {
+        vec![&PT::Short, &PT::NullableString]
+    }
+
+    fn row(&self, params: Vec<MValue>) -> Row {
+        Row::new(Rc::new(FieldNames::new(&["id", "label"])), params)
+    }
+}
+
+#[test] // cargo test --features proptest --test test_round_trip -- --nocapture
+pub fn test_round_trip() {
+    let _loghandle = util::init_logger();
+
+    info!("=== Property-based round trips ===");
+    info!("Round trip arbitrary tuples");
+    check_round_trip::<(i16, Option<String>), _>(&MockBridge);
+
+    info!("Round trip structs from a custom strategy");
+    let items = (any::<i16>(), proptest::option::of("[a-z]{1,8}"))
+        .prop_map(|(id, label)| Item { id, label });
+    check_round_trip_with(&MockBridge, &items, Config::with_cases(64));
+
+    info!("Round trip single values");
+    let item = Item {
+        id: -5,
+        label: Some("five".to_string()),
+    };
+    assert!(round_trip(&MockBridge, &item).is_ok());
+
+    info!("Report minimized counterexamples");
+    let counterexample = find_counterexample(
+        &MockBridge,
+        &any::<(i32, Option<String>)>(),
+        Config::default(),
+    )
+    .expect("values out of the range of i16 must fail");
+    info!("{counterexample}");
+    assert!(
+        counterexample.value.0 == i32::from(i16::MAX) + 1
+            || counterexample.value.0 == i32::from(i16::MIN) - 1
+    );
+    assert_eq!(None, counterexample.value.1);
+    assert!(counterexample.reason.starts_with("serialization failed"));
+
+    let result = std::panic::catch_unwind(|| {
+        check_round_trip::<(i32, Option<String>), _>(&MockBridge);
+    });
+    assert!(result.is_err());
+
+    #[cfg(feature = "memory")]
+    memory::round_trip();
+}
+
+#[cfg(feature = "memory")]
+mod memory {
+    use proptest::prelude::*;
+    use proptest::test_runner::Config;
+    use serde_db::memory::{ColumnType, MemValue, ParameterType, Row};
+    use serde_db::testkit::round_trip::{check_round_trip, check_round_trip_with, Bridge};
+
+    // Binds values of any type, and returns them as a row of the in-memory driver.
+    struct MemoryBridge;
+
+    impl Bridge for MemoryBridge {
+        type Factory = ParameterType;
+        type Row = Row;
+
+        fn parameter_types(&self) -> Vec<ParameterType> {
+            vec![ParameterType::nullable(ColumnType::Any); 4]
+        }
+
+        fn row(&self, params: Vec<MemValue>) -> Row {
+            Row::new(&["a", "b", "c", "d"], params)
+        }
+    }
+
+    pub fn round_trip() {
+        info!("Round trip through the in-memory driver");
+        check_round_trip::<(i64, Option<String>, bool, Option<u32>), _>(&MemoryBridge);
+        let values = (
+            any::<i8>(),
+            proptest::num::f64::NORMAL | proptest::num::f64::ZERO,
+            any::<bool>(),
+            "\\PC*",
+        );
+        check_round_trip_with(&MemoryBridge, &values, Config::with_cases(64));
+    }
+}
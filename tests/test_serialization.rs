@@ -16,7 +16,7 @@ pub fn test_serialization() {
     match impl_test_serialization(&mut loghandle) {
         Err(e) => {
             error!("test_serialization() failed with {:?}", e);
-            assert!(false)
+            panic!()
         }
         Ok(_) => debug!("test_serialization() ended successful"),
     }
@@ -85,12 +85,9 @@ fn tuple_serialization(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
             .unwrap()
             .and_hms_nano_opt(3, 3, 3, 300_000_000)
             .unwrap(),
-        Some(
-            NaiveDate::from_ymd_opt(2014, 4, 4)
-                .unwrap()
-                .and_hms_nano_opt(4, 4, 4, 400_000_000),
-        )
-        .unwrap(),
+        NaiveDate::from_ymd_opt(2014, 4, 4)
+            .unwrap()
+            .and_hms_nano_opt(4, 4, 4, 400_000_000),
         t_none,
     );
     _loghandle.parse_new_spec("info").unwrap();
@@ -205,22 +202,16 @@ fn struct_serialization(_loghandle: &mut LoggerHandle) -> mock_db::Result<()> {
             .unwrap()
             .and_hms_nano_opt(1, 1, 1, 100_000_000)
             .unwrap(),
-        twelve: Some(
-            NaiveDate::from_ymd_opt(2012, 2, 2)
-                .unwrap()
-                .and_hms_nano_opt(2, 2, 2, 200_000_000),
-        )
-        .unwrap(),
+        twelve: NaiveDate::from_ymd_opt(2012, 2, 2)
+            .unwrap()
+            .and_hms_nano_opt(2, 2, 2, 200_000_000),
         thirteen: NaiveDate::from_ymd_opt(2013, 3, 3)
             .unwrap()
             .and_hms_nano_opt(3, 3, 3, 300_000_000)
             .unwrap(),
-        fourteen: Some(
-            NaiveDate::from_ymd_opt(2014, 4, 4)
-                .unwrap()
-                .and_hms_nano_opt(4, 4, 4, 400_000_000),
-        )
-        .unwrap(),
+        fourteen: NaiveDate::from_ymd_opt(2014, 4, 4)
+            .unwrap()
+            .and_hms_nano_opt(4, 4, 4, 400_000_000),
         fifteen: t_none,
     };
     _loghandle.parse_new_spec("info").unwrap();
@@ -274,5 +265,5 @@ fn mvalvec_compare(va: &[MValue], vb: &[MValue]) -> bool {
     (va.len() == vb.len()) &&  // zip stops at the shortest
      va.iter()
        .zip(vb)
-       .all(|(a,b)| (*a==*b))
+       .all(|(a,b)| *a==*b)
 }
@@ -19,7 +19,7 @@ pub fn test_special_types() {
     match impl_test_special_types(&mut loghandle) {
         Err(e) => {
             error!("test_special_types() failed with {:?}", e);
-            assert!(false)
+            panic!()
         }
         Ok(_) => debug!("test_special_types() ended successful"),
     }
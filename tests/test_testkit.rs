@@ -0,0 +1,174 @@
+#![cfg(feature = "testkit")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use serde_db::testkit::{self, Driver};
+
+// Provides the value constructors of the mock driver to the test kit.
+struct MockDriver;
+
+impl Driver for MockDriver {
+    type Value = MValue;
+    type Row = mock_db::Row;
+    type ResultSet = ResultSet;
+    type Error = mock_db::Error;
+    type Factory = &'static PT;
+
+    fn null(&self) -> MValue {
+        MValue::Null
+    }
+    fn int(&self, value: i16) -> MValue {
+        MValue::Short(value)
+    }
+    fn string(&self, value: &str) -> MValue {
+        MValue::String(value.to_string())
+    }
+    fn float(&self, value: f64) -> Option<MValue> {
+        Some(MValue::Double(value))
+    }
+    fn result_set(&self, columns: &[&'static str], rows: Vec<Vec<MValue>>) -> ResultSet {
+        let mut rs = ResultSet::new(columns);
+        for row in rows {
+            rs.push(row);
+        }
+        rs
+    }
+    fn int_parameter(&self, nullable: bool) -> Option<&'static PT> {
+        Some(if nullable {
+            &PT::NullableShort
+        } else {
+            &PT::Short
+        })
+    }
+    fn string_parameter(&self, nullable: bool) -> Option<&'static PT> {
+        Some(if nullable {
+            &PT::NullableString
+        } else {
+            &PT::String
+        })
+    }
+}
+
+#[test] // cargo test --features testkit --test test_testkit -- --nocapture
+pub fn test_testkit() {
+    let _loghandle = util::init_logger();
+
+    info!("=== Conformance test kit ===");
+    info!("Check the mock driver");
+    testkit::check_all(&MockDriver);
+
+    info!("A driver without parameters and floats skips the respective checks");
+    testkit::check_values(&MinimalDriver);
+    testkit::check_parameters(&MinimalDriver);
+
+    info!("Non-conforming drivers are detected");
+    let result = std::panic::catch_unwind(|| testkit::check_values(&BrokenDriver));
+    assert!(result.is_err());
+
+    #[cfg(feature = "memory")]
+    {
+        info!("Check the in-memory driver");
+        testkit::check_all(&memory::MemoryDriver);
+    }
+}
+
+// Uses only the required constructors.
+struct MinimalDriver;
+
+impl Driver for MinimalDriver {
+    type Value = MValue;
+    type Row = mock_db::Row;
+    type ResultSet = ResultSet;
+    type Error = mock_db::Error;
+    type Factory = &'static PT;
+
+    fn null(&self) -> MValue {
+        MValue::Null
+    }
+    fn int(&self, value: i16) -> MValue {
+        MValue::Short(value)
+    }
+    fn string(&self, value: &str) -> MValue {
+        MValue::String(value.to_string())
+    }
+    fn result_set(&self, columns: &[&'static str], rows: Vec<Vec<MValue>>) -> ResultSet {
+        MockDriver.result_set(columns, rows)
+    }
+}
+
+// Produces fractional numbers where integers are expected.
+struct BrokenDriver;
+
+impl Driver for BrokenDriver {
+    type Value = MValue;
+    type Row = mock_db::Row;
+    type ResultSet = ResultSet;
+    type Error = mock_db::Error;
+    type Factory = &'static PT;
+
+    fn null(&self) -> MValue {
+        MValue::Null
+    }
+    fn int(&self, value: i16) -> MValue {
+        MValue::Double(f64::from(value) + 0.5)
+    }
+    fn string(&self, value: &str) -> MValue {
+        MValue::String(value.to_string())
+    }
+    fn result_set(&self, columns: &[&'static str], rows: Vec<Vec<MValue>>) -> ResultSet {
+        MockDriver.result_set(columns, rows)
+    }
+}
+
+#[cfg(feature = "memory")]
+mod memory {
+    use serde_db::de::DeserializationError;
+    use serde_db::memory::{ColumnType, MemValue, ParameterType, ResultSet, Row};
+    use serde_db::testkit::Driver;
+
+    pub struct MemoryDriver;
+
+    impl Driver for MemoryDriver {
+        type Value = MemValue;
+        type Row = Row;
+        type ResultSet = ResultSet;
+        type Error = DeserializationError;
+        type Factory = ParameterType;
+
+        fn null(&self) -> MemValue {
+            MemValue::Null
+        }
+        fn int(&self, value: i16) -> MemValue {
+            value.into()
+        }
+        fn string(&self, value: &str) -> MemValue {
+            value.into()
+        }
+        fn float(&self, value: f64) -> Option<MemValue> {
+            Some(value.into())
+        }
+        fn result_set(&self, columns: &[&'static str], rows: Vec<Vec<MemValue>>) -> ResultSet {
+            rows.into_iter()
+                .fold(ResultSet::new(columns), ResultSet::with_row)
+        }
+        fn int_parameter(&self, nullable: bool) -> Option<ParameterType> {
+            Some(parameter_type(ColumnType::Int, nullable))
+        }
+        fn string_parameter(&self, nullable: bool) -> Option<ParameterType> {
+            Some(parameter_type(ColumnType::String, nullable))
+        }
+    }
+
+    fn parameter_type(column_type: ColumnType, nullable: bool) -> ParameterType {
+        if nullable {
+            ParameterType::nullable(column_type)
+        } else {
+            ParameterType::new(column_type)
+        }
+    }
+}
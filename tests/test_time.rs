@@ -0,0 +1,157 @@
+#![cfg(feature = "time")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet, Timestamp};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_db::datetime::NaiveTimestampPolicy;
+use serde_db::de::{DeserializableResultSet, DeserializationConfig};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+#[derive(Debug, Deserialize, Serialize)]
+struct TestData {
+    #[serde(with = "serde_db::datetime")]
+    f1: PrimitiveDateTime,
+    #[serde(with = "serde_db::datetime::option")]
+    f2: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StringData {
+    #[serde(with = "serde_db::datetime")]
+    f1: Date,
+    #[serde(with = "serde_db::datetime")]
+    f2: Time,
+    #[serde(with = "serde_db::datetime")]
+    f3: OffsetDateTime,
+}
+
+#[test] // cargo test --features time --test test_time -- --nocapture
+pub fn test_time() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Date and time transport for the time crate ===");
+    {
+        info!("Convert a result set with binary timestamps into a Vec<struct>");
+        let vtd: Vec<TestData> = get_result_set_timestamps(5).try_into()?;
+        assert_eq!(5, vtd.len());
+        for (i, td) in vtd.into_iter().enumerate() {
+            assert_eq!(timestamp(i), td.f1);
+            assert_eq!(
+                if i % 2 == 0 {
+                    None
+                } else {
+                    Some(timestamp(i).assume_utc())
+                },
+                td.f2
+            );
+        }
+    }
+    {
+        info!("Convert a result set with ISO 8601 strings into a struct");
+        let mut rs = ResultSet::new(&["f1", "f2", "f3"]);
+        rs.push(vec![
+            MValue::String("2024-10-13".to_string()),
+            MValue::String("23:59:01.5".to_string()),
+            MValue::String("2024-10-13 23:59:01-02:30".to_string()),
+        ]);
+        let sd: StringData = rs.try_into()?;
+        let date = Date::from_calendar_date(2024, Month::October, 13).unwrap();
+        assert_eq!(date, sd.f1);
+        assert_eq!(Time::from_hms_milli(23, 59, 1, 500).unwrap(), sd.f2);
+        assert_eq!(
+            PrimitiveDateTime::new(date, Time::from_hms(23, 59, 1).unwrap())
+                .assume_offset(UtcOffset::from_hms(-2, -30, 0).unwrap()),
+            sd.f3
+        );
+    }
+    {
+        let s = "Negative test: no conversion of naive timestamp with policy Reject";
+        info!("{}", s);
+        let config = DeserializationConfig::default()
+            .with_naive_timestamp_policy(NaiveTimestampPolicy::Reject);
+        let test: mock_db::Result<Vec<TestData>> =
+            get_result_set_timestamps(2).try_into_with(&config);
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    assume_local()?;
+    {
+        info!("Serialize date and time fields into timestamp parameters");
+        let input_metadata = [PT::Timestamp, PT::NullableString];
+        let input = TestData {
+            f1: timestamp(3),
+            f2: Some(timestamp(3).assume_offset(UtcOffset::from_hms(1, 0, 0).unwrap())),
+        };
+        let result: Vec<MValue> = serde_db::ser::to_params(&input, &mut input_metadata.iter())?;
+        assert_eq!(
+            vec![
+                MValue::Timestamp(Timestamp(
+                    NaiveDate::from_ymd_opt(2024, 10, 4)
+                        .unwrap()
+                        .and_hms_milli_opt(10, 0, 3, 300)
+                        .unwrap()
+                )),
+                MValue::String("2024-10-04T10:00:03.300000000+01:00".to_string()),
+            ],
+            result
+        );
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+fn assume_local() -> mock_db::Result<()> {
+    info!("Apply the policy AssumeLocal, or fail with a clear error");
+    let config = DeserializationConfig::default()
+        .with_naive_timestamp_policy(NaiveTimestampPolicy::AssumeLocal);
+    let test: mock_db::Result<Vec<TestData>> = get_result_set_timestamps(2).try_into_with(&config);
+    match test {
+        Ok(vtd) => {
+            // the offset must be that of the local time zone at the resulting instant
+            let f2 = vtd[1].f2.unwrap();
+            assert_eq!(timestamp(1), PrimitiveDateTime::new(f2.date(), f2.time()));
+            assert_eq!(UtcOffset::local_offset_at(f2).unwrap(), f2.offset());
+        }
+        Err(e) => {
+            // the test harness runs multithreaded, where time cannot determine the offset
+            info!("--> Exception: {:?}", e);
+            assert!(format!("{e:?}").contains("cannot be determined"));
+        }
+    }
+    Ok(())
+}
+
+fn timestamp(i: usize) -> PrimitiveDateTime {
+    PrimitiveDateTime::new(
+        Date::from_calendar_date(2024, Month::October, i as u8 + 1).unwrap(),
+        Time::from_hms_milli(10, 0, i as u8, 100 * i as u16).unwrap(),
+    )
+}
+
+fn get_result_set_timestamps(len: usize) -> ResultSet {
+    let mut rs = ResultSet::new(&["f1", "f2"]);
+    for i in 0..len {
+        let ts = NaiveDate::from_ymd_opt(2024, 10, i as u32 + 1)
+            .unwrap()
+            .and_hms_milli_opt(10, 0, i as u32, 100 * i as u32)
+            .unwrap();
+        rs.push(vec![
+            MValue::Timestamp(Timestamp(ts)),
+            if i % 2 == 0 {
+                MValue::Null
+            } else {
+                MValue::Timestamp(Timestamp(ts))
+            },
+        ]);
+    }
+    rs
+}
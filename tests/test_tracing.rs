@@ -0,0 +1,186 @@
+#![cfg(feature = "tracing")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use serde::Deserialize;
+use serde_db::de::{DeserializableResultSet, DeserializationConfig};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata};
+
+#[derive(Debug, Deserialize)]
+struct TestData {
+    id: i32,
+    name: String,
+}
+
+#[test] // cargo test --features tracing --test test_tracing -- --nocapture
+pub fn test_tracing() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Tracing ===");
+    let subscriber = Capture::default();
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        deserialize_result_set(&subscriber)?;
+        deserialize_row(&subscriber)?;
+        serialize(&subscriber)?;
+        failing_deserialization(&subscriber);
+        Ok(())
+    })
+}
+
+fn deserialize_result_set(subscriber: &Capture) -> mock_db::Result<()> {
+    info!("A result set deserialization is a span with row and column counts, and a span per row");
+    subscriber.clear();
+    let vtd: Vec<TestData> = get_result_set(5).try_into()?;
+    assert_eq!(5, vtd.len());
+    assert_eq!("name 4", vtd[4].name);
+
+    let spans = subscriber.spans();
+    assert_eq!(6, spans.len());
+    assert_eq!("serde_db::deserialize_result_set", spans[0].name);
+    assert_eq!("2", spans[0].fields["columns"]);
+    assert_eq!("5", spans[0].fields["rows"]);
+    assert!(spans[0].fields.contains_key("duration_us"));
+    for span in &spans[1..] {
+        assert_eq!("serde_db::deserialize_row", span.name);
+        assert_eq!("2", span.fields["columns"]);
+        assert!(span.fields.contains_key("duration_us"));
+    }
+    assert!(subscriber.events().is_empty());
+    Ok(())
+}
+
+fn deserialize_row(subscriber: &Capture) -> mock_db::Result<()> {
+    info!("A row deserialization is one span with the column count");
+    subscriber.clear();
+    let td: TestData = get_result_set(1).next().unwrap().try_into()?;
+    assert_eq!(0, td.id);
+
+    let spans = subscriber.spans();
+    assert_eq!(1, spans.len());
+    assert_eq!("serde_db::deserialize_row", spans[0].name);
+    assert_eq!("2", spans[0].fields["columns"]);
+    assert!(spans[0].fields.contains_key("duration_us"));
+    assert!(subscriber.events().is_empty());
+    Ok(())
+}
+
+fn serialize(subscriber: &Capture) -> mock_db::Result<()> {
+    info!("A serialization is one span with the parameter count");
+    subscriber.clear();
+    let input_metadata = [PT::Short, PT::String];
+    let params: Vec<MValue> =
+        serde_db::ser::to_params(&(7_i16, "seven"), &mut input_metadata.iter())?;
+    assert_eq!(2, params.len());
+
+    let spans = subscriber.spans();
+    assert_eq!(1, spans.len());
+    assert_eq!("serde_db::to_params", spans[0].name);
+    assert_eq!("2", spans[0].fields["params"]);
+    assert!(spans[0].fields.contains_key("duration_us"));
+    assert!(subscriber.events().is_empty());
+    Ok(())
+}
+
+fn failing_deserialization(subscriber: &Capture) {
+    info!("Errors are reported with a debug event");
+    subscriber.clear();
+    let config = DeserializationConfig::default().with_max_rows(3);
+    let test: mock_db::Result<Vec<TestData>> = get_result_set(5).try_into_with(&config);
+    assert!(test.is_err());
+
+    let events = subscriber.events();
+    assert_eq!(1, events.len());
+    assert_eq!(Level::DEBUG, events[0].0);
+    assert!(events[0].1.contains("resource limit"), "{}", events[0].1);
+}
+
+////////////////////////////////////////////////////////
+fn get_result_set(len: i16) -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "name"]);
+    for i in 0..len {
+        rs.push(vec![MValue::Short(i), MValue::String(format!("name {i}"))]);
+    }
+    rs
+}
+
+// A minimal subscriber that records spans with their fields, and events.
+#[derive(Clone, Default)]
+struct Capture {
+    spans: Arc<Mutex<Vec<CapturedSpan>>>,
+    events: Arc<Mutex<Vec<(Level, String)>>>,
+}
+
+#[derive(Clone, Debug)]
+struct CapturedSpan {
+    name: &'static str,
+    fields: BTreeMap<&'static str, String>,
+}
+
+impl Capture {
+    fn clear(&self) {
+        self.spans.lock().unwrap().clear();
+        self.events.lock().unwrap().clear();
+    }
+    fn spans(&self) -> Vec<CapturedSpan> {
+        self.spans.lock().unwrap().clone()
+    }
+    fn events(&self) -> Vec<(Level, String)> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut BTreeMap<&'static str, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name(), format!("{value:?}"));
+    }
+}
+
+impl tracing::Subscriber for Capture {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = BTreeMap::new();
+        span.record(&mut FieldVisitor(&mut fields));
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(CapturedSpan {
+            name: span.metadata().name(),
+            fields,
+        });
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some(span) = spans.get_mut(span.into_u64() as usize - 1) {
+            values.record(&mut FieldVisitor(&mut span.fields));
+        }
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = BTreeMap::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        self.events
+            .lock()
+            .unwrap()
+            .push((*event.metadata().level(), format!("{fields:?}")));
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
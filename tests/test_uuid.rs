@@ -0,0 +1,83 @@
+#![cfg(feature = "uuid")]
+
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct TestData {
+    #[serde(with = "serde_db::uuid")]
+    f1: Uuid,
+    #[serde(with = "serde_db::uuid::option")]
+    f2: Option<Uuid>,
+}
+
+#[test] // cargo test --features uuid --test test_uuid -- --nocapture
+pub fn test_uuid() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+    let uuid = Uuid::parse_str(UUID).unwrap();
+
+    info!("=== UUID transport ===");
+    {
+        info!("Convert binary and textual UUID columns into a Vec<struct>");
+        let mut rs = ResultSet::new(&["f1", "f2"]);
+        rs.push(vec![
+            MValue::Binary(uuid.as_bytes().to_vec()),
+            MValue::String(UUID.to_uppercase()),
+        ]);
+        rs.push(vec![MValue::String(UUID.to_string()), MValue::Null]);
+        let vtd: Vec<TestData> = rs.try_into()?;
+        assert_eq!(
+            vec![
+                TestData {
+                    f1: uuid,
+                    f2: Some(uuid)
+                },
+                TestData { f1: uuid, f2: None },
+            ],
+            vtd
+        );
+    }
+    {
+        let s = "Negative test: no conversion of binary value with wrong length";
+        info!("{}", s);
+        let mut rs = ResultSet::new(&["f1", "f2"]);
+        rs.push(vec![MValue::Binary(vec![1, 2, 3]), MValue::Null]);
+        let test: mock_db::Result<TestData> = rs.try_into();
+        match test {
+            Ok(_) => panic!("Failed \"{}\"", s),
+            Err(e) => info!("--> Exception: {:?}", e),
+        }
+    }
+    {
+        info!("Serialize UUIDs according to the parameter type");
+        let input_metadata = [PT::Binary, PT::NullableString];
+        let input = TestData {
+            f1: uuid,
+            f2: Some(uuid),
+        };
+        let result: Vec<MValue> = serde_db::ser::to_params(&input, &mut input_metadata.iter())?;
+        assert_eq!(
+            vec![
+                MValue::Binary(uuid.as_bytes().to_vec()),
+                MValue::String(UUID.to_string()),
+            ],
+            result
+        );
+
+        let input_metadata = [PT::String, PT::NullableBinary];
+        let input = TestData { f1: uuid, f2: None };
+        let result: Vec<MValue> = serde_db::ser::to_params(&input, &mut input_metadata.iter())?;
+        assert_eq!(vec![MValue::String(UUID.to_string()), MValue::Null], result);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+#[macro_use]
+extern crate log;
+
+mod mock_db;
+mod util;
+
+use crate::mock_db::{MValue, ParameterType as PT, ResultSet};
+use indexmap::IndexMap;
+use serde_db::de::{DbValue, DeserializableResultSet};
+use serde_db::value::ValueKind;
+use serde_db::Value;
+
+#[test] // cargo test --test test_value -- --nocapture
+pub fn test_value() -> mock_db::Result<()> {
+    let _loghandle = util::init_logger();
+
+    info!("=== Dynamic values ===");
+    {
+        info!("Convert rows into maps of values");
+        let rows: Vec<IndexMap<String, Value>> = get_result_set().try_into()?;
+        assert_eq!(2, rows.len());
+        assert_eq!(
+            vec!["id", "name", "score", "data", "note"],
+            rows[0].keys().collect::<Vec<_>>()
+        );
+        assert_eq!(Value::Int(1), rows[0]["id"]);
+        assert_eq!(Value::String("one".to_string()), rows[0]["name"]);
+        assert_eq!(Value::Float(1.5), rows[0]["score"]);
+        assert_eq!(Value::Bytes(vec![1, 2]), rows[0]["data"]);
+        assert_eq!(Value::Null, rows[0]["note"]);
+        assert_eq!(Value::String("second".to_string()), rows[1]["note"]);
+        assert_eq!(Some(ValueKind::Float), rows[1]["score"].kind());
+    }
+    {
+        info!("Convert a result set into a table");
+        let table = get_result_set().try_into_table()?;
+        assert_eq!(["id", "name", "score", "data", "note"], table.columns());
+        assert_eq!(2, table.len());
+        let row = &table.rows()[1];
+        assert_eq!(Some(&Value::String("two".to_string())), row.get("name"));
+        assert_eq!(Value::Int(2), row[0]);
+        assert_eq!(None, row.get("unknown"));
+        assert_eq!(
+            vec![
+                ("id", &Value::Int(2)),
+                ("name", &Value::String("two".to_string()))
+            ],
+            row.iter().take(2).collect::<Vec<_>>()
+        );
+
+        info!("Tables of empty result sets know their columns");
+        let table = ResultSet::new(&["a", "b"]).try_into_table()?;
+        assert!(table.is_empty());
+        assert_eq!(["a", "b"], table.columns());
+    }
+    {
+        info!("Convert single values");
+        let value: Value = get_result_set()
+            .try_into()
+            .map(|mut rows: Vec<Vec<Value>>| rows.remove(0).remove(0))?;
+        assert_eq!(Value::Int(1), value);
+        let value: Value = DbValue::try_into(MValue::Null)?;
+        assert!(value.is_null());
+        let mut rs = ResultSet::new(&["f"]);
+        rs.push(vec![MValue::Double(0.25)]);
+        let value: Value = rs.try_into()?;
+        assert_eq!(Value::Float(0.25), value);
+    }
+    {
+        info!("Use values as parameters");
+        let input_metadata = [PT::Short, PT::NullableString];
+        let params: Vec<MValue> =
+            serde_db::ser::to_params(&(Value::Int(7), Value::Null), &mut input_metadata.iter())?;
+        assert_eq!(vec![MValue::Short(7), MValue::Null], params);
+    }
+    #[cfg(feature = "decimal")]
+    decimal_values()?;
+    #[cfg(feature = "chrono")]
+    datetime_values()?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////
+#[cfg(feature = "decimal")]
+fn decimal_values() -> mock_db::Result<()> {
+    use serde_db::decimal::DbDecimal;
+
+    info!("Decimals are values, and are bound as decimals");
+    let decimal = DbDecimal::new(-31_415, 4);
+    let value: Value = DbValue::try_into(MValue::Decimal(decimal))?;
+    assert_eq!(Value::Decimal(decimal), value);
+
+    let input_metadata = [PT::Decimal];
+    let params: Vec<MValue> = serde_db::ser::to_params(&value, &mut input_metadata.iter())?;
+    assert_eq!(vec![MValue::Decimal(decimal)], params);
+
+    #[cfg(feature = "json")]
+    {
+        info!("Decimals are exported as strings");
+        assert_eq!(r#""-3.1415""#, serde_json::to_string(&value).unwrap());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+fn datetime_values() -> mock_db::Result<()> {
+    use serde_db::datetime::{DbDate, DbDateTime, DbTime};
+
+    info!("Timestamps are values, and are bound as timestamps");
+    let ts = chrono::NaiveDate::from_ymd_opt(2024, 2, 29)
+        .unwrap()
+        .and_hms_opt(12, 30, 15)
+        .unwrap();
+    let value: Value = DbValue::try_into(MValue::new_ts(ts))?;
+    assert_eq!(
+        Value::DateTime(DbDateTime::Timestamp(
+            DbDate::new(2024, 2, 29),
+            DbTime::new(12, 30, 15, 0)
+        )),
+        value
+    );
+
+    let input_metadata = [PT::Timestamp];
+    let params: Vec<MValue> = serde_db::ser::to_params(&value, &mut input_metadata.iter())?;
+    assert_eq!(vec![MValue::new_ts(ts)], params);
+
+    #[cfg(feature = "json")]
+    {
+        info!("Timestamps are exported as ISO 8601 strings");
+        assert_eq!(
+            r#""2024-02-29T12:30:15""#,
+            serde_json::to_string(&value).unwrap()
+        );
+    }
+    Ok(())
+}
+
+fn get_result_set() -> ResultSet {
+    let mut rs = ResultSet::new(&["id", "name", "score", "data", "note"]);
+    rs.push(vec![
+        MValue::Short(1),
+        MValue::String("one".to_string()),
+        MValue::Double(1.5),
+        MValue::Binary(vec![1, 2]),
+        MValue::Null,
+    ]);
+    rs.push(vec![
+        MValue::Short(2),
+        MValue::String("two".to_string()),
+        MValue::Double(2.5),
+        MValue::Binary(vec![3]),
+        MValue::String("second".to_string()),
+    ]);
+    rs
+}